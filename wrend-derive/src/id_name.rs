@@ -0,0 +1,115 @@
+use crate::rename_rule::RenameRule;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Error, Expr, ExprLit, Lit, LitStr, Meta};
+
+/// Gets the explicit name given in an `#[id_name(name = "...")]` attribute
+/// (or its shorthand, `#[name = "..."]`), if there is one
+fn explicit_name(attributes: &[Attribute]) -> Result<Option<String>, Error> {
+    let mut name = None;
+    let mut set_name = |attribute: &Attribute, value: String| {
+        if name.replace(value).is_some() {
+            return Err(Error::new_spanned(attribute, "a name was already given"));
+        }
+        Ok(())
+    };
+
+    for attribute in attributes {
+        if attribute.path().is_ident("name") {
+            let value = match &attribute.meta {
+                Meta::NameValue(name_value) => match &name_value.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(value),
+                        ..
+                    }) => value.value(),
+                    value => return Err(Error::new_spanned(value, "expected a string literal")),
+                },
+                meta => return Err(Error::new_spanned(meta, "expected `#[name = \"...\"]`")),
+            };
+            set_name(attribute, value)?;
+            continue;
+        }
+
+        if !attribute.path().is_ident("id_name") {
+            continue;
+        }
+
+        // the other options are validated when the rename rule is parsed
+        let mut value = None;
+        attribute.parse_nested_meta(|meta| {
+            let meta_value: LitStr = meta.value()?.parse()?;
+            if meta.path.is_ident("name") {
+                value = Some(meta_value.value());
+            }
+            Ok(())
+        })?;
+        if let Some(value) = value {
+            set_name(attribute, value)?;
+        }
+    }
+
+    Ok(name)
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream, Error> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let rename_rule = RenameRule::from_attributes(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Enum(data_enum) => {
+            if explicit_name(&input.attrs)?.is_some() {
+                return Err(Error::new_spanned(
+                    ident,
+                    "`#[id_name(name = \"...\")]` names a single value, so it can only be used on variants and structs",
+                ));
+            }
+
+            let arms = data_enum
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let name = match explicit_name(&variant.attrs)? {
+                        Some(name) => name,
+                        None => rename_rule.apply(&variant_ident.to_string()),
+                    };
+                    // a braced pattern matches unit, tuple and struct variants alike
+                    Ok(quote! { Self::#variant_ident { .. } => #name })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            if arms.is_empty() {
+                quote! { match *self {} }
+            } else {
+                quote! {
+                    let name = match self {
+                        #(#arms,)*
+                    };
+                    ::std::string::String::from(name)
+                }
+            }
+        }
+        Data::Struct(_) => {
+            let name = match explicit_name(&input.attrs)? {
+                Some(name) => name,
+                None => rename_rule.apply(&ident.to_string()),
+            };
+            quote! { ::std::string::String::from(#name) }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                ident,
+                "`IdName` can only be derived for enums and structs",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::wrend::IdName for #ident #ty_generics #where_clause {
+            fn name(&self) -> ::std::string::String {
+                #body
+            }
+        }
+    })
+}
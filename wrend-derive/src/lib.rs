@@ -0,0 +1,123 @@
+#![warn(missing_docs)]
+
+//! Derive macros for `wrend`'s [`Id`](https://docs.rs/wrend/latest/wrend/trait.Id.html),
+//! [`IdName`](https://docs.rs/wrend/latest/wrend/trait.IdName.html),
+//! [`Std140`](https://docs.rs/wrend/latest/wrend/trait.Std140.html)
+//! and [`Uniforms`](https://docs.rs/wrend/latest/wrend/trait.Uniforms.html) traits.
+//!
+//! These are re-exported from `wrend` when its `derive` feature is enabled, so there is usually
+//! no need to depend on this crate directly.
+
+mod id_name;
+mod rename_rule;
+mod std140;
+mod uniforms;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Implements `wrend::Id` for a type.
+///
+/// `Id` also requires `Hash`, `Eq`, `Clone`, `Debug` and `Default`, which can all be derived with the
+/// standard derives (use `#[default]` on one of an enum's variants to derive `Default`).
+///
+/// ```ignore
+/// #[derive(Id, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+/// pub enum ProgramId {
+///     #[default]
+///     PassThrough,
+///     Blur,
+/// }
+/// ```
+#[proc_macro_derive(Id)]
+pub fn derive_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::wrend::Id for #ident #ty_generics #where_clause {}
+    }
+    .into()
+}
+
+/// Implements `wrend::IdName` for an enum or a unit struct.
+///
+/// Each variant's (or a struct's) name can be given explicitly with `#[id_name(name = "...")]`, or with the shorthand
+/// `#[name = "..."]`. Any variant without one is named
+/// by converting its identifier with the rule given in a container-level `#[id_name(...)]` attribute:
+///
+/// - `case`: one of `"snake"`, `"screaming_snake"`, `"kebab"`, `"camel"`, `"pascal"`, `"lower"` or `"upper"`
+///   (if omitted, the identifier is used as-is)
+/// - `prefix` / `suffix`: text added before / after the converted identifier
+///
+/// ```ignore
+/// #[derive(IdName, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// #[id_name(prefix = "u_", case = "snake")]
+/// pub enum UniformId {
+///     WhiteNoiseTexture, // "u_white_noise_texture"
+///     #[id_name(name = "u_time")]
+///     Now, // "u_time"
+///     #[name = "u_resolution"]
+///     Size, // "u_resolution"
+/// }
+/// ```
+#[proc_macro_derive(IdName, attributes(id_name, name))]
+pub fn derive_id_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    id_name::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `wrend::Std140` for a struct, so that it can be packed into a uniform block.
+///
+/// Fields are laid out in declaration order, following the `std140` rules. Every field's type must implement
+/// `Std140` itself, except for fields marked with `#[std140(array)]`, which must be arrays (`[T; N]`)
+/// of a type that does, and are packed as GLSL arrays.
+///
+/// Note that `[f32; 3]` is packed as a `vec3` and `[[f32; 4]; 4]` as a `mat4` unless marked as an array.
+///
+/// ```ignore
+/// #[derive(Std140)]
+/// struct Camera {
+///     view: [[f32; 4]; 4], // mat4 view;
+///     position: [f32; 3],  // vec3 position;
+///     #[std140(array)]
+///     weights: [f32; 4],   // float weights[4];
+/// }
+/// ```
+#[proc_macro_derive(Std140, attributes(std140))]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    std140::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `wrend::Uniforms` for a struct with named fields, so that it can be uploaded to a GLSL
+/// struct uniform (or an array of them) with `UniformLink::struct_of` or `UniformLink::array_of`.
+///
+/// Each field is uploaded to the struct member with the same name, unless renamed with
+/// `#[uniforms(name = "...")]` (raw identifiers like `r#type` are named without the `r#`).
+/// Every field's type must implement `Uniforms` itself, except for fields marked with `#[uniforms(array)]`,
+/// which must be arrays (`[T; N]`) of a type that does, and are uploaded as GLSL arrays.
+///
+/// ```ignore
+/// #[derive(Uniforms)]
+/// struct Sphere {
+///     center: [f32; 3],        // vec3 center;
+///     radius: f32,             // float radius;
+///     material: Material,      // Material material;
+///     #[uniforms(array)]
+///     weights: [f32; 4],       // float weights[4];
+/// }
+/// ```
+#[proc_macro_derive(Uniforms, attributes(uniforms))]
+pub fn derive_uniforms(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    uniforms::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
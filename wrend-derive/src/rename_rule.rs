@@ -0,0 +1,222 @@
+use syn::{Attribute, Error, LitStr};
+
+/// Letter case that an identifier is converted to when no explicit name is given
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Case {
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    Camel,
+    Pascal,
+    Lower,
+    Upper,
+}
+
+impl Case {
+    fn from_lit(lit: &LitStr) -> Result<Self, Error> {
+        match lit.value().as_str() {
+            "snake" => Ok(Case::Snake),
+            "screaming_snake" => Ok(Case::ScreamingSnake),
+            "kebab" => Ok(Case::Kebab),
+            "camel" => Ok(Case::Camel),
+            "pascal" => Ok(Case::Pascal),
+            "lower" => Ok(Case::Lower),
+            "upper" => Ok(Case::Upper),
+            _ => Err(Error::new_spanned(
+                lit,
+                "unknown case: expected one of \"snake\", \"screaming_snake\", \"kebab\", \"camel\", \"pascal\", \"lower\" or \"upper\"",
+            )),
+        }
+    }
+}
+
+/// Container-level rule for deriving names from identifiers: `#[id_name(prefix = "u_", case = "snake")]`
+///
+/// An explicit `#[id_name(name = "...")]` is parsed separately, by [crate::id_name].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RenameRule {
+    pub prefix: String,
+    pub suffix: String,
+    pub case: Option<Case>,
+}
+
+impl RenameRule {
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Self, Error> {
+        let mut rename_rule = RenameRule::default();
+
+        for attribute in attributes {
+            if !attribute.path().is_ident("id_name") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                let value: LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("prefix") {
+                    rename_rule.prefix = value.value();
+                } else if meta.path.is_ident("suffix") {
+                    rename_rule.suffix = value.value();
+                } else if meta.path.is_ident("case") {
+                    rename_rule.case = Some(Case::from_lit(&value)?);
+                } else if !meta.path.is_ident("name") {
+                    return Err(meta.error(
+                        "unknown `id_name` option: expected `prefix`, `suffix`, `case` or `name`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(rename_rule)
+    }
+
+    /// Converts a Rust identifier into a name according to this rule
+    pub fn apply(&self, ident: &str) -> String {
+        let converted = match self.case {
+            None => ident.to_string(),
+            Some(case) => convert_case(ident, case),
+        };
+
+        format!("{}{}{}", self.prefix, converted, self.suffix)
+    }
+}
+
+/// Splits an identifier such as `UPerlinNoise2D` or `u_perlin_noise` into its lowercase words
+fn split_words(ident: &str) -> Vec<String> {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            // start a new word at `aB`, and at the last capital of an acronym, as in `HTMLCanvas`.
+            // digits are treated like capitals, so that `Noise2D` stays one word but `Vec3Buffer` does not
+            if previous.is_lowercase()
+                || ((previous.is_uppercase() || previous.is_ascii_digit()) && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.extend(c.to_lowercase());
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn convert_case(ident: &str, case: Case) -> String {
+    let words = split_words(ident);
+
+    match case {
+        Case::Snake => words.join("_"),
+        Case::ScreamingSnake => words.join("_").to_uppercase(),
+        Case::Kebab => words.join("-"),
+        Case::Lower => words.concat(),
+        Case::Upper => words.concat().to_uppercase(),
+        Case::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+        Case::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.clone()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_identifiers_into_words() {
+        assert_eq!(
+            split_words("UPerlinNoiseTexture"),
+            ["u", "perlin", "noise", "texture"]
+        );
+        assert_eq!(split_words("HTMLCanvas"), ["html", "canvas"]);
+        assert_eq!(
+            split_words("already_snake_case"),
+            ["already", "snake", "case"]
+        );
+        assert_eq!(split_words("Now"), ["now"]);
+    }
+
+    #[test]
+    fn keeps_digits_followed_by_capitals_together() {
+        assert_eq!(split_words("Noise2D"), ["noise2d"]);
+        assert_eq!(
+            split_words("UPerlinNoise2DTexture"),
+            ["u", "perlin", "noise2d", "texture"]
+        );
+        assert_eq!(split_words("Vec3Buffer"), ["vec3", "buffer"]);
+        assert_eq!(split_words("noise_2d"), ["noise", "2d"]);
+    }
+
+    #[test]
+    fn converts_between_cases() {
+        assert_eq!(
+            convert_case("WhiteNoiseTexture", Case::Snake),
+            "white_noise_texture"
+        );
+        assert_eq!(
+            convert_case("WhiteNoiseTexture", Case::ScreamingSnake),
+            "WHITE_NOISE_TEXTURE"
+        );
+        assert_eq!(
+            convert_case("WhiteNoiseTexture", Case::Kebab),
+            "white-noise-texture"
+        );
+        assert_eq!(
+            convert_case("WhiteNoiseTexture", Case::Camel),
+            "whiteNoiseTexture"
+        );
+        assert_eq!(
+            convert_case("white_noise_texture", Case::Pascal),
+            "WhiteNoiseTexture"
+        );
+        assert_eq!(
+            convert_case("WhiteNoiseTexture", Case::Lower),
+            "whitenoisetexture"
+        );
+        assert_eq!(
+            convert_case("WhiteNoiseTexture", Case::Upper),
+            "WHITENOISETEXTURE"
+        );
+    }
+
+    #[test]
+    fn applies_prefix_and_suffix() {
+        let rename_rule = RenameRule {
+            prefix: "u_".to_string(),
+            suffix: "_texture".to_string(),
+            case: Some(Case::Snake),
+        };
+        assert_eq!(rename_rule.apply("WhiteNoise"), "u_white_noise_texture");
+        assert_eq!(RenameRule::default().apply("WhiteNoise"), "WhiteNoise");
+    }
+}
@@ -0,0 +1,132 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Type};
+
+/// Returns `true` if the field is marked with `#[std140(array)]`
+fn is_array(attributes: &[Attribute]) -> Result<bool, Error> {
+    let mut is_array = false;
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("std140") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("array") {
+                is_array = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `#[std140(array)]`"))
+            }
+        })?;
+    }
+
+    Ok(is_array)
+}
+
+/// The layout of a single field: its alignment, its size and how to write it
+struct FieldLayout {
+    alignment: TokenStream,
+    size: TokenStream,
+    write: TokenStream,
+}
+
+fn field_layout(
+    attributes: &[Attribute],
+    ty: &Type,
+    member: TokenStream,
+) -> Result<FieldLayout, Error> {
+    if !is_array(attributes)? {
+        return Ok(FieldLayout {
+            alignment: quote! { <#ty as ::wrend::Std140>::ALIGNMENT },
+            size: quote! { <#ty as ::wrend::Std140>::SIZE },
+            write: quote! { writer.write(&self.#member); },
+        });
+    }
+
+    let Type::Array(array) = ty else {
+        return Err(Error::new_spanned(
+            ty,
+            "`#[std140(array)]` can only be used on fields with an array type",
+        ));
+    };
+    let element = &array.elem;
+    let len = &array.len;
+
+    Ok(FieldLayout {
+        alignment: quote! {
+            ::wrend::std140_aggregate_alignment(<#element as ::wrend::Std140>::ALIGNMENT)
+        },
+        size: quote! {
+            (#len) * ::wrend::std140_array_stride(
+                <#element as ::wrend::Std140>::SIZE,
+                <#element as ::wrend::Std140>::ALIGNMENT,
+            )
+        },
+        write: quote! { writer.write_array(&self.#member); },
+    })
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream, Error> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(data_struct) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Std140` can only be derived for structs",
+        ));
+    };
+
+    let layouts = match &data_struct.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let member = field.ident.to_token_stream();
+                field_layout(&field.attrs, &field.ty, member)
+            })
+            .collect::<Result<Vec<_>, Error>>()?,
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let member = syn::Index::from(i).to_token_stream();
+                field_layout(&field.attrs, &field.ty, member)
+            })
+            .collect::<Result<Vec<_>, Error>>()?,
+        Fields::Unit => Vec::new(),
+    };
+
+    let alignments: Vec<_> = layouts.iter().map(|layout| &layout.alignment).collect();
+    let sizes: Vec<_> = layouts.iter().map(|layout| &layout.size).collect();
+    let writes: Vec<_> = layouts.iter().map(|layout| &layout.write).collect();
+
+    Ok(quote! {
+        impl #impl_generics ::wrend::Std140 for #ident #ty_generics #where_clause {
+            const ALIGNMENT: usize = {
+                let mut alignment = 0;
+                #(
+                    if #alignments > alignment {
+                        alignment = #alignments;
+                    }
+                )*
+                ::wrend::std140_aggregate_alignment(alignment)
+            };
+
+            const SIZE: usize = {
+                let mut offset = 0;
+                #(
+                    offset = ::wrend::std140_align(offset, #alignments) + #sizes;
+                )*
+                ::wrend::std140_align(offset, Self::ALIGNMENT)
+            };
+
+            fn write_std140(&self, writer: &mut ::wrend::Std140Writer) {
+                #(#writes)*
+                writer.align(Self::ALIGNMENT);
+            }
+        }
+    })
+}
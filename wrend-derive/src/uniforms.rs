@@ -0,0 +1,151 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, LitStr, Type};
+
+/// The options given in a field's `#[uniforms(...)]` attributes
+#[derive(Default)]
+struct FieldOptions {
+    is_array: bool,
+    name: Option<String>,
+}
+
+fn field_options(attributes: &[Attribute]) -> Result<FieldOptions, Error> {
+    let mut options = FieldOptions::default();
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("uniforms") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("array") {
+                options.is_array = true;
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                options.name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("expected `#[uniforms(array)]` or `#[uniforms(name = \"...\")]`"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+/// How to list a single field's uniform names and how to visit its values
+struct FieldUniforms {
+    names: TokenStream,
+    values: TokenStream,
+}
+
+fn field_uniforms(
+    options: FieldOptions,
+    ty: &Type,
+    member: &syn::Ident,
+    name: String,
+) -> Result<FieldUniforms, Error> {
+    if !options.is_array {
+        return Ok(FieldUniforms {
+            names: quote! {
+                <#ty as ::wrend::Uniforms>::uniform_names(
+                    &::std::format!("{}.{}", prefix, #name),
+                    names,
+                );
+            },
+            values: quote! {
+                ::wrend::Uniforms::visit_uniform_values(
+                    &self.#member,
+                    &::std::format!("{}.{}", prefix, #name),
+                    visit,
+                );
+            },
+        });
+    }
+
+    let Type::Array(array) = ty else {
+        return Err(Error::new_spanned(
+            ty,
+            "`#[uniforms(array)]` can only be used on fields with an array type",
+        ));
+    };
+    let element = &array.elem;
+    let len = &array.len;
+
+    Ok(FieldUniforms {
+        names: quote! {
+            for i in 0..(#len) {
+                <#element as ::wrend::Uniforms>::uniform_names(
+                    &::std::format!("{}.{}[{}]", prefix, #name, i),
+                    names,
+                );
+            }
+        },
+        values: quote! {
+            for (i, element) in self.#member.iter().enumerate() {
+                ::wrend::Uniforms::visit_uniform_values(
+                    element,
+                    &::std::format!("{}.{}[{}]", prefix, #name, i),
+                    visit,
+                );
+            }
+        },
+    })
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream, Error> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(data_struct) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Uniforms` can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data_struct.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Uniforms` can only be derived for structs with named fields",
+        ));
+    };
+
+    let fields = fields
+        .named
+        .iter()
+        .map(|field| {
+            let member = field
+                .ident
+                .as_ref()
+                .expect("Named fields should have an ident");
+            let options = field_options(&field.attrs)?;
+            // raw identifiers like `r#type` are named after the identifier itself
+            let name = options
+                .name
+                .clone()
+                .unwrap_or_else(|| member.unraw().to_string());
+            field_uniforms(options, &field.ty, member, name)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let names: Vec<_> = fields.iter().map(|field| &field.names).collect();
+    let values: Vec<_> = fields.iter().map(|field| &field.values).collect();
+
+    Ok(quote! {
+        impl #impl_generics ::wrend::Uniforms for #ident #ty_generics #where_clause {
+            fn uniform_names(prefix: &str, names: &mut ::std::vec::Vec<::std::string::String>) {
+                #(#names)*
+            }
+
+            fn visit_uniform_values(
+                &self,
+                prefix: &str,
+                visit: &mut dyn ::std::ops::FnMut(&str, ::wrend::UniformValue),
+            ) {
+                #(#values)*
+            }
+        }
+    })
+}
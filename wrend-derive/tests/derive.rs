@@ -0,0 +1,62 @@
+use wrend::{Id, IdName};
+
+#[derive(Id, IdName, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[id_name(prefix = "u_", case = "snake")]
+enum UniformId {
+    #[default]
+    WhiteNoiseTexture,
+    PerlinNoiseTexture,
+    #[id_name(name = "u_time")]
+    Now,
+    #[name = "u_resolution"]
+    Size,
+}
+
+#[derive(Id, IdName, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+enum ProgramId {
+    #[default]
+    PassThrough,
+    #[id_name(name = "blur_program")]
+    Blur,
+}
+
+#[derive(Id, IdName, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[id_name(prefix = "a_", case = "snake")]
+struct Position;
+
+#[derive(Id, IdName, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[id_name(name = "u_sphere")]
+struct SphereUniform(usize);
+
+fn assert_id<T: Id>() {}
+
+#[test]
+fn derives_id() {
+    assert_id::<UniformId>();
+    assert_id::<ProgramId>();
+    assert_id::<Position>();
+    assert_id::<SphereUniform>();
+}
+
+#[test]
+fn derives_names_from_rename_rule() {
+    assert_eq!(UniformId::WhiteNoiseTexture.name(), "u_white_noise_texture");
+    assert_eq!(
+        UniformId::PerlinNoiseTexture.name(),
+        "u_perlin_noise_texture"
+    );
+    assert_eq!(Position.name(), "a_position");
+}
+
+#[test]
+fn explicit_names_take_precedence() {
+    assert_eq!(UniformId::Now.name(), "u_time");
+    assert_eq!(UniformId::Size.name(), "u_resolution");
+    assert_eq!(ProgramId::Blur.name(), "blur_program");
+    assert_eq!(SphereUniform(3).name(), "u_sphere");
+}
+
+#[test]
+fn identifiers_are_kept_without_a_case() {
+    assert_eq!(ProgramId::PassThrough.name(), "PassThrough");
+}
@@ -0,0 +1,65 @@
+use wrend::Std140;
+
+#[derive(Std140)]
+struct Light {
+    color: [f32; 3],
+    intensity: f32,
+}
+
+#[derive(Std140)]
+struct Scene {
+    time: f32,
+    #[std140(array)]
+    weights: [f32; 3],
+    light: Light,
+    view: [[f32; 4]; 4],
+    flags: [u32; 2],
+}
+
+fn f32_at(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+#[test]
+fn packs_members_into_the_remaining_space_of_a_vec3() {
+    let bytes = Light {
+        color: [1.0, 2.0, 3.0],
+        intensity: 4.0,
+    }
+    .to_std140_bytes();
+
+    assert_eq!(Light::SIZE, 16);
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(f32_at(&bytes, 12), 4.0);
+}
+
+#[test]
+fn lays_out_arrays_structs_and_matrices() {
+    let mut view = [[0.0; 4]; 4];
+    view[0][0] = 5.0;
+    let scene = Scene {
+        time: 1.0,
+        weights: [2.0, 3.0, 4.0],
+        light: Light {
+            color: [6.0, 7.0, 8.0],
+            intensity: 9.0,
+        },
+        view,
+        flags: [10, 11],
+    };
+
+    let bytes = scene.to_std140_bytes();
+
+    assert_eq!(Scene::ALIGNMENT, 16);
+    assert_eq!(Scene::SIZE, 160);
+    assert_eq!(bytes.len(), 160);
+    assert_eq!(f32_at(&bytes, 0), 1.0);
+    // every array element is padded to 16 bytes
+    assert_eq!(f32_at(&bytes, 16), 2.0);
+    assert_eq!(f32_at(&bytes, 32), 3.0);
+    assert_eq!(f32_at(&bytes, 48), 4.0);
+    assert_eq!(f32_at(&bytes, 64), 6.0);
+    assert_eq!(f32_at(&bytes, 76), 9.0);
+    assert_eq!(f32_at(&bytes, 80), 5.0);
+    assert_eq!(&bytes[144..152], &[10, 0, 0, 0, 11, 0, 0, 0]);
+}
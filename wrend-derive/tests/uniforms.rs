@@ -0,0 +1,68 @@
+use wrend::{UniformValue, Uniforms};
+
+#[derive(Uniforms)]
+struct Material {
+    r#type: i32,
+    #[uniforms(name = "albedo")]
+    color: [f32; 3],
+}
+
+#[derive(Uniforms)]
+struct Sphere {
+    center: [f32; 3],
+    radius: f32,
+    material: Material,
+    #[uniforms(array)]
+    weights: [f32; 2],
+}
+
+fn sphere() -> Sphere {
+    Sphere {
+        center: [1.0, 2.0, 3.0],
+        radius: 4.0,
+        material: Material {
+            r#type: 5,
+            color: [0.5, 0.5, 0.5],
+        },
+        weights: [6.0, 7.0],
+    }
+}
+
+#[test]
+fn names_nested_structs_and_arrays() {
+    let mut names = Vec::new();
+    Sphere::uniform_names("[0]", &mut names);
+
+    assert_eq!(
+        names,
+        vec![
+            "[0].center",
+            "[0].radius",
+            "[0].material.type",
+            "[0].material.albedo",
+            "[0].weights[0]",
+            "[0].weights[1]",
+        ]
+    );
+}
+
+#[test]
+fn visits_every_member_in_order() {
+    let mut values = Vec::new();
+    sphere().visit_uniform_values("", &mut |name, value| {
+        values.push((name.to_string(), value))
+    });
+
+    let mut names = Vec::new();
+    Sphere::uniform_names("", &mut names);
+
+    assert_eq!(
+        values
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>(),
+        names
+    );
+    assert_eq!(values[2].1, UniformValue::Int(5));
+    assert_eq!(values[5].1, UniformValue::Float(7.0));
+}
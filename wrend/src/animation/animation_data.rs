@@ -2,72 +2,18 @@ use std::{any::Any, cell::RefCell, rc::Rc};
 
 use wasm_bindgen::JsValue;
 
-use crate::{
-    AnimationCallback, Callback, Id, IdDefault, IdName, RendererData, RendererDataJs,
-    RendererDataJsInner,
-};
+use crate::{AnimationCallback, Callback, JsTypes, RendererData, RendererDataJs, RendererTypes};
 use log::error;
 
 #[derive(Clone, Debug)]
-pub(crate) struct AnimationData<
-    VertexShaderId: Id = IdDefault,
-    FragmentShaderId: Id = IdDefault,
-    ProgramId: Id = IdDefault,
-    UniformId: Id + IdName = IdDefault,
-    BufferId: Id = IdDefault,
-    AttributeId: Id + IdName = IdDefault,
-    TextureId: Id = IdDefault,
-    FramebufferId: Id = IdDefault,
-    TransformFeedbackId: Id = IdDefault,
-    VertexArrayObjectId: Id = IdDefault,
-    UserCtx: Clone + 'static = (),
-> {
+pub(crate) struct AnimationData<T: RendererTypes> {
     request_id: i32,
-    animation_callback: Option<
-        AnimationCallback<
-            VertexShaderId,
-            FragmentShaderId,
-            ProgramId,
-            UniformId,
-            BufferId,
-            AttributeId,
-            TextureId,
-            FramebufferId,
-            TransformFeedbackId,
-            VertexArrayObjectId,
-            UserCtx,
-        >,
-    >,
+    animation_callback: Option<AnimationCallback<T>>,
     is_animating: bool,
+    is_paused: bool,
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone + 'static,
-    >
-    AnimationData<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes> AnimationData<T> {
     pub fn new() -> Self {
         Self::default()
     }
@@ -83,30 +29,11 @@ impl<
     /// Calls the internal animation callback.
     ///
     /// If no animation has been supplied yet, this is a no-op.
-    pub fn call_animation_callback(
-        &self,
-        renderer_data: Rc<
-            RefCell<
-                RendererData<
-                    VertexShaderId,
-                    FragmentShaderId,
-                    ProgramId,
-                    UniformId,
-                    BufferId,
-                    AttributeId,
-                    TextureId,
-                    FramebufferId,
-                    TransformFeedbackId,
-                    VertexArrayObjectId,
-                    UserCtx,
-                >,
-            >,
-        >,
-    ) {
+    pub fn call_animation_callback(&self, renderer_data: Rc<RefCell<RendererData<T>>>) {
         if let Some(animation_callback) = &self.animation_callback {
             // if the types are compatible with JavaScript, treat as a special case and pass in the `RendererData` to the JavaScript function
             let rendered = if let Some(renderer_data) =
-                (&renderer_data as &dyn Any).downcast_ref::<Rc<RefCell<RendererDataJsInner>>>()
+                (&renderer_data as &dyn Any).downcast_ref::<Rc<RefCell<RendererData<JsTypes>>>>()
             {
                 let renderer_data = Rc::clone(renderer_data);
                 match &**animation_callback {
@@ -134,24 +61,7 @@ impl<
         }
     }
 
-    pub fn set_animation_callback(
-        &mut self,
-        animation_callback: Option<
-            AnimationCallback<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        >,
-    ) {
+    pub fn set_animation_callback(&mut self, animation_callback: Option<AnimationCallback<T>>) {
         self.animation_callback = animation_callback;
     }
 
@@ -163,35 +73,19 @@ impl<
     pub fn is_animating(&self) -> bool {
         self.is_animating
     }
+
+    /// While paused, the animation loop keeps running, but the animation callback is not called
+    pub fn set_is_paused(&mut self, is_paused: bool) -> &mut Self {
+        self.is_paused = is_paused;
+        self
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone + 'static,
-    > Default
-    for AnimationData<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes> Default for AnimationData<T> {
     fn default() -> Self {
         Self {
             animation_callback: None,
@@ -200,6 +94,7 @@ impl<
             // used to cancel a requested animation frame.
             request_id: 0,
             is_animating: false,
+            is_paused: false,
         }
     }
 }
@@ -0,0 +1,11 @@
+mod asset_loader;
+mod fetch_asset_loader;
+mod load_progress;
+mod load_progress_callback;
+mod load_progress_callback_js;
+
+pub use asset_loader::*;
+pub use fetch_asset_loader::*;
+pub use load_progress::*;
+pub use load_progress_callback::*;
+pub use load_progress_callback_js::*;
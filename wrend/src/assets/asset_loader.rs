@@ -0,0 +1,21 @@
+use crate::LoadAssetError;
+use std::{future::Future, pin::Pin};
+use web_sys::HtmlImageElement;
+
+/// A boxed future that resolves to a loaded asset
+pub type AssetFuture<T> = Pin<Box<dyn Future<Output = Result<T, LoadAssetError>>>>;
+
+/// Loads the assets that have been added to a [crate::RendererDataBuilder] by URL.
+///
+/// [crate::FetchAssetLoader] is used by default, but any other implementation (such as a local stand-in
+/// for tests) can be supplied to [crate::RendererDataBuilder::load_assets].
+///
+/// Loading should begin as soon as one of these methods is called (rather than when the
+/// returned future is first polled), so that every asset can download in parallel.
+pub trait AssetLoader {
+    /// Loads a text file, such as a shader source
+    fn load_text(&self, url: &str) -> AssetFuture<String>;
+
+    /// Loads an image that can be uploaded to a texture
+    fn load_image(&self, url: &str) -> AssetFuture<HtmlImageElement>;
+}
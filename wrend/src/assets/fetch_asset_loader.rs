@@ -0,0 +1,85 @@
+use crate::{AssetFuture, AssetLoader, LoadAssetError};
+use js_sys::Promise;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, HtmlImageElement, Response};
+
+/// Loads assets in the browser: text is downloaded with `fetch`, and images are loaded with an `<img>` element.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FetchAssetLoader;
+
+impl FetchAssetLoader {
+    async fn response_text(url: String, response: Promise) -> Result<String, LoadAssetError> {
+        let fetch_error = |err: JsValue| LoadAssetError::Fetch {
+            url: url.clone(),
+            message: format!("{err:?}"),
+        };
+
+        let response: Response = JsFuture::from(response)
+            .await
+            .map_err(fetch_error)?
+            .dyn_into()
+            .map_err(fetch_error)?;
+
+        if !response.ok() {
+            return Err(LoadAssetError::Status {
+                url,
+                status: response.status(),
+            });
+        }
+
+        let text = JsFuture::from(response.text().map_err(fetch_error)?)
+            .await
+            .map_err(fetch_error)?;
+
+        text.as_string().ok_or_else(|| LoadAssetError::Fetch {
+            url,
+            message: String::from("Response body was not text"),
+        })
+    }
+}
+
+impl AssetLoader for FetchAssetLoader {
+    fn load_text(&self, url: &str) -> AssetFuture<String> {
+        let url = url.to_string();
+        let response = window()
+            .ok_or(LoadAssetError::NoWindow)
+            .map(|window| window.fetch_with_str(&url));
+
+        Box::pin(async move { Self::response_text(url, response?).await })
+    }
+
+    fn load_image(&self, url: &str) -> AssetFuture<HtmlImageElement> {
+        let url = url.to_string();
+        let image = HtmlImageElement::new().map_err(|err| LoadAssetError::Image {
+            url: url.clone(),
+            message: format!("{err:?}"),
+        });
+
+        let loaded = image.as_ref().ok().map(|image| {
+            // allows images from other origins to be uploaded to WebGL textures
+            image.set_cross_origin(Some("anonymous"));
+            let loaded = Promise::new(&mut |resolve, reject| {
+                image.set_onload(Some(&resolve));
+                image.set_onerror(Some(&reject));
+            });
+            image.set_src(&url);
+            loaded
+        });
+
+        Box::pin(async move {
+            let image = image?;
+            if let Some(loaded) = loaded {
+                let result = JsFuture::from(loaded).await;
+                image.set_onload(None);
+                image.set_onerror(None);
+                result.map_err(|err| LoadAssetError::Image {
+                    url,
+                    message: format!("{err:?}"),
+                })?;
+            }
+
+            Ok(image)
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Passed to the [crate::LoadProgressCallback] each time an asset finishes loading
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LoadProgress {
+    loaded: u32,
+    total: u32,
+    url: String,
+}
+
+impl LoadProgress {
+    pub fn new(loaded: u32, total: u32, url: impl Into<String>) -> Self {
+        Self {
+            loaded,
+            total,
+            url: url.into(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl LoadProgress {
+    /// How many assets have finished loading so far
+    pub fn loaded(&self) -> u32 {
+        self.loaded
+    }
+
+    /// How many assets are being loaded in total
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// The URL of the asset that just finished loading
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+}
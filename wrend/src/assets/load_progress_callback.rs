@@ -0,0 +1,47 @@
+use std::{fmt::Debug, ops::Deref, rc::Rc};
+
+use crate::{Callback, LoadProgress, LoadProgressCallbackJs};
+
+/// This is the inner type that [`LoadProgressCallback`] stores
+pub type LoadProgressCallbackInner = Callback<dyn Fn(LoadProgress), LoadProgressCallbackJs>;
+
+/// This callback is called by [`crate::RendererDataBuilder::load_assets`] each time an asset finishes loading.
+/// Can be created by converting from either a Rust or a JavaScript callback.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct LoadProgressCallback(LoadProgressCallbackInner);
+
+impl Deref for LoadProgressCallback {
+    type Target = LoadProgressCallbackInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for LoadProgressCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LoadProgressCallback")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<F: Fn(LoadProgress) + 'static> From<F> for LoadProgressCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(LoadProgress)>
+        ))
+    }
+}
+
+impl<F: Fn(LoadProgress) + 'static> From<Rc<F>> for LoadProgressCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(LoadProgress)>))
+    }
+}
+
+impl From<LoadProgressCallbackJs> for LoadProgressCallback {
+    fn from(callback: LoadProgressCallbackJs) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
@@ -0,0 +1,14 @@
+use js_sys::Function;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(typescript_custom_section)]
+const LOAD_PROGRESS_CALLBACK_JS: &'static str = r#"
+type LoadProgressCallbackJs = (progress: LoadProgress) => void;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Function, is_type_of = JsValue::is_function, typescript_type = "LoadProgressCallbackJs")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type LoadProgressCallbackJs;
+}
@@ -7,6 +7,7 @@ mod attribute_js;
 mod attribute_link;
 mod attribute_link_js;
 mod attribute_location;
+mod vertex_layout;
 
 pub use attribute::*;
 pub use attribute_create_callback::*;
@@ -17,3 +18,4 @@ pub use attribute_js::*;
 pub use attribute_link::*;
 pub use attribute_link_js::*;
 pub use attribute_location::*;
+pub use vertex_layout::*;
@@ -1,6 +1,6 @@
 use crate::{
     AttributeCreateCallback, AttributeCreateContext, AttributeLinkJs, AttributeLinkJsInner,
-    AttributeLocation, Bridge, Id, IdName,
+    AttributeLocation, Bridge, Id, IdName, VertexLayout,
 };
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -12,6 +12,7 @@ pub struct AttributeLink<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id
     buffer_id: BufferId,
     attribute_id: AttributeId,
     attribute_create_callback: AttributeCreateCallback,
+    divisor: u32,
 }
 
 impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
@@ -29,9 +30,53 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
             buffer_id,
             attribute_id,
             attribute_create_callback: attribute_create_callback.into(),
+            divisor: 0,
         }
     }
 
+    /// Creates a link for every attribute in `vertex_layout`, all of which read from the same buffer.
+    ///
+    /// Each link's create callback binds the buffer and makes the `vertexAttribPointer` call itself,
+    /// so no callback needs to be written by hand. Each link's divisor is taken from its [crate::VertexAttribute].
+    pub fn from_layout(
+        vao_ids: impl Into<Bridge<VertexArrayObjectId>>,
+        buffer_id: BufferId,
+        vertex_layout: &VertexLayout<AttributeId>,
+    ) -> Vec<Self> {
+        let vao_ids_bridge: Bridge<VertexArrayObjectId> = vao_ids.into();
+        let vao_ids: Vec<VertexArrayObjectId> = vao_ids_bridge.into();
+        let stride = vertex_layout.stride();
+
+        vertex_layout
+            .attributes()
+            .iter()
+            .map(|vertex_attribute| {
+                let attribute_id = vertex_attribute.attribute_id().clone();
+                let divisor = vertex_attribute.divisor();
+                let vertex_attribute = vertex_attribute.clone();
+                let mut attribute_link = Self::new(
+                    vao_ids.clone(),
+                    buffer_id.clone(),
+                    attribute_id,
+                    move |ctx: &AttributeCreateContext| {
+                        let gl = ctx.gl();
+                        gl.bind_buffer(
+                            WebGl2RenderingContext::ARRAY_BUFFER,
+                            Some(ctx.webgl_buffer()),
+                        );
+                        vertex_attribute.vertex_attrib_pointer(
+                            gl,
+                            ctx.attribute_location().get(),
+                            stride,
+                        );
+                    },
+                );
+                attribute_link.set_divisor(divisor);
+                attribute_link
+            })
+            .collect()
+    }
+
     pub fn vao_ids(&self) -> &[VertexArrayObjectId] {
         &self.vao_ids
     }
@@ -48,7 +93,21 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
         self.attribute_create_callback.clone()
     }
 
-    /// Runs the associated `attribute_create_callback` to initialize the attribute
+    /// The number of instances drawn before this attribute advances to its next value,
+    /// or `0` if it advances once per vertex (the default)
+    pub fn divisor(&self) -> u32 {
+        self.divisor
+    }
+
+    /// Makes this a per-instance attribute, which advances once every `divisor` instances
+    /// when drawing with [crate::RendererData::draw_instanced]
+    pub fn set_divisor(&mut self, divisor: u32) -> &mut Self {
+        self.divisor = divisor;
+        self
+    }
+
+    /// Runs the associated `attribute_create_callback` to initialize the attribute,
+    /// then sets its divisor if it is a per-instance attribute
     pub fn create_attribute(
         &self,
         gl: WebGl2RenderingContext,
@@ -57,9 +116,13 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
         attribute_location: AttributeLocation,
     ) {
         let attribute_create_context =
-            AttributeCreateContext::new(gl, now, webgl_buffer, attribute_location);
+            AttributeCreateContext::new(gl.clone(), now, webgl_buffer, attribute_location);
         self.attribute_create_callback
             .call_with_into_js_arg(&attribute_create_context);
+
+        if self.divisor != 0 {
+            gl.vertex_attrib_divisor(attribute_location.get(), self.divisor);
+        }
     }
 }
 
@@ -71,6 +134,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Debug
             .field("vao_ids", &self.vao_ids)
             .field("buffer_id", &self.buffer_id)
             .field("attribute_id", &self.attribute_id)
+            .field("divisor", &self.divisor)
             .field("update_callback", &"[not shown]")
             .field("should_update_callback", &"[not shown]")
             .finish()
@@ -95,6 +159,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> PartialEq
             && self.buffer_id == other.buffer_id
             && self.attribute_id == other.attribute_id
             && self.attribute_create_callback == other.attribute_create_callback
+            && self.divisor == other.divisor
     }
 }
 
@@ -44,6 +44,15 @@ impl AttributeLinkJs {
         self.deref().attribute_id().to_owned()
     }
 
+    pub fn divisor(&self) -> u32 {
+        self.deref().divisor()
+    }
+
+    #[wasm_bindgen(js_name = setDivisor)]
+    pub fn set_divisor(&mut self, divisor: u32) {
+        self.deref_mut().set_divisor(divisor);
+    }
+
     #[wasm_bindgen(js_name = createCallback)]
     pub fn create_callback(&self) -> Option<AttributeCreateCallbackJs> {
         self.deref().create_callback().js_inner_owned()
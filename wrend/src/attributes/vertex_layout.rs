@@ -0,0 +1,280 @@
+use crate::{AttributeType, Id, IdName};
+use web_sys::WebGl2RenderingContext;
+
+/// A single attribute within a [VertexLayout].
+///
+/// If `integer` is `true`, the attribute is read with `vertexAttribIPointer`, so that integer
+/// data is not converted to floats (in which case `normalized` is ignored).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VertexAttribute<AttributeId: Id + IdName> {
+    attribute_id: AttributeId,
+    size: i32,
+    data_type: AttributeType,
+    normalized: bool,
+    integer: bool,
+    offset: i32,
+    divisor: u32,
+}
+
+impl<AttributeId: Id + IdName> VertexAttribute<AttributeId> {
+    /// Describes an attribute made of `size` components (1 to 4) of type `data_type`
+    pub fn new(attribute_id: AttributeId, size: i32, data_type: AttributeType) -> Self {
+        Self {
+            attribute_id,
+            size,
+            data_type,
+            normalized: false,
+            integer: false,
+            offset: 0,
+            divisor: 0,
+        }
+    }
+
+    /// Normalizes integer components into the range `[0, 1]` (or `[-1, 1]` if signed)
+    pub fn normalized(mut self) -> Self {
+        self.normalized = true;
+        self
+    }
+
+    /// Reads integer components as integers (as `int`/`ivec`/`uint`/`uvec` in GLSL) instead of floats
+    pub fn integer(mut self) -> Self {
+        self.integer = true;
+        self
+    }
+
+    /// Advances this attribute once every `divisor` instances rather than once per vertex.
+    /// A divisor of `0` (the default) advances it once per vertex.
+    pub fn with_divisor(mut self, divisor: u32) -> Self {
+        self.divisor = divisor;
+        self
+    }
+
+    /// The id of the attribute
+    pub fn attribute_id(&self) -> &AttributeId {
+        &self.attribute_id
+    }
+
+    /// The number of components per vertex (1 to 4)
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    /// The type of each component
+    pub fn data_type(&self) -> AttributeType {
+        self.data_type
+    }
+
+    /// Whether integer components are normalized to floats when read
+    pub fn is_normalized(&self) -> bool {
+        self.normalized
+    }
+
+    /// Whether the attribute is read as an integer (`vertexAttribIPointer`)
+    pub fn is_integer(&self) -> bool {
+        self.integer
+    }
+
+    /// The number of bytes from the start of a vertex to the start of this attribute
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// The number of instances drawn before the attribute advances, or `0` to advance once per vertex
+    pub fn divisor(&self) -> u32 {
+        self.divisor
+    }
+
+    /// The number of bytes that this attribute occupies within a vertex
+    pub fn byte_size(&self) -> i32 {
+        self.size * self.data_type.byte_size()
+    }
+
+    /// Points the attribute at `attribute_location` to the buffer currently bound to `ARRAY_BUFFER`.
+    ///
+    /// The divisor is not set here, since [crate::AttributeLink::from_layout] passes it on to each link.
+    pub fn vertex_attrib_pointer(
+        &self,
+        gl: &WebGl2RenderingContext,
+        attribute_location: u32,
+        stride: i32,
+    ) {
+        let data_type = self.data_type.into();
+        if self.integer {
+            gl.vertex_attrib_i_pointer_with_i32(
+                attribute_location,
+                self.size,
+                data_type,
+                stride,
+                self.offset,
+            );
+        } else {
+            gl.vertex_attrib_pointer_with_i32(
+                attribute_location,
+                self.size,
+                data_type,
+                self.normalized,
+                stride,
+                self.offset,
+            );
+        }
+    }
+}
+
+/// Describes how the attributes of a vertex are laid out in a buffer, so that the `vertexAttribPointer`
+/// calls can be made by [crate::AttributeLink::from_layout] rather than by hand.
+///
+/// Attributes are stored one after another in the order they are added, so several attributes can be
+/// interleaved in a single buffer. Each attribute's offset is aligned to the size of its components,
+/// and the stride is padded so that every vertex starts at a correctly aligned offset.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VertexLayout<AttributeId: Id + IdName> {
+    attributes: Vec<VertexAttribute<AttributeId>>,
+    end: i32,
+    alignment: i32,
+    stride: Option<i32>,
+}
+
+impl<AttributeId: Id + IdName> VertexLayout<AttributeId> {
+    /// Creates a layout without any attributes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an attribute after all of the attributes added so far
+    pub fn add_attribute(&mut self, mut attribute: VertexAttribute<AttributeId>) -> &mut Self {
+        let alignment = attribute.data_type.byte_size();
+        attribute.offset = align(self.end, alignment);
+        self.end = attribute.offset + attribute.byte_size();
+        self.alignment = self.alignment.max(alignment);
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Skips `bytes` bytes of data that aren't read by any attribute
+    pub fn add_padding(&mut self, bytes: i32) -> &mut Self {
+        self.end += bytes;
+        self
+    }
+
+    /// Overrides the computed stride, for vertices that are followed by data that isn't part of this layout
+    pub fn set_stride(&mut self, stride: i32) -> &mut Self {
+        self.stride.replace(stride);
+        self
+    }
+
+    /// Every attribute, in the order that it was added
+    pub fn attributes(&self) -> &Vec<VertexAttribute<AttributeId>> {
+        &self.attributes
+    }
+
+    /// The number of bytes from the start of one vertex to the start of the next
+    pub fn stride(&self) -> i32 {
+        self.stride
+            .unwrap_or_else(|| align(self.end, self.alignment))
+    }
+}
+
+impl<AttributeId: Id + IdName> Default for VertexLayout<AttributeId> {
+    fn default() -> Self {
+        Self {
+            attributes: Vec::new(),
+            end: 0,
+            alignment: 1,
+            stride: None,
+        }
+    }
+}
+
+/// Rounds `offset` up to the next multiple of `alignment`
+fn align(offset: i32, alignment: i32) -> i32 {
+    (offset + alignment - 1) / alignment * alignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AttributeLink;
+
+    fn offsets(vertex_layout: &VertexLayout<&'static str>) -> Vec<i32> {
+        vertex_layout
+            .attributes()
+            .iter()
+            .map(VertexAttribute::offset)
+            .collect()
+    }
+
+    #[test]
+    fn interleaves_attributes() {
+        let mut vertex_layout = VertexLayout::new();
+        vertex_layout
+            .add_attribute(VertexAttribute::new("a_position", 3, AttributeType::Float))
+            .add_attribute(
+                VertexAttribute::new("a_color", 4, AttributeType::UnsignedByte).normalized(),
+            )
+            .add_attribute(VertexAttribute::new("a_uv", 2, AttributeType::Float));
+
+        assert_eq!(offsets(&vertex_layout), vec![0, 12, 16]);
+        assert_eq!(vertex_layout.stride(), 24);
+    }
+
+    #[test]
+    fn aligns_offsets_and_stride_to_component_size() {
+        let mut vertex_layout = VertexLayout::new();
+        vertex_layout
+            .add_attribute(VertexAttribute::new("a_normal", 3, AttributeType::Byte))
+            .add_attribute(VertexAttribute::new(
+                "a_weight",
+                1,
+                AttributeType::HalfFloat,
+            ))
+            .add_attribute(VertexAttribute::new("a_id", 1, AttributeType::UnsignedByte));
+
+        assert_eq!(offsets(&vertex_layout), vec![0, 4, 6]);
+        // padded so that the next vertex's half float is also 2 byte aligned
+        assert_eq!(vertex_layout.stride(), 8);
+    }
+
+    #[test]
+    fn padding_and_stride_overrides() {
+        let mut vertex_layout = VertexLayout::new();
+        vertex_layout
+            .add_padding(4)
+            .add_attribute(VertexAttribute::new("a_position", 2, AttributeType::Float));
+
+        assert_eq!(offsets(&vertex_layout), vec![4]);
+        assert_eq!(vertex_layout.stride(), 12);
+
+        vertex_layout.set_stride(32);
+        assert_eq!(vertex_layout.stride(), 32);
+    }
+
+    #[test]
+    fn creates_a_link_per_attribute_sharing_one_buffer() {
+        let mut vertex_layout = VertexLayout::new();
+        vertex_layout
+            .add_attribute(VertexAttribute::new("a_position", 2, AttributeType::Float))
+            .add_attribute(VertexAttribute::new("a_uv", 2, AttributeType::Float));
+
+        let attribute_links =
+            AttributeLink::<&str, &str, &str>::from_layout("vao", "vertices", &vertex_layout);
+
+        assert_eq!(attribute_links.len(), 2);
+        assert!(attribute_links
+            .iter()
+            .all(|attribute_link| *attribute_link.buffer_id() == "vertices"));
+        assert_eq!(*attribute_links[1].attribute_id(), "a_uv");
+    }
+
+    #[test]
+    fn passes_divisors_on_to_links() {
+        let mut vertex_layout = VertexLayout::new();
+        vertex_layout.add_attribute(
+            VertexAttribute::new("a_offset", 2, AttributeType::Float).with_divisor(1),
+        );
+
+        let attribute_links =
+            AttributeLink::<&str, &str, &str>::from_layout("vao", "instances", &vertex_layout);
+
+        assert_eq!(attribute_links[0].divisor(), 1);
+    }
+}
@@ -6,6 +6,9 @@ mod buffer_create_context_js;
 mod buffer_js;
 mod buffer_link;
 mod buffer_link_js;
+mod index_buffer;
+mod index_buffer_link;
+mod instance_buffer;
 
 pub use buffer::*;
 pub use buffer_create_callback::*;
@@ -15,3 +18,6 @@ pub use buffer_create_context_js::*;
 pub use buffer_js::*;
 pub use buffer_link::*;
 pub use buffer_link_js::*;
+pub use index_buffer::*;
+pub use index_buffer_link::*;
+pub use instance_buffer::*;
@@ -1,24 +1,60 @@
-use crate::{BufferJs, BufferJsInner, Id};
+use crate::{BufferJs, BufferJsInner, Id, UpdateBufferError};
+use bytemuck::Pod;
+use std::cell::Cell;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::rc::Rc;
 use wasm_bindgen::JsValue;
-use web_sys::WebGlBuffer;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer};
 
 #[derive(Clone)]
 pub struct Buffer<BufferId: Id> {
     buffer_id: BufferId,
     webgl_buffer: WebGlBuffer,
+    // shared with any `InstanceBuffer` handles, which may reallocate the buffer
+    byte_length: Rc<Cell<usize>>,
+    usage: u32,
 }
 
 impl<BufferId: Id> Buffer<BufferId> {
     // @todo move into builder pattern ?
-    pub fn new(buffer_id: BufferId, webgl_buffer: WebGlBuffer) -> Self {
+    pub fn new(
+        buffer_id: BufferId,
+        webgl_buffer: WebGlBuffer,
+        byte_length: usize,
+        usage: u32,
+    ) -> Self {
         Self {
             buffer_id,
             webgl_buffer,
+            byte_length: Rc::new(Cell::new(byte_length)),
+            usage,
         }
     }
 
+    /// Wraps a buffer that has just been created, reading its size and usage back from the WebGL2 context
+    pub fn from_webgl_buffer(
+        gl: &WebGl2RenderingContext,
+        buffer_id: BufferId,
+        webgl_buffer: WebGlBuffer,
+    ) -> Self {
+        // the copy targets are used here, since buffers of any kind may be bound to them
+        let target = WebGl2RenderingContext::COPY_READ_BUFFER;
+        gl.bind_buffer(target, Some(&webgl_buffer));
+        let byte_length = gl
+            .get_buffer_parameter(target, WebGl2RenderingContext::BUFFER_SIZE)
+            .as_f64()
+            .unwrap_or(0.0) as usize;
+        let usage = gl
+            .get_buffer_parameter(target, WebGl2RenderingContext::BUFFER_USAGE)
+            .as_f64()
+            .map(|usage| usage as u32)
+            .unwrap_or(WebGl2RenderingContext::STATIC_DRAW);
+        gl.bind_buffer(target, None);
+
+        Self::new(buffer_id, webgl_buffer, byte_length, usage)
+    }
+
     pub fn buffer_id(&self) -> &BufferId {
         &self.buffer_id
     }
@@ -26,6 +62,109 @@ impl<BufferId: Id> Buffer<BufferId> {
     pub fn webgl_buffer(&self) -> &WebGlBuffer {
         &self.webgl_buffer
     }
+
+    /// The number of bytes currently allocated for the buffer
+    pub fn byte_length(&self) -> usize {
+        self.byte_length.get()
+    }
+
+    /// The usage hint that the buffer was allocated with, such as `STATIC_DRAW`
+    pub fn usage(&self) -> u32 {
+        self.usage
+    }
+
+    pub(crate) fn byte_length_cell(&self) -> Rc<Cell<usize>> {
+        Rc::clone(&self.byte_length)
+    }
+
+    /// Overwrites part of the buffer with `data`, starting `offset` bytes into the buffer.
+    ///
+    /// Returns an error rather than writing anything if `data` does not fit within the buffer.
+    pub fn update<V: Pod>(
+        &self,
+        gl: &WebGl2RenderingContext,
+        offset: usize,
+        data: &[V],
+    ) -> Result<(), UpdateBufferError> {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        let buffer_byte_length = self.byte_length();
+        if !fits_within(offset, bytes.len(), buffer_byte_length) {
+            return Err(UpdateBufferError::OutOfBounds {
+                buffer_id: format!("{:?}", self.buffer_id),
+                offset,
+                byte_length: bytes.len(),
+                buffer_byte_length,
+            });
+        }
+
+        let target = WebGl2RenderingContext::COPY_WRITE_BUFFER;
+        gl.bind_buffer(target, Some(&self.webgl_buffer));
+        gl.buffer_sub_data_with_i32_and_u8_array(target, offset as i32, bytes);
+        gl.bind_buffer(target, None);
+
+        Ok(())
+    }
+
+    /// Reallocates the buffer to hold `byte_length` bytes, keeping its usage hint.
+    ///
+    /// As much of the existing contents as fits is kept, and any newly allocated bytes are zeroed.
+    /// The `WebGlBuffer` itself is kept, so VAOs that read from it do not need to be updated.
+    pub fn resize(&self, gl: &WebGl2RenderingContext, byte_length: usize) {
+        let kept_byte_length = byte_length.min(self.byte_length()) as i32;
+        let target = WebGl2RenderingContext::COPY_WRITE_BUFFER;
+
+        // copy the contents aside, since reallocating the buffer discards them
+        let staging_buffer = (kept_byte_length > 0).then(|| gl.create_buffer()).flatten();
+        if let Some(staging_buffer) = &staging_buffer {
+            gl.bind_buffer(target, Some(staging_buffer));
+            gl.buffer_data_with_i32(
+                target,
+                kept_byte_length,
+                WebGl2RenderingContext::STREAM_COPY,
+            );
+            gl.bind_buffer(
+                WebGl2RenderingContext::COPY_READ_BUFFER,
+                Some(&self.webgl_buffer),
+            );
+            gl.copy_buffer_sub_data_with_i32_and_i32_and_i32(
+                WebGl2RenderingContext::COPY_READ_BUFFER,
+                target,
+                0,
+                0,
+                kept_byte_length,
+            );
+        }
+
+        gl.bind_buffer(target, Some(&self.webgl_buffer));
+        gl.buffer_data_with_i32(target, byte_length as i32, self.usage);
+
+        if let Some(staging_buffer) = &staging_buffer {
+            gl.bind_buffer(
+                WebGl2RenderingContext::COPY_READ_BUFFER,
+                Some(staging_buffer),
+            );
+            gl.copy_buffer_sub_data_with_i32_and_i32_and_i32(
+                WebGl2RenderingContext::COPY_READ_BUFFER,
+                target,
+                0,
+                0,
+                kept_byte_length,
+            );
+            gl.delete_buffer(Some(staging_buffer));
+        }
+
+        gl.bind_buffer(WebGl2RenderingContext::COPY_READ_BUFFER, None);
+        gl.bind_buffer(target, None);
+        self.byte_length.set(byte_length);
+    }
+}
+
+/// Whether `byte_length` bytes written `offset` bytes into a buffer of `buffer_byte_length` bytes
+/// stay within the buffer (an `offset + byte_length` that overflows never fits)
+pub(crate) fn fits_within(offset: usize, byte_length: usize, buffer_byte_length: usize) -> bool {
+    offset
+        .checked_add(byte_length)
+        .is_some_and(|end| end <= buffer_byte_length)
 }
 
 impl<BufferId: Id> Debug for Buffer<BufferId> {
@@ -33,6 +172,8 @@ impl<BufferId: Id> Debug for Buffer<BufferId> {
         f.debug_struct("Buffer")
             .field("buffer_id", &self.buffer_id)
             .field("webgl_buffer", &self.webgl_buffer)
+            .field("byte_length", &self.byte_length())
+            .field("usage", &self.usage)
             .finish()
     }
 }
@@ -56,3 +197,20 @@ impl From<BufferJsInner> for JsValue {
         js_buffer.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_writes_against_the_buffer_bounds() {
+        assert!(fits_within(0, 4, 16));
+        assert!(fits_within(4, 8, 16));
+        assert!(fits_within(12, 4, 16));
+        assert!(fits_within(16, 0, 16));
+        assert!(!fits_within(13, 4, 16));
+        assert!(!fits_within(17, 0, 16));
+        assert!(!fits_within(usize::MAX, 1, 16));
+        assert!(!fits_within(1, usize::MAX, usize::MAX));
+    }
+}
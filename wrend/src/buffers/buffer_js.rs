@@ -19,6 +19,15 @@ impl BufferJs {
     pub fn webgl_buffer(&self) -> WebGlBuffer {
         self.deref().webgl_buffer().to_owned()
     }
+
+    #[wasm_bindgen(js_name = byteLength)]
+    pub fn byte_length(&self) -> usize {
+        self.deref().byte_length()
+    }
+
+    pub fn usage(&self) -> u32 {
+        self.deref().usage()
+    }
 }
 
 impl Deref for BufferJs {
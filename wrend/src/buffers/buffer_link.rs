@@ -1,5 +1,6 @@
 use super::buffer_create_context::BufferCreateContext;
-use crate::{BufferCreateCallback, Id};
+use crate::{BufferCreateCallback, BufferUsage, Id};
+use bytemuck::Pod;
 use std::fmt::Debug;
 use std::hash::Hash;
 use web_sys::{WebGl2RenderingContext, WebGlBuffer};
@@ -21,6 +22,37 @@ impl<BufferId: Id> BufferLink<BufferId> {
         }
     }
 
+    /// Creates an `ARRAY_BUFFER` that holds one `V` per instance, for use with per-instance attributes
+    /// (see [crate::AttributeLink::set_divisor]).
+    ///
+    /// The buffer starts out holding `instances`, and can be refilled afterward through
+    /// [crate::RendererData::instance_buffer].
+    pub fn from_instances<V: Pod>(buffer_id: impl Into<BufferId>, instances: &[V]) -> Self {
+        Self::from_data(buffer_id, instances, BufferUsage::DynamicDraw)
+    }
+
+    /// Creates an `ARRAY_BUFFER` that starts out holding `data`.
+    ///
+    /// The buffer can be updated in place afterward with [crate::RendererData::update_buffer].
+    pub fn from_data<V: Pod>(
+        buffer_id: impl Into<BufferId>,
+        data: &[V],
+        usage: BufferUsage,
+    ) -> Self {
+        let bytes: Vec<u8> = bytemuck::cast_slice(data).to_vec();
+        let usage: u32 = usage.into();
+        Self::new(buffer_id, move |ctx: &BufferCreateContext| {
+            let gl = ctx.gl();
+            let webgl_buffer = gl
+                .create_buffer()
+                .expect("WebGL2 should be able to create a buffer");
+            gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&webgl_buffer));
+            gl.buffer_data_with_u8_array(WebGl2RenderingContext::ARRAY_BUFFER, &bytes, usage);
+            gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+            webgl_buffer
+        })
+    }
+
     pub fn buffer_id(&self) -> &BufferId {
         &self.buffer_id
     }
@@ -0,0 +1,91 @@
+use crate::Id;
+use std::fmt::Debug;
+use std::hash::Hash;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer};
+
+/// An index (`ELEMENT_ARRAY_BUFFER`) buffer that has been attached to one or more VAOs,
+/// along with everything needed to draw with it.
+#[derive(Clone)]
+pub struct IndexBuffer<VertexArrayObjectId: Id, BufferId: Id> {
+    vao_ids: Vec<VertexArrayObjectId>,
+    buffer_id: BufferId,
+    webgl_buffer: WebGlBuffer,
+    index_type: u32,
+    count: i32,
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> IndexBuffer<VertexArrayObjectId, BufferId> {
+    /// Wraps an index buffer holding `count` indices of type `index_type`
+    pub fn new(
+        vao_ids: Vec<VertexArrayObjectId>,
+        buffer_id: BufferId,
+        webgl_buffer: WebGlBuffer,
+        index_type: u32,
+        count: i32,
+    ) -> Self {
+        Self {
+            vao_ids,
+            buffer_id,
+            webgl_buffer,
+            index_type,
+            count,
+        }
+    }
+
+    /// The VAOs that the buffer is attached to
+    pub fn vao_ids(&self) -> &[VertexArrayObjectId] {
+        &self.vao_ids
+    }
+
+    /// The id the buffer was saved under
+    pub fn buffer_id(&self) -> &BufferId {
+        &self.buffer_id
+    }
+
+    /// The underlying WebGL buffer
+    pub fn webgl_buffer(&self) -> &WebGlBuffer {
+        &self.webgl_buffer
+    }
+
+    /// The type of each index: `UNSIGNED_BYTE`, `UNSIGNED_SHORT` or `UNSIGNED_INT`
+    pub fn index_type(&self) -> u32 {
+        self.index_type
+    }
+
+    /// The number of indices in the buffer
+    pub fn count(&self) -> i32 {
+        self.count
+    }
+
+    /// Draws every index in the buffer with the VAO that is currently bound
+    pub fn draw(&self, gl: &WebGl2RenderingContext, mode: u32) {
+        gl.draw_elements_with_i32(mode, self.count, self.index_type, 0);
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> Debug for IndexBuffer<VertexArrayObjectId, BufferId> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexBuffer")
+            .field("vao_ids", &self.vao_ids)
+            .field("buffer_id", &self.buffer_id)
+            .field("index_type", &self.index_type)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> Hash for IndexBuffer<VertexArrayObjectId, BufferId> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.buffer_id.hash(state);
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> PartialEq
+    for IndexBuffer<VertexArrayObjectId, BufferId>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.buffer_id == other.buffer_id && self.webgl_buffer == other.webgl_buffer
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> Eq for IndexBuffer<VertexArrayObjectId, BufferId> {}
@@ -0,0 +1,156 @@
+use crate::{Bridge, Id};
+use js_sys::{Uint16Array, Uint32Array, Uint8Array};
+use std::fmt::Debug;
+use std::hash::Hash;
+use web_sys::WebGl2RenderingContext;
+
+/// The indices stored in an index (`ELEMENT_ARRAY_BUFFER`) buffer, along with their type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IndexData {
+    /// `UNSIGNED_BYTE` indices
+    U8(Vec<u8>),
+    /// `UNSIGNED_SHORT` indices
+    U16(Vec<u16>),
+    /// `UNSIGNED_INT` indices
+    U32(Vec<u32>),
+}
+
+impl IndexData {
+    /// The type of each index, as passed to `drawElements`
+    pub fn index_type(&self) -> u32 {
+        match self {
+            IndexData::U8(_) => WebGl2RenderingContext::UNSIGNED_BYTE,
+            IndexData::U16(_) => WebGl2RenderingContext::UNSIGNED_SHORT,
+            IndexData::U32(_) => WebGl2RenderingContext::UNSIGNED_INT,
+        }
+    }
+
+    /// The number of indices
+    pub fn len(&self) -> usize {
+        match self {
+            IndexData::U8(indices) => indices.len(),
+            IndexData::U16(indices) => indices.len(),
+            IndexData::U32(indices) => indices.len(),
+        }
+    }
+
+    /// Whether there are no indices
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Uploads the indices to the buffer currently bound to `ELEMENT_ARRAY_BUFFER`
+    pub fn upload(&self, gl: &WebGl2RenderingContext) {
+        let target = WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER;
+        let usage = WebGl2RenderingContext::STATIC_DRAW;
+        match self {
+            IndexData::U8(indices) => gl.buffer_data_with_array_buffer_view(
+                target,
+                &Uint8Array::from(&indices[..]),
+                usage,
+            ),
+            IndexData::U16(indices) => gl.buffer_data_with_array_buffer_view(
+                target,
+                &Uint16Array::from(&indices[..]),
+                usage,
+            ),
+            IndexData::U32(indices) => gl.buffer_data_with_array_buffer_view(
+                target,
+                &Uint32Array::from(&indices[..]),
+                usage,
+            ),
+        }
+    }
+}
+
+impl From<Vec<u8>> for IndexData {
+    fn from(indices: Vec<u8>) -> Self {
+        IndexData::U8(indices)
+    }
+}
+
+impl From<Vec<u16>> for IndexData {
+    fn from(indices: Vec<u16>) -> Self {
+        IndexData::U16(indices)
+    }
+}
+
+impl From<Vec<u32>> for IndexData {
+    fn from(indices: Vec<u32>) -> Self {
+        IndexData::U32(indices)
+    }
+}
+
+/// This link is used to create an index (`ELEMENT_ARRAY_BUFFER`) buffer and attach it to one or more VAOs,
+/// so that the meshes in those VAOs can be drawn with [crate::RendererData::draw_elements].
+///
+/// Index buffers share their ids with the buffers created from [crate::BufferLink]s.
+#[derive(Clone)]
+pub struct IndexBufferLink<VertexArrayObjectId: Id, BufferId: Id> {
+    vao_ids: Vec<VertexArrayObjectId>,
+    buffer_id: BufferId,
+    indices: IndexData,
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> IndexBufferLink<VertexArrayObjectId, BufferId> {
+    /// Creates a link for an index buffer holding `indices`, attached to every VAO in `vao_ids`
+    pub fn new(
+        vao_ids: impl Into<Bridge<VertexArrayObjectId>>,
+        buffer_id: BufferId,
+        indices: impl Into<IndexData>,
+    ) -> Self {
+        let vao_ids_bridge = vao_ids.into();
+        Self {
+            vao_ids: vao_ids_bridge.into(),
+            buffer_id,
+            indices: indices.into(),
+        }
+    }
+
+    /// The VAOs that the buffer is attached to
+    pub fn vao_ids(&self) -> &[VertexArrayObjectId] {
+        &self.vao_ids
+    }
+
+    /// The id the buffer is saved under
+    pub fn buffer_id(&self) -> &BufferId {
+        &self.buffer_id
+    }
+
+    /// The indices that the buffer is filled with
+    pub fn indices(&self) -> &IndexData {
+        &self.indices
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> Debug
+    for IndexBufferLink<VertexArrayObjectId, BufferId>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexBufferLink")
+            .field("vao_ids", &self.vao_ids)
+            .field("buffer_id", &self.buffer_id)
+            .field("index_type", &self.indices.index_type())
+            .field("count", &self.indices.len())
+            .finish()
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> Hash
+    for IndexBufferLink<VertexArrayObjectId, BufferId>
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.vao_ids.hash(state);
+        self.buffer_id.hash(state);
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> PartialEq
+    for IndexBufferLink<VertexArrayObjectId, BufferId>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.vao_ids == other.vao_ids && self.buffer_id == other.buffer_id
+    }
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id> Eq for IndexBufferLink<VertexArrayObjectId, BufferId> {}
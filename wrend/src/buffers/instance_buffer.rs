@@ -0,0 +1,84 @@
+use bytemuck::Pod;
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer};
+
+/// A handle to a per-instance buffer created with [crate::BufferLink::from_instances],
+/// typed by the struct (or array) that holds the data for a single instance.
+///
+/// Its contents can be replaced every frame (for example, to move 100k markers at once)
+/// before drawing them all with [crate::RendererData::draw_instanced].
+pub struct InstanceBuffer<V: Pod> {
+    gl: WebGl2RenderingContext,
+    webgl_buffer: WebGlBuffer,
+    byte_length: Rc<Cell<usize>>,
+    _instance: PhantomData<fn(V)>,
+}
+
+impl<V: Pod> InstanceBuffer<V> {
+    pub(crate) fn new(
+        gl: WebGl2RenderingContext,
+        webgl_buffer: WebGlBuffer,
+        byte_length: Rc<Cell<usize>>,
+    ) -> Self {
+        Self {
+            gl,
+            webgl_buffer,
+            byte_length,
+            _instance: PhantomData,
+        }
+    }
+
+    /// The underlying WebGL buffer
+    pub fn webgl_buffer(&self) -> &WebGlBuffer {
+        &self.webgl_buffer
+    }
+
+    /// The number of instances that the buffer currently holds
+    pub fn len(&self) -> usize {
+        self.byte_length.get() / std::mem::size_of::<V>().max(1)
+    }
+
+    /// Whether the buffer holds no instances
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Replaces the entire contents of the buffer with `instances`, reallocating it if their size has changed
+    pub fn update(&self, instances: &[V]) {
+        let bytes: &[u8] = bytemuck::cast_slice(instances);
+        self.gl.bind_buffer(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            Some(&self.webgl_buffer),
+        );
+        self.gl.buffer_data_with_u8_array(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            bytes,
+            WebGl2RenderingContext::DYNAMIC_DRAW,
+        );
+        self.gl
+            .bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+        self.byte_length.set(bytes.len());
+    }
+}
+
+impl<V: Pod> Clone for InstanceBuffer<V> {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.gl.clone(),
+            self.webgl_buffer.clone(),
+            Rc::clone(&self.byte_length),
+        )
+    }
+}
+
+impl<V: Pod> Debug for InstanceBuffer<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstanceBuffer")
+            .field("webgl_buffer", &self.webgl_buffer)
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -1,4 +1,6 @@
+mod depth_stencil_attachment;
 mod framebuffer;
+mod framebuffer_attachment;
 mod framebuffer_create_callback;
 mod framebuffer_create_callback_js;
 mod framebuffer_create_context;
@@ -7,7 +9,9 @@ mod framebuffer_js;
 mod framebuffer_link;
 mod framebuffer_link_js;
 
+pub use depth_stencil_attachment::*;
 pub use framebuffer::*;
+pub use framebuffer_attachment::*;
 pub use framebuffer_create_callback::*;
 pub use framebuffer_create_callback_js::*;
 pub use framebuffer_create_context::*;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::wasm_bindgen;
+use web_sys::WebGl2RenderingContext;
+
+/// Which attachment point a framebuffer's depth and/or stencil texture is attached to
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum DepthStencilAttachment {
+    #[default]
+    Depth,
+    Stencil,
+    DepthStencil,
+}
+
+impl From<DepthStencilAttachment> for u32 {
+    fn from(depth_stencil_attachment: DepthStencilAttachment) -> Self {
+        match depth_stencil_attachment {
+            DepthStencilAttachment::Depth => WebGl2RenderingContext::DEPTH_ATTACHMENT,
+            DepthStencilAttachment::Stencil => WebGl2RenderingContext::STENCIL_ATTACHMENT,
+            DepthStencilAttachment::DepthStencil => {
+                WebGl2RenderingContext::DEPTH_STENCIL_ATTACHMENT
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// Something that can be attached to a framebuffer: either a texture, which can be sampled afterward,
+/// or a renderbuffer, which can only be rendered into (and read back / blitted from)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FramebufferAttachment<TextureId: Id, RenderbufferId: Id> {
+    /// A texture, created from a [crate::TextureLink]
+    Texture(TextureId),
+    /// A renderbuffer, created from a [crate::RenderbufferLink]
+    Renderbuffer(RenderbufferId),
+}
+
+impl<TextureId: Id, RenderbufferId: Id> FramebufferAttachment<TextureId, RenderbufferId> {
+    /// The attached texture's id, if this attachment is a texture
+    pub fn texture_id(&self) -> Option<&TextureId> {
+        match self {
+            FramebufferAttachment::Texture(texture_id) => Some(texture_id),
+            FramebufferAttachment::Renderbuffer(_) => None,
+        }
+    }
+
+    /// The attached renderbuffer's id, if this attachment is a renderbuffer
+    pub fn renderbuffer_id(&self) -> Option<&RenderbufferId> {
+        match self {
+            FramebufferAttachment::Texture(_) => None,
+            FramebufferAttachment::Renderbuffer(renderbuffer_id) => Some(renderbuffer_id),
+        }
+    }
+}
@@ -1,16 +1,36 @@
-use crate::{FramebufferCreateCallback, FramebufferCreateContext, Id, IdDefault};
+use crate::{
+    Bridge, DepthStencilAttachment, FramebufferAttachment, FramebufferCreateCallback,
+    FramebufferCreateContext, Id, IdDefault,
+};
 use std::fmt::Debug;
 use std::hash::Hash;
 use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
 
+/// This link is used to create a framebuffer and attach textures and renderbuffers to it.
+///
+/// Everything declared on the link is attached automatically once the callback returns:
+/// color attachments to `COLOR_ATTACHMENT0`, `COLOR_ATTACHMENT1`, etc. (with a matching `drawBuffers` call),
+/// and an optional depth / stencil attachment to its [DepthStencilAttachment] point.
+/// The framebuffer is then checked for completeness.
 #[derive(Clone)]
-pub struct FramebufferLink<FramebufferId: Id, TextureId: Id = IdDefault> {
+pub struct FramebufferLink<
+    FramebufferId: Id,
+    TextureId: Id = IdDefault,
+    RenderbufferId: Id = IdDefault,
+> {
     framebuffer_id: FramebufferId,
-    texture_id: Option<TextureId>,
+    color_attachments: Vec<FramebufferAttachment<TextureId, RenderbufferId>>,
+    depth_stencil_attachment: Option<(
+        DepthStencilAttachment,
+        FramebufferAttachment<TextureId, RenderbufferId>,
+    )>,
     framebuffer_create_callback: FramebufferCreateCallback,
 }
 
-impl<FramebufferId: Id, TextureId: Id> FramebufferLink<FramebufferId, TextureId> {
+impl<FramebufferId: Id, TextureId: Id, RenderbufferId: Id>
+    FramebufferLink<FramebufferId, TextureId, RenderbufferId>
+{
+    /// If a `texture_id` is given, it is attached as the framebuffer's `COLOR_ATTACHMENT0`
     pub fn new(
         framebuffer_id: FramebufferId,
         framebuffer_create_callback: impl Into<FramebufferCreateCallback>,
@@ -19,7 +39,33 @@ impl<FramebufferId: Id, TextureId: Id> FramebufferLink<FramebufferId, TextureId>
         Self {
             framebuffer_id,
             framebuffer_create_callback: framebuffer_create_callback.into(),
-            texture_id,
+            color_attachments: texture_id
+                .into_iter()
+                .map(FramebufferAttachment::Texture)
+                .collect(),
+            depth_stencil_attachment: None,
+        }
+    }
+
+    /// Creates a framebuffer with the given color textures attached, without needing a callback
+    pub fn from_attachments(
+        framebuffer_id: FramebufferId,
+        color_texture_ids: impl Into<Bridge<TextureId>>,
+    ) -> Self {
+        let color_texture_ids: Vec<TextureId> = color_texture_ids.into().into();
+        Self {
+            framebuffer_id,
+            framebuffer_create_callback: (|ctx: &FramebufferCreateContext| {
+                ctx.gl()
+                    .create_framebuffer()
+                    .expect("WebGL2 should be able to create a framebuffer")
+            })
+            .into(),
+            color_attachments: color_texture_ids
+                .into_iter()
+                .map(FramebufferAttachment::Texture)
+                .collect(),
+            depth_stencil_attachment: None,
         }
     }
 
@@ -27,8 +73,91 @@ impl<FramebufferId: Id, TextureId: Id> FramebufferLink<FramebufferId, TextureId>
         &self.framebuffer_id
     }
 
+    /// The texture attached to `COLOR_ATTACHMENT0`, if any
     pub fn texture_id(&self) -> Option<TextureId> {
-        self.texture_id.clone()
+        self.color_attachments
+            .first()
+            .and_then(FramebufferAttachment::texture_id)
+            .cloned()
+    }
+
+    /// The textures and renderbuffers attached to `COLOR_ATTACHMENT0`, `COLOR_ATTACHMENT1`, etc., in order
+    pub fn color_attachments(&self) -> &[FramebufferAttachment<TextureId, RenderbufferId>] {
+        &self.color_attachments
+    }
+
+    /// Attaches a texture to the next `COLOR_ATTACHMENTn`
+    pub fn add_color_attachment(&mut self, texture_id: TextureId) -> &mut Self {
+        self.color_attachments
+            .push(FramebufferAttachment::Texture(texture_id));
+        self
+    }
+
+    /// Attaches a renderbuffer to the next `COLOR_ATTACHMENTn` (e.g. a multisampled render target)
+    pub fn add_color_renderbuffer(&mut self, renderbuffer_id: RenderbufferId) -> &mut Self {
+        self.color_attachments
+            .push(FramebufferAttachment::Renderbuffer(renderbuffer_id));
+        self
+    }
+
+    /// The depth, stencil or depth-stencil attachment, and where it is attached
+    pub fn depth_stencil_attachment(
+        &self,
+    ) -> Option<(
+        DepthStencilAttachment,
+        &FramebufferAttachment<TextureId, RenderbufferId>,
+    )> {
+        self.depth_stencil_attachment
+            .as_ref()
+            .map(|(attachment, framebuffer_attachment)| (*attachment, framebuffer_attachment))
+    }
+
+    /// Attaches a depth, stencil or depth-stencil texture to the framebuffer
+    pub fn set_depth_stencil_attachment(
+        &mut self,
+        attachment: DepthStencilAttachment,
+        texture_id: TextureId,
+    ) -> &mut Self {
+        self.depth_stencil_attachment =
+            Some((attachment, FramebufferAttachment::Texture(texture_id)));
+        self
+    }
+
+    /// Attaches a depth, stencil or depth-stencil renderbuffer to the framebuffer,
+    /// for when the depth / stencil values never need to be sampled
+    pub fn set_depth_stencil_renderbuffer(
+        &mut self,
+        attachment: DepthStencilAttachment,
+        renderbuffer_id: RenderbufferId,
+    ) -> &mut Self {
+        self.depth_stencil_attachment = Some((
+            attachment,
+            FramebufferAttachment::Renderbuffer(renderbuffer_id),
+        ));
+        self
+    }
+
+    /// Every color and depth / stencil attachment, in the order that they are attached
+    fn attachments(
+        &self,
+    ) -> impl Iterator<Item = &FramebufferAttachment<TextureId, RenderbufferId>> {
+        self.color_attachments.iter().chain(
+            self.depth_stencil_attachment
+                .as_ref()
+                .map(|(_, framebuffer_attachment)| framebuffer_attachment),
+        )
+    }
+
+    /// Every texture that is attached to the framebuffer, whether as a color or a depth / stencil attachment
+    pub fn attached_texture_ids(&self) -> impl Iterator<Item = &TextureId> {
+        self.attachments()
+            .filter_map(FramebufferAttachment::texture_id)
+    }
+
+    /// Every renderbuffer that is attached to the framebuffer, whether as a color or a depth / stencil attachment
+    pub fn attached_renderbuffer_ids(&self) -> impl Iterator<Item = &RenderbufferId> {
+        self.attachments()
+            .filter_map(FramebufferAttachment::renderbuffer_id)
     }
 
     pub fn create_framebuffer(
@@ -43,24 +172,35 @@ impl<FramebufferId: Id, TextureId: Id> FramebufferLink<FramebufferId, TextureId>
     }
 }
 
-impl<FramebufferId: Id, TextureId: Id> Debug for FramebufferLink<FramebufferId, TextureId> {
+impl<FramebufferId: Id, TextureId: Id, RenderbufferId: Id> Debug
+    for FramebufferLink<FramebufferId, TextureId, RenderbufferId>
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FramebufferLink")
             .field("framebuffer_id", &self.framebuffer_id)
+            .field("color_attachments", &self.color_attachments)
+            .field("depth_stencil_attachment", &self.depth_stencil_attachment)
             .finish()
     }
 }
 
-impl<FramebufferId: Id, TextureId: Id> Hash for FramebufferLink<FramebufferId, TextureId> {
+impl<FramebufferId: Id, TextureId: Id, RenderbufferId: Id> Hash
+    for FramebufferLink<FramebufferId, TextureId, RenderbufferId>
+{
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.framebuffer_id.hash(state);
     }
 }
 
-impl<FramebufferId: Id, TextureId: Id> PartialEq for FramebufferLink<FramebufferId, TextureId> {
+impl<FramebufferId: Id, TextureId: Id, RenderbufferId: Id> PartialEq
+    for FramebufferLink<FramebufferId, TextureId, RenderbufferId>
+{
     fn eq(&self, other: &Self) -> bool {
         self.framebuffer_id == other.framebuffer_id
     }
 }
 
-impl<FramebufferId: Id, TextureId: Id> Eq for FramebufferLink<FramebufferId, TextureId> {}
+impl<FramebufferId: Id, TextureId: Id, RenderbufferId: Id> Eq
+    for FramebufferLink<FramebufferId, TextureId, RenderbufferId>
+{
+}
@@ -3,9 +3,12 @@ use std::ops::{Deref, DerefMut};
 use wasm_bindgen::prelude::wasm_bindgen;
 use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
 
-use crate::{FramebufferCreateCallbackJs, FramebufferLink};
+use crate::{
+    utils, DepthStencilAttachment, FramebufferAttachment, FramebufferCreateCallbackJs,
+    FramebufferLink, StringArray,
+};
 
-pub type FramebufferLinkJsInner = FramebufferLink<String, String>;
+pub type FramebufferLinkJsInner = FramebufferLink<String, String, String>;
 
 #[wasm_bindgen(inspectable, js_name = FramebufferLink)]
 #[derive(Clone)]
@@ -36,6 +39,50 @@ impl FramebufferLinkJs {
         self.deref().texture_id()
     }
 
+    #[wasm_bindgen(js_name = colorTextureIds)]
+    pub fn color_texture_ids(&self) -> StringArray {
+        let color_texture_ids: Vec<_> = self
+            .deref()
+            .color_attachments()
+            .iter()
+            .filter_map(FramebufferAttachment::texture_id)
+            .cloned()
+            .collect();
+        utils::strings_to_js_array(&color_texture_ids)
+    }
+
+    #[wasm_bindgen(js_name = addColorAttachment)]
+    pub fn add_color_attachment(&mut self, texture_id: String) {
+        self.deref_mut().add_color_attachment(texture_id);
+    }
+
+    /// Attaches a renderbuffer to the next `COLOR_ATTACHMENTn`
+    #[wasm_bindgen(js_name = addColorRenderbuffer)]
+    pub fn add_color_renderbuffer(&mut self, renderbuffer_id: String) {
+        self.deref_mut().add_color_renderbuffer(renderbuffer_id);
+    }
+
+    #[wasm_bindgen(js_name = setDepthStencilAttachment)]
+    pub fn set_depth_stencil_attachment(
+        &mut self,
+        attachment: DepthStencilAttachment,
+        texture_id: String,
+    ) {
+        self.deref_mut()
+            .set_depth_stencil_attachment(attachment, texture_id);
+    }
+
+    /// Attaches a depth, stencil or depth-stencil renderbuffer to the framebuffer
+    #[wasm_bindgen(js_name = setDepthStencilRenderbuffer)]
+    pub fn set_depth_stencil_renderbuffer(
+        &mut self,
+        attachment: DepthStencilAttachment,
+        renderbuffer_id: String,
+    ) {
+        self.deref_mut()
+            .set_depth_stencil_renderbuffer(attachment, renderbuffer_id);
+    }
+
     #[wasm_bindgen(js_name = createFramebuffer)]
     pub fn create_framebuffer(
         &self,
@@ -1,7 +1,9 @@
 mod id;
 mod id_default;
+mod id_kind;
 mod id_name;
 
 pub use id::*;
 pub use id_default::*;
+pub use id_kind::*;
 pub use id_name::*;
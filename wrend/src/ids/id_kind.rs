@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Identifies which kind of resource an id refers to (used when reporting id-related errors
+/// and when describing the pipeline)
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum IdKind {
+    /// A vertex shader
+    VertexShader,
+    /// A fragment shader
+    FragmentShader,
+    /// A linked program
+    Program,
+    /// A uniform
+    Uniform,
+    /// A uniform block
+    UniformBlock,
+    /// A buffer, including index buffers
+    Buffer,
+    /// A vertex attribute
+    Attribute,
+    /// A texture
+    Texture,
+    /// A framebuffer
+    Framebuffer,
+    /// A renderbuffer
+    Renderbuffer,
+    /// A transform feedback object
+    TransformFeedback,
+    /// A vertex array object
+    VertexArrayObject,
+}
@@ -31,16 +31,28 @@
 //!
 //! Most resources such as shaders, [`Uniform`]s and [`Attribute`]s retrieve resources using unique [`Id`]s, which can be
 //! any data type that implements the [`Id`] trait. These Ids help Wrend understand how your data fits together.
+//! All of the id types used by a single pipeline are bundled together by implementing [`RendererTypes`],
+//! so that the [`RendererData`], [`Renderer`], and callbacks only need to be given that one type.
 //!
 //! For example, you can load shaders into the build pipeline using [`RendererDataBuilder::add_vertex_shader_src`].
 //! Then, when creating a [`ProgramLink`], you can refer to that shader using its `VertexShaderId` to link that shader
 //! to any number of programs you create.
 //!
+//! With the `derive` feature enabled, [`Id`] and [`IdName`] can be derived for your own enums and structs
+//! (for example: `#[derive(Id, IdName, ...)] #[id_name(prefix = "u_", case = "snake")] enum UniformId { ... }`).
+//! [`Std140`] can also be derived for structs that are uploaded to a uniform block with a [`UniformBlockLink`],
+//! and [`Uniforms`] for structs that mirror GLSL struct uniforms (see [`UniformLink::array_of`]).
+//!
 //! ### Build
 //!
 //! Once all resources and `links` have been added to the [RendererDataBuilder], the pipeline can be built
 //! on using [RendererDataBuilder::build_renderer].
 //!
+//! Shaders and texture images can also be added by URL (see [RendererDataBuilder::add_vertex_shader_url]),
+//! in which case the pipeline is built with [RendererDataBuilder::build_renderer_async], which loads every
+//! asset before running the normal build steps. Async builds also compile shaders in parallel when the
+//! `KHR_parallel_shader_compile` extension is available, rather than blocking the main thread on each one.
+//!
 //! # Panics
 //!
 //! There are very few locations in which Rust code can panic in `wrend`, and those that exist are being slimmed down.
@@ -61,7 +73,7 @@
 //! use web_sys::{window, HtmlCanvasElement, WebGl2RenderingContext};
 //! use wrend::{
 //!     AttributeCreateContext, AttributeLink, BufferCreateContext, BufferLink, Id, IdDefault, IdName,
-//!     ProgramLink, Renderer, RendererData,
+//!     ProgramLink, Renderer, RendererData, RendererTypes,
 //! };
 //!
 //! #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
@@ -121,6 +133,25 @@
 //!     count: u32,
 //! }
 //!
+//! #[derive(Clone, Copy, Debug)]
+//! struct AppTypes;
+//!
+//! impl RendererTypes for AppTypes {
+//!     type VertexShaderId = VertexShaderId;
+//!     type FragmentShaderId = FragmentShaderId;
+//!     type ProgramId = ProgramId;
+//!     type UniformId = IdDefault;
+//!     type UniformBlockId = IdDefault;
+//!     type BufferId = BufferId;
+//!     type AttributeId = PositionAttributeId;
+//!     type TextureId = IdDefault;
+//!     type FramebufferId = IdDefault;
+//!     type RenderbufferId = IdDefault;
+//!     type TransformFeedbackId = IdDefault;
+//!     type VertexArrayObjectId = VaoId;
+//!     type UserCtx = AppState;
+//! }
+//!
 //! const VERTEX_SHADER: &str = r#"#version 300 es
 //! in vec2 a_position;
 //! out vec2 v_position;
@@ -192,19 +223,7 @@
 //!         },
 //!     );
 //!
-//!     let render_callback = |renderer_data: &RendererData<
-//!         VertexShaderId,
-//!         FragmentShaderId,
-//!         ProgramId,
-//!         IdDefault,
-//!         BufferId,
-//!         PositionAttributeId,
-//!         IdDefault,
-//!         IdDefault,
-//!         IdDefault,
-//!         VaoId,
-//!         AppState,
-//!     >| {
+//!     let render_callback = |renderer_data: &RendererData<AppTypes>| {
 //!         let gl = renderer_data.gl();
 //!         let canvas: HtmlCanvasElement = gl.canvas().unwrap().dyn_into().unwrap();
 //!
@@ -217,7 +236,7 @@
 //!         gl.draw_arrays(WebGl2RenderingContext::TRIANGLES, 0, 3);
 //!     };
 //!
-//!     let mut render_builder = Renderer::builder();
+//!     let mut render_builder = Renderer::<AppTypes>::builder();
 //!
 //!     render_builder
 //!         .set_canvas(canvas)
@@ -250,6 +269,7 @@
 //! That is, no *new* textures, buffers, uniforms can be added after the pipeline has been initialized.
 
 mod animation;
+mod assets;
 mod attributes;
 mod buffers;
 mod callbacks;
@@ -257,20 +277,25 @@ mod constants;
 mod framebuffers;
 mod ids;
 mod math;
+mod pipeline_descriptors;
+mod pipeline_graphs;
 mod programs;
 mod recording;
+mod renderbuffers;
 mod renderer_data;
 mod renderers;
 mod shaders;
 mod textures;
 mod transform_feedback;
 mod types;
+mod uniform_blocks;
 mod uniforms;
 mod utils;
 
 pub(crate) use recording::*;
 
 pub use animation::*;
+pub use assets::*;
 pub use attributes::*;
 pub use buffers::*;
 pub use callbacks::*;
@@ -278,12 +303,19 @@ pub use constants::*;
 pub use framebuffers::*;
 pub use ids::*;
 pub use math::*;
+pub use pipeline_descriptors::*;
+pub use pipeline_graphs::*;
 pub use programs::*;
+pub use renderbuffers::*;
 pub use renderer_data::*;
 pub use renderers::*;
 pub use shaders::*;
 pub use textures::*;
 pub use transform_feedback::*;
 pub use types::*;
+pub use uniform_blocks::*;
 pub use uniforms::*;
 pub use utils::*;
+
+#[cfg(feature = "derive")]
+pub use wrend_derive::{Id, IdName, Std140, Uniforms};
@@ -0,0 +1,19 @@
+mod attribute_descriptor;
+mod buffer_descriptor;
+mod framebuffer_descriptor;
+mod pipeline_descriptor;
+mod program_descriptor;
+mod renderbuffer_descriptor;
+mod shader_descriptor;
+mod texture_descriptor;
+mod texture_formats;
+
+pub use attribute_descriptor::*;
+pub use buffer_descriptor::*;
+pub use framebuffer_descriptor::*;
+pub use pipeline_descriptor::*;
+pub use program_descriptor::*;
+pub use renderbuffer_descriptor::*;
+pub use shader_descriptor::*;
+pub use texture_descriptor::*;
+pub use texture_formats::*;
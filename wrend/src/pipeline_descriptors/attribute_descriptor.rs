@@ -0,0 +1,150 @@
+use crate::{AttributeCreateContext, AttributeLink, Id, IdName};
+use serde::{Deserialize, Serialize};
+use web_sys::WebGl2RenderingContext;
+
+/// The component type of a described attribute, as passed to `vertexAttribPointer`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum AttributeType {
+    /// 32-bit floats (`FLOAT`)
+    #[default]
+    Float,
+    /// 16-bit floats (`HALF_FLOAT`)
+    HalfFloat,
+    /// Signed 8-bit integers (`BYTE`)
+    Byte,
+    /// Unsigned 8-bit integers (`UNSIGNED_BYTE`)
+    UnsignedByte,
+    /// Signed 16-bit integers (`SHORT`)
+    Short,
+    /// Unsigned 16-bit integers (`UNSIGNED_SHORT`)
+    UnsignedShort,
+    /// Signed 32-bit integers (`INT`)
+    Int,
+    /// Unsigned 32-bit integers (`UNSIGNED_INT`)
+    UnsignedInt,
+}
+
+impl AttributeType {
+    /// The number of bytes in a single component of this type
+    pub fn byte_size(&self) -> i32 {
+        match self {
+            AttributeType::Byte | AttributeType::UnsignedByte => 1,
+            AttributeType::HalfFloat | AttributeType::Short | AttributeType::UnsignedShort => 2,
+            AttributeType::Float | AttributeType::Int | AttributeType::UnsignedInt => 4,
+        }
+    }
+}
+
+impl From<AttributeType> for u32 {
+    fn from(attribute_type: AttributeType) -> Self {
+        match attribute_type {
+            AttributeType::Float => WebGl2RenderingContext::FLOAT,
+            AttributeType::HalfFloat => WebGl2RenderingContext::HALF_FLOAT,
+            AttributeType::Byte => WebGl2RenderingContext::BYTE,
+            AttributeType::UnsignedByte => WebGl2RenderingContext::UNSIGNED_BYTE,
+            AttributeType::Short => WebGl2RenderingContext::SHORT,
+            AttributeType::UnsignedShort => WebGl2RenderingContext::UNSIGNED_SHORT,
+            AttributeType::Int => WebGl2RenderingContext::INT,
+            AttributeType::UnsignedInt => WebGl2RenderingContext::UNSIGNED_INT,
+        }
+    }
+}
+
+/// Describes an [AttributeLink] whose layout within its buffer is given as data, rather than
+/// set up in a callback.
+///
+/// If `integer` is `true`, the attribute is read with `vertexAttribIPointer`, so that integer
+/// data is not converted to floats (in which case `normalized` is ignored).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AttributeDescriptor<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> {
+    /// The id of the attribute, whose name is looked up in each program
+    pub attribute_id: AttributeId,
+    /// The buffer that the attribute reads from
+    pub buffer_id: BufferId,
+    /// The VAOs that the attribute is enabled in
+    #[serde(default)]
+    pub vao_ids: Vec<VertexArrayObjectId>,
+    /// The number of components per vertex (1 to 4)
+    pub size: i32,
+    /// The type of each component
+    #[serde(default)]
+    pub data_type: AttributeType,
+    /// Whether integer data is normalized to `[0, 1]` or `[-1, 1]` when converted to floats
+    #[serde(default)]
+    pub normalized: bool,
+    /// Whether the attribute is read as integers with `vertexAttribIPointer`
+    #[serde(default)]
+    pub integer: bool,
+    /// The number of bytes between consecutive vertices, or 0 if they are tightly packed
+    #[serde(default)]
+    pub stride: i32,
+    /// The byte offset of the first component within the buffer
+    #[serde(default)]
+    pub offset: i32,
+}
+
+impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
+    AttributeDescriptor<VertexArrayObjectId, BufferId, AttributeId>
+{
+    /// Creates a descriptor with tightly packed, unnormalized float components
+    pub fn new(
+        vao_ids: Vec<VertexArrayObjectId>,
+        buffer_id: BufferId,
+        attribute_id: AttributeId,
+        size: i32,
+    ) -> Self {
+        Self {
+            attribute_id,
+            buffer_id,
+            vao_ids,
+            size,
+            data_type: Default::default(),
+            normalized: false,
+            integer: false,
+            stride: 0,
+            offset: 0,
+        }
+    }
+
+    /// Builds an [AttributeLink] that sets up the attribute pointer as described
+    pub fn attribute_link(&self) -> AttributeLink<VertexArrayObjectId, BufferId, AttributeId> {
+        let size = self.size;
+        let data_type: u32 = self.data_type.into();
+        let normalized = self.normalized;
+        let integer = self.integer;
+        let stride = self.stride;
+        let offset = self.offset;
+
+        AttributeLink::new(
+            self.vao_ids.clone(),
+            self.buffer_id.clone(),
+            self.attribute_id.clone(),
+            move |ctx: &AttributeCreateContext| {
+                let gl = ctx.gl();
+                let attribute_location = ctx.attribute_location().get();
+                gl.bind_buffer(
+                    WebGl2RenderingContext::ARRAY_BUFFER,
+                    Some(ctx.webgl_buffer()),
+                );
+                if integer {
+                    gl.vertex_attrib_i_pointer_with_i32(
+                        attribute_location,
+                        size,
+                        data_type,
+                        stride,
+                        offset,
+                    );
+                } else {
+                    gl.vertex_attrib_pointer_with_i32(
+                        attribute_location,
+                        size,
+                        data_type,
+                        normalized,
+                        stride,
+                        offset,
+                    );
+                }
+            },
+        )
+    }
+}
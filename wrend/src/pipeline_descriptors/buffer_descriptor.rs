@@ -0,0 +1,169 @@
+use crate::{BufferCreateContext, BufferLink, Id};
+use js_sys::{Float32Array, Int32Array, Uint16Array, Uint32Array, Uint8Array};
+use serde::{Deserialize, Serialize};
+use web_sys::WebGl2RenderingContext;
+
+/// The binding point that a described buffer is created and filled through
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum BufferTarget {
+    /// Vertex data (`ARRAY_BUFFER`)
+    #[default]
+    ArrayBuffer,
+    /// Indices (`ELEMENT_ARRAY_BUFFER`)
+    ElementArrayBuffer,
+    /// Transform feedback output (`TRANSFORM_FEEDBACK_BUFFER`)
+    TransformFeedbackBuffer,
+    /// Uniform block data (`UNIFORM_BUFFER`)
+    UniformBuffer,
+}
+
+impl From<BufferTarget> for u32 {
+    fn from(buffer_target: BufferTarget) -> Self {
+        match buffer_target {
+            BufferTarget::ArrayBuffer => WebGl2RenderingContext::ARRAY_BUFFER,
+            BufferTarget::ElementArrayBuffer => WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER,
+            BufferTarget::TransformFeedbackBuffer => {
+                WebGl2RenderingContext::TRANSFORM_FEEDBACK_BUFFER
+            }
+            BufferTarget::UniformBuffer => WebGl2RenderingContext::UNIFORM_BUFFER,
+        }
+    }
+}
+
+/// Usage hint that is passed to `bufferData`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum BufferUsage {
+    /// `STATIC_DRAW`
+    #[default]
+    StaticDraw,
+    /// `DYNAMIC_DRAW`
+    DynamicDraw,
+    /// `STREAM_DRAW`
+    StreamDraw,
+    /// `STATIC_READ`
+    StaticRead,
+    /// `DYNAMIC_READ`
+    DynamicRead,
+    /// `STREAM_READ`
+    StreamRead,
+    /// `STATIC_COPY`
+    StaticCopy,
+    /// `DYNAMIC_COPY`
+    DynamicCopy,
+    /// `STREAM_COPY`
+    StreamCopy,
+}
+
+impl From<BufferUsage> for u32 {
+    fn from(buffer_usage: BufferUsage) -> Self {
+        match buffer_usage {
+            BufferUsage::StaticDraw => WebGl2RenderingContext::STATIC_DRAW,
+            BufferUsage::DynamicDraw => WebGl2RenderingContext::DYNAMIC_DRAW,
+            BufferUsage::StreamDraw => WebGl2RenderingContext::STREAM_DRAW,
+            BufferUsage::StaticRead => WebGl2RenderingContext::STATIC_READ,
+            BufferUsage::DynamicRead => WebGl2RenderingContext::DYNAMIC_READ,
+            BufferUsage::StreamRead => WebGl2RenderingContext::STREAM_READ,
+            BufferUsage::StaticCopy => WebGl2RenderingContext::STATIC_COPY,
+            BufferUsage::DynamicCopy => WebGl2RenderingContext::DYNAMIC_COPY,
+            BufferUsage::StreamCopy => WebGl2RenderingContext::STREAM_COPY,
+        }
+    }
+}
+
+/// The initial contents of a described buffer
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BufferData {
+    /// 32-bit floats
+    F32(Vec<f32>),
+    /// Signed 32-bit integers
+    I32(Vec<i32>),
+    /// Unsigned 32-bit integers
+    U32(Vec<u32>),
+    /// Unsigned 16-bit integers
+    U16(Vec<u16>),
+    /// Unsigned bytes
+    U8(Vec<u8>),
+    /// Allocates the given number of bytes without initializing them
+    Size(i32),
+}
+
+impl Default for BufferData {
+    fn default() -> Self {
+        Self::Size(0)
+    }
+}
+
+/// Describes a [BufferLink] whose buffer is created and filled from data, rather than from a callback.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BufferDescriptor<BufferId: Id> {
+    /// The id of the buffer
+    pub buffer_id: BufferId,
+    /// The binding point that the buffer is filled through
+    #[serde(default)]
+    pub target: BufferTarget,
+    /// The usage hint passed to `bufferData`
+    #[serde(default)]
+    pub usage: BufferUsage,
+    /// The initial contents of the buffer
+    #[serde(default)]
+    pub data: BufferData,
+}
+
+impl<BufferId: Id> BufferDescriptor<BufferId> {
+    /// Creates a descriptor for a `STATIC_DRAW` `ARRAY_BUFFER`
+    pub fn new(buffer_id: BufferId, data: BufferData) -> Self {
+        Self {
+            buffer_id,
+            target: Default::default(),
+            usage: Default::default(),
+            data,
+        }
+    }
+
+    /// Builds a [BufferLink] that creates and fills the buffer as described
+    pub fn buffer_link(&self) -> BufferLink<BufferId> {
+        let target: u32 = self.target.into();
+        let usage: u32 = self.usage.into();
+        let data = self.data.clone();
+
+        BufferLink::new(self.buffer_id.clone(), move |ctx: &BufferCreateContext| {
+            let gl = ctx.gl();
+            let buffer = gl
+                .create_buffer()
+                .expect("WebGL2 should be able to create a buffer");
+            gl.bind_buffer(target, Some(&buffer));
+
+            match &data {
+                BufferData::F32(data) => gl.buffer_data_with_array_buffer_view(
+                    target,
+                    &Float32Array::from(data.as_slice()),
+                    usage,
+                ),
+                BufferData::I32(data) => gl.buffer_data_with_array_buffer_view(
+                    target,
+                    &Int32Array::from(data.as_slice()),
+                    usage,
+                ),
+                BufferData::U32(data) => gl.buffer_data_with_array_buffer_view(
+                    target,
+                    &Uint32Array::from(data.as_slice()),
+                    usage,
+                ),
+                BufferData::U16(data) => gl.buffer_data_with_array_buffer_view(
+                    target,
+                    &Uint16Array::from(data.as_slice()),
+                    usage,
+                ),
+                BufferData::U8(data) => gl.buffer_data_with_array_buffer_view(
+                    target,
+                    &Uint8Array::from(data.as_slice()),
+                    usage,
+                ),
+                BufferData::Size(size) => gl.buffer_data_with_i32(target, *size, usage),
+            }
+
+            gl.bind_buffer(target, None);
+            buffer
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{DepthStencilAttachment, FramebufferAttachment, FramebufferLink, Id};
+use serde::{Deserialize, Serialize};
+
+/// Describes a [FramebufferLink] whose framebuffer is created from data, rather than from a callback.
+///
+/// If a `texture_id` is given, that texture is attached as the framebuffer's `COLOR_ATTACHMENT0`,
+/// followed by each of `color_attachments` (so that a single attachment can be described with just `texture_id`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FramebufferDescriptor<FramebufferId: Id, TextureId: Id, RenderbufferId: Id> {
+    /// The id of the framebuffer to create
+    pub framebuffer_id: FramebufferId,
+    /// The texture to attach as `COLOR_ATTACHMENT0`, if any
+    #[serde(default)]
+    pub texture_id: Option<TextureId>,
+    /// Textures and renderbuffers to attach as the following `COLOR_ATTACHMENTn`s, in order
+    #[serde(default)]
+    pub color_attachments: Vec<FramebufferAttachment<TextureId, RenderbufferId>>,
+    /// The texture or renderbuffer to attach as the depth, stencil or depth-stencil attachment, if any
+    #[serde(default)]
+    pub depth_stencil_attachment: Option<(
+        DepthStencilAttachment,
+        FramebufferAttachment<TextureId, RenderbufferId>,
+    )>,
+}
+
+impl<FramebufferId: Id, TextureId: Id, RenderbufferId: Id>
+    FramebufferDescriptor<FramebufferId, TextureId, RenderbufferId>
+{
+    /// Describes a framebuffer with at most a single color texture attached
+    pub fn new(framebuffer_id: FramebufferId, texture_id: Option<TextureId>) -> Self {
+        Self {
+            framebuffer_id,
+            texture_id,
+            color_attachments: Vec::new(),
+            depth_stencil_attachment: None,
+        }
+    }
+
+    /// Creates the described link
+    pub fn framebuffer_link(&self) -> FramebufferLink<FramebufferId, TextureId, RenderbufferId> {
+        let mut framebuffer_link = FramebufferLink::from_attachments(
+            self.framebuffer_id.clone(),
+            self.texture_id.iter().cloned().collect::<Vec<_>>(),
+        );
+        for color_attachment in &self.color_attachments {
+            match color_attachment {
+                FramebufferAttachment::Texture(texture_id) => {
+                    framebuffer_link.add_color_attachment(texture_id.clone())
+                }
+                FramebufferAttachment::Renderbuffer(renderbuffer_id) => {
+                    framebuffer_link.add_color_renderbuffer(renderbuffer_id.clone())
+                }
+            };
+        }
+        match &self.depth_stencil_attachment {
+            Some((attachment, FramebufferAttachment::Texture(texture_id))) => {
+                framebuffer_link.set_depth_stencil_attachment(*attachment, texture_id.clone());
+            }
+            Some((attachment, FramebufferAttachment::Renderbuffer(renderbuffer_id))) => {
+                framebuffer_link
+                    .set_depth_stencil_renderbuffer(*attachment, renderbuffer_id.clone());
+            }
+            None => {}
+        }
+
+        framebuffer_link
+    }
+}
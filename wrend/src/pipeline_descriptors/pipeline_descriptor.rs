@@ -0,0 +1,294 @@
+use crate::{
+    AttributeDescriptor, BufferDescriptor, FramebufferDescriptor, Id, IdName,
+    PipelineDescriptorError, ProgramDescriptor, RenderbufferDescriptor, ShaderDescriptor,
+    TextureDescriptor,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A declarative, serializable description of a rendering pipeline.
+///
+/// Covers everything that can be expressed as plain data: shader sources, program links
+/// (including transform feedback varyings), VAOs, transform feedbacks, buffers and their contents,
+/// attribute layouts, textures, renderbuffers and framebuffer attachments. Anything that needs custom behavior,
+/// such as uniforms or a resource that must be created in a callback, can still be added to the
+/// [crate::RendererDataBuilder] in code after the descriptor has been loaded with
+/// [crate::RendererDataBuilder::add_pipeline_descriptor].
+///
+/// Descriptors can be read from and written to JSON and RON. Every list keeps the order it was
+/// authored in, so that descriptor files diff cleanly.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PipelineDescriptor<
+    VertexShaderId: Id = String,
+    FragmentShaderId: Id = String,
+    ProgramId: Id = String,
+    BufferId: Id = String,
+    AttributeId: Id + IdName = String,
+    TextureId: Id = String,
+    FramebufferId: Id = String,
+    TransformFeedbackId: Id = String,
+    VertexArrayObjectId: Id = String,
+    RenderbufferId: Id = String,
+> {
+    /// Vertex shader sources
+    #[serde(default)]
+    pub vertex_shaders: Vec<ShaderDescriptor<VertexShaderId>>,
+    /// Fragment shader sources
+    #[serde(default)]
+    pub fragment_shaders: Vec<ShaderDescriptor<FragmentShaderId>>,
+    /// Programs to link from the shaders above
+    #[serde(default)]
+    pub programs: Vec<ProgramDescriptor<ProgramId, VertexShaderId, FragmentShaderId>>,
+    /// Ids of the VAOs to create
+    #[serde(default)]
+    pub vertex_array_objects: Vec<VertexArrayObjectId>,
+    /// Ids of the transform feedback objects to create
+    #[serde(default)]
+    pub transform_feedbacks: Vec<TransformFeedbackId>,
+    /// Buffers to create, along with their initial contents
+    #[serde(default)]
+    pub buffers: Vec<BufferDescriptor<BufferId>>,
+    /// Attribute layouts, each reading from one of the buffers above
+    #[serde(default)]
+    pub attributes: Vec<AttributeDescriptor<VertexArrayObjectId, BufferId, AttributeId>>,
+    /// Textures to allocate
+    #[serde(default)]
+    pub textures: Vec<TextureDescriptor<TextureId>>,
+    /// Renderbuffers to allocate
+    #[serde(default)]
+    pub renderbuffers: Vec<RenderbufferDescriptor<RenderbufferId>>,
+    /// Framebuffers, with the textures and renderbuffers attached to them
+    #[serde(default)]
+    pub framebuffers: Vec<FramebufferDescriptor<FramebufferId, TextureId, RenderbufferId>>,
+}
+
+impl<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        RenderbufferId: Id,
+    >
+    PipelineDescriptor<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        RenderbufferId,
+    >
+{
+    /// Creates an empty pipeline descriptor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a pipeline descriptor from JSON
+    pub fn from_json(json: &str) -> Result<Self, PipelineDescriptorError>
+    where
+        Self: DeserializeOwned,
+    {
+        serde_json::from_str(json)
+            .map_err(|err| PipelineDescriptorError::JsonError(err.to_string()))
+    }
+
+    /// Writes the pipeline descriptor as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, PipelineDescriptorError>
+    where
+        Self: Serialize,
+    {
+        serde_json::to_string_pretty(self)
+            .map_err(|err| PipelineDescriptorError::JsonError(err.to_string()))
+    }
+
+    /// Parses a pipeline descriptor from RON
+    pub fn from_ron(ron: &str) -> Result<Self, PipelineDescriptorError>
+    where
+        Self: DeserializeOwned,
+    {
+        ron::from_str(ron).map_err(|err| PipelineDescriptorError::RonError(err.to_string()))
+    }
+
+    /// Writes the pipeline descriptor as pretty-printed RON
+    pub fn to_ron(&self) -> Result<String, PipelineDescriptorError>
+    where
+        Self: Serialize,
+    {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| PipelineDescriptorError::RonError(err.to_string()))
+    }
+}
+
+impl<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        RenderbufferId: Id,
+    > Default
+    for PipelineDescriptor<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        RenderbufferId,
+    >
+{
+    fn default() -> Self {
+        Self {
+            vertex_shaders: Default::default(),
+            fragment_shaders: Default::default(),
+            programs: Default::default(),
+            vertex_array_objects: Default::default(),
+            transform_feedbacks: Default::default(),
+            buffers: Default::default(),
+            attributes: Default::default(),
+            textures: Default::default(),
+            renderbuffers: Default::default(),
+            framebuffers: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AttributeType, BufferData, BufferUsage, DepthStencilAttachment, FramebufferAttachment,
+        TextureFilter, TextureSize,
+    };
+    use web_sys::WebGl2RenderingContext;
+
+    fn example_descriptor() -> PipelineDescriptor {
+        let mut pipeline_descriptor = PipelineDescriptor::new();
+        pipeline_descriptor
+            .vertex_shaders
+            .push(ShaderDescriptor::new(
+                "vertex".to_string(),
+                "void main() {}",
+            ));
+        pipeline_descriptor
+            .fragment_shaders
+            .push(ShaderDescriptor::new(
+                "fragment".to_string(),
+                "void main() {}",
+            ));
+        let mut program_descriptor = ProgramDescriptor::new(
+            "program".to_string(),
+            "vertex".to_string(),
+            "fragment".to_string(),
+        );
+        program_descriptor.transform_feedback_varyings = vec!["o_position".to_string()];
+        pipeline_descriptor.programs.push(program_descriptor);
+        pipeline_descriptor
+            .vertex_array_objects
+            .push("vao".to_string());
+        let mut buffer_descriptor =
+            BufferDescriptor::new("quad".to_string(), BufferData::F32(vec![-1.0, 1.0, 0.5]));
+        buffer_descriptor.usage = BufferUsage::DynamicDraw;
+        pipeline_descriptor.buffers.push(buffer_descriptor);
+        let mut attribute_descriptor = AttributeDescriptor::new(
+            vec!["vao".to_string()],
+            "quad".to_string(),
+            "a_position".to_string(),
+            2,
+        );
+        attribute_descriptor.data_type = AttributeType::Float;
+        attribute_descriptor.stride = 8;
+        pipeline_descriptor.attributes.push(attribute_descriptor);
+        let mut texture_descriptor = TextureDescriptor::new("color".to_string());
+        texture_descriptor.size = TextureSize::Fixed {
+            width: 16,
+            height: 8,
+        };
+        texture_descriptor.mag_filter = TextureFilter::Linear;
+        pipeline_descriptor.textures.push(texture_descriptor);
+        pipeline_descriptor
+            .renderbuffers
+            .push(RenderbufferDescriptor::new(
+                "depth".to_string(),
+                WebGl2RenderingContext::DEPTH_COMPONENT24,
+            ));
+        let mut framebuffer_descriptor =
+            FramebufferDescriptor::new("color_framebuffer".to_string(), Some("color".to_string()));
+        framebuffer_descriptor.depth_stencil_attachment = Some((
+            DepthStencilAttachment::Depth,
+            FramebufferAttachment::Renderbuffer("depth".to_string()),
+        ));
+        pipeline_descriptor
+            .framebuffers
+            .push(framebuffer_descriptor);
+        pipeline_descriptor
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let pipeline_descriptor = example_descriptor();
+        let json = pipeline_descriptor.to_json().unwrap();
+        assert_eq!(
+            PipelineDescriptor::from_json(&json).unwrap(),
+            pipeline_descriptor
+        );
+    }
+
+    #[test]
+    fn ron_round_trip() {
+        let pipeline_descriptor = example_descriptor();
+        let ron = pipeline_descriptor.to_ron().unwrap();
+        assert_eq!(
+            PipelineDescriptor::from_ron(&ron).unwrap(),
+            pipeline_descriptor
+        );
+    }
+
+    #[test]
+    fn omitted_fields_use_defaults() {
+        let pipeline_descriptor: PipelineDescriptor = PipelineDescriptor::from_json(
+            r#"{
+                "buffers": [{ "buffer_id": "quad", "data": { "U16": [0, 1, 2] } }],
+                "attributes": [{ "attribute_id": "a_position", "buffer_id": "quad", "size": 2 }]
+            }"#,
+        )
+        .unwrap();
+
+        let buffer_descriptor = &pipeline_descriptor.buffers[0];
+        assert_eq!(buffer_descriptor.usage, BufferUsage::StaticDraw);
+        assert_eq!(buffer_descriptor.data, BufferData::U16(vec![0, 1, 2]));
+
+        let attribute_descriptor = &pipeline_descriptor.attributes[0];
+        assert!(attribute_descriptor.vao_ids.is_empty());
+        assert_eq!(attribute_descriptor.data_type, AttributeType::Float);
+        assert_eq!(attribute_descriptor.stride, 0);
+        assert!(pipeline_descriptor.programs.is_empty());
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert!(matches!(
+            PipelineDescriptor::<String>::from_json("{ \"programs\": 1 }"),
+            Err(PipelineDescriptorError::JsonError(_))
+        ));
+        assert!(matches!(
+            PipelineDescriptor::<String>::from_ron("(programs: 1)"),
+            Err(PipelineDescriptorError::RonError(_))
+        ));
+    }
+}
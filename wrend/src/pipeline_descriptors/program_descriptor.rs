@@ -0,0 +1,47 @@
+use crate::{Id, ProgramLink};
+use serde::{Deserialize, Serialize};
+
+/// Describes a [ProgramLink]: a vertex shader and a fragment shader linked together into one program.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ProgramDescriptor<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id> {
+    /// The id the linked program is saved under
+    pub program_id: ProgramId,
+    /// The id of the vertex shader to link
+    pub vertex_shader_id: VertexShaderId,
+    /// The id of the fragment shader to link
+    pub fragment_shader_id: FragmentShaderId,
+    /// Vertex shader outputs captured by transform feedback, in buffer order
+    #[serde(default)]
+    pub transform_feedback_varyings: Vec<String>,
+}
+
+impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
+    ProgramDescriptor<ProgramId, VertexShaderId, FragmentShaderId>
+{
+    /// Describes a program without any transform feedback varyings
+    pub fn new(
+        program_id: ProgramId,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+    ) -> Self {
+        Self {
+            program_id,
+            vertex_shader_id,
+            fragment_shader_id,
+            transform_feedback_varyings: Default::default(),
+        }
+    }
+
+    /// Creates the [ProgramLink] that this descriptor describes
+    pub fn program_link(&self) -> ProgramLink<ProgramId, VertexShaderId, FragmentShaderId> {
+        let mut program_link_builder = ProgramLink::builder();
+        program_link_builder
+            .set_program_id(self.program_id.clone())
+            .set_vertex_shader_id(self.vertex_shader_id.clone())
+            .set_fragment_shader_id(self.fragment_shader_id.clone())
+            .set_transform_feedback_varyings(self.transform_feedback_varyings.clone());
+        program_link_builder
+            .build()
+            .expect("ProgramLinkBuilder should build when every id has been supplied")
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{Id, RenderbufferLink, TextureSize};
+use serde::{Deserialize, Serialize};
+
+/// Describes a [RenderbufferLink], so that renderbuffers can be declared in a [crate::PipelineDescriptor]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RenderbufferDescriptor<RenderbufferId: Id> {
+    /// The id that framebuffers use to attach the renderbuffer
+    pub renderbuffer_id: RenderbufferId,
+    /// A sized format, such as `DEPTH_COMPONENT24`, `DEPTH24_STENCIL8` or `RGBA8`
+    pub internal_format: u32,
+    /// Defaults to the size of the canvas
+    #[serde(default)]
+    pub size: TextureSize,
+    /// The number of samples per pixel, or `0` (the default) if the renderbuffer is not multisampled
+    #[serde(default)]
+    pub samples: i32,
+}
+
+impl<RenderbufferId: Id> RenderbufferDescriptor<RenderbufferId> {
+    /// Describes a canvas-sized renderbuffer that is not multisampled
+    pub fn new(renderbuffer_id: RenderbufferId, internal_format: u32) -> Self {
+        Self {
+            renderbuffer_id,
+            internal_format,
+            size: Default::default(),
+            samples: 0,
+        }
+    }
+
+    /// Creates the described link
+    pub fn renderbuffer_link(&self) -> RenderbufferLink<RenderbufferId> {
+        let mut renderbuffer_link = RenderbufferLink::new(
+            self.renderbuffer_id.clone(),
+            self.internal_format,
+            self.size,
+        );
+        renderbuffer_link.set_samples(self.samples);
+        renderbuffer_link
+    }
+}
@@ -0,0 +1,21 @@
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// Describes a single shader source and the id it should be saved under.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ShaderDescriptor<ShaderId: Id> {
+    /// The id the compiled shader is saved under
+    pub shader_id: ShaderId,
+    /// The GLSL source of the shader
+    pub src: String,
+}
+
+impl<ShaderId: Id> ShaderDescriptor<ShaderId> {
+    /// Describes a shader compiled from `src`
+    pub fn new(shader_id: ShaderId, src: impl Into<String>) -> Self {
+        Self {
+            shader_id,
+            src: src.into(),
+        }
+    }
+}
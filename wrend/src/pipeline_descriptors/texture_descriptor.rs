@@ -0,0 +1,628 @@
+use crate::{
+    is_color_renderable_texture_format, is_filterable_texture_format, is_integer_texture_format,
+    is_valid_texture_format, texel_len, Id, TextureCreateContext, TextureDescriptorError,
+    TextureLink,
+};
+use js_sys::{Float32Array, Object, Uint16Array, Uint8Array};
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlTexture};
+
+/// The kind of texture that is described, along with its number of layers
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TextureTarget {
+    /// A single 2D image (`TEXTURE_2D`)
+    #[default]
+    Texture2D,
+    /// A stack of 2D images (`TEXTURE_2D_ARRAY`)
+    Texture2DArray {
+        /// The number of images in the stack
+        layers: i32,
+    },
+    /// A volume (`TEXTURE_3D`)
+    Texture3D {
+        /// The depth of the volume
+        depth: i32,
+    },
+}
+
+impl TextureTarget {
+    /// The number of layers (or depth) of the texture, which is `1` for 2D textures
+    pub fn depth(&self) -> i32 {
+        match self {
+            TextureTarget::Texture2D => 1,
+            TextureTarget::Texture2DArray { layers } => *layers,
+            TextureTarget::Texture3D { depth } => *depth,
+        }
+    }
+}
+
+impl From<TextureTarget> for u32 {
+    fn from(texture_target: TextureTarget) -> Self {
+        match texture_target {
+            TextureTarget::Texture2D => WebGl2RenderingContext::TEXTURE_2D,
+            TextureTarget::Texture2DArray { .. } => WebGl2RenderingContext::TEXTURE_2D_ARRAY,
+            TextureTarget::Texture3D { .. } => WebGl2RenderingContext::TEXTURE_3D,
+        }
+    }
+}
+
+/// The size of a described texture
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TextureSize {
+    /// Matches the size of the canvas at the time the texture is created
+    #[default]
+    Canvas,
+    /// A size that does not depend on the canvas
+    Fixed {
+        /// The width in texels
+        width: i32,
+        /// The height in texels
+        height: i32,
+    },
+}
+
+/// The storage format of a described texture.
+///
+/// Each variant corresponds to a valid combination of WebGL2's internal format, format and type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TextureFormat {
+    /// `RGBA8` with `UNSIGNED_BYTE` data
+    #[default]
+    Rgba8,
+    /// `RGB8` with `UNSIGNED_BYTE` data
+    Rgb8,
+    /// `R8` with `UNSIGNED_BYTE` data
+    R8,
+    /// `RGBA16F` with `HALF_FLOAT` data
+    Rgba16F,
+    /// `RGBA32F` with `FLOAT` data
+    Rgba32F,
+    /// `R32F` with `FLOAT` data
+    R32F,
+    /// `RG32F` with `FLOAT` data
+    Rg32F,
+    /// `R32UI` with `UNSIGNED_INT` data
+    R32UI,
+    /// Any other combination of WebGL2's internal format, format and type
+    /// (see [TextureDescriptor::validate] for which combinations are allowed)
+    Custom {
+        /// The sized internal format, e.g. `RGBA8`
+        internal_format: u32,
+        /// The format of the data, e.g. `RGBA`
+        format: u32,
+        /// The type of the data, e.g. `UNSIGNED_BYTE`
+        data_type: u32,
+    },
+}
+
+impl TextureFormat {
+    /// Gets the `(internal_format, format, type)` triple to pass to `texImage2D`
+    pub fn gl_formats(&self) -> (u32, u32, u32) {
+        match self {
+            TextureFormat::Rgba8 => (
+                WebGl2RenderingContext::RGBA8,
+                WebGl2RenderingContext::RGBA,
+                WebGl2RenderingContext::UNSIGNED_BYTE,
+            ),
+            TextureFormat::Rgb8 => (
+                WebGl2RenderingContext::RGB8,
+                WebGl2RenderingContext::RGB,
+                WebGl2RenderingContext::UNSIGNED_BYTE,
+            ),
+            TextureFormat::R8 => (
+                WebGl2RenderingContext::R8,
+                WebGl2RenderingContext::RED,
+                WebGl2RenderingContext::UNSIGNED_BYTE,
+            ),
+            TextureFormat::Rgba16F => (
+                WebGl2RenderingContext::RGBA16F,
+                WebGl2RenderingContext::RGBA,
+                WebGl2RenderingContext::HALF_FLOAT,
+            ),
+            TextureFormat::Rgba32F => (
+                WebGl2RenderingContext::RGBA32F,
+                WebGl2RenderingContext::RGBA,
+                WebGl2RenderingContext::FLOAT,
+            ),
+            TextureFormat::R32F => (
+                WebGl2RenderingContext::R32F,
+                WebGl2RenderingContext::RED,
+                WebGl2RenderingContext::FLOAT,
+            ),
+            TextureFormat::Rg32F => (
+                WebGl2RenderingContext::RG32F,
+                WebGl2RenderingContext::RG,
+                WebGl2RenderingContext::FLOAT,
+            ),
+            TextureFormat::R32UI => (
+                WebGl2RenderingContext::R32UI,
+                WebGl2RenderingContext::RED_INTEGER,
+                WebGl2RenderingContext::UNSIGNED_INT,
+            ),
+            TextureFormat::Custom {
+                internal_format,
+                format,
+                data_type,
+            } => (*internal_format, *format, *data_type),
+        }
+    }
+}
+
+/// Minification / magnification filter of a described texture.
+///
+/// The mipmap filters can only be used for minification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TextureFilter {
+    /// `NEAREST`
+    #[default]
+    Nearest,
+    /// `LINEAR`
+    Linear,
+    /// `NEAREST_MIPMAP_NEAREST`
+    NearestMipmapNearest,
+    /// `LINEAR_MIPMAP_NEAREST`
+    LinearMipmapNearest,
+    /// `NEAREST_MIPMAP_LINEAR`
+    NearestMipmapLinear,
+    /// `LINEAR_MIPMAP_LINEAR`
+    LinearMipmapLinear,
+}
+
+impl TextureFilter {
+    /// Whether the filter samples from the texture's mipmaps
+    pub fn uses_mipmaps(&self) -> bool {
+        !matches!(self, TextureFilter::Nearest | TextureFilter::Linear)
+    }
+
+    /// Whether the filter blends between texels or between mipmap levels,
+    /// which is only possible for formats that are filterable (see [is_filterable_texture_format])
+    pub fn interpolates(&self) -> bool {
+        !matches!(
+            self,
+            TextureFilter::Nearest | TextureFilter::NearestMipmapNearest
+        )
+    }
+}
+
+impl From<TextureFilter> for u32 {
+    fn from(texture_filter: TextureFilter) -> Self {
+        match texture_filter {
+            TextureFilter::Nearest => WebGl2RenderingContext::NEAREST,
+            TextureFilter::Linear => WebGl2RenderingContext::LINEAR,
+            TextureFilter::NearestMipmapNearest => WebGl2RenderingContext::NEAREST_MIPMAP_NEAREST,
+            TextureFilter::LinearMipmapNearest => WebGl2RenderingContext::LINEAR_MIPMAP_NEAREST,
+            TextureFilter::NearestMipmapLinear => WebGl2RenderingContext::NEAREST_MIPMAP_LINEAR,
+            TextureFilter::LinearMipmapLinear => WebGl2RenderingContext::LINEAR_MIPMAP_LINEAR,
+        }
+    }
+}
+
+/// Wrap mode of a described texture
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TextureWrap {
+    /// `CLAMP_TO_EDGE`
+    #[default]
+    ClampToEdge,
+    /// `REPEAT`
+    Repeat,
+    /// `MIRRORED_REPEAT`
+    MirroredRepeat,
+}
+
+impl From<TextureWrap> for u32 {
+    fn from(texture_wrap: TextureWrap) -> Self {
+        match texture_wrap {
+            TextureWrap::ClampToEdge => WebGl2RenderingContext::CLAMP_TO_EDGE,
+            TextureWrap::Repeat => WebGl2RenderingContext::REPEAT,
+            TextureWrap::MirroredRepeat => WebGl2RenderingContext::MIRRORED_REPEAT,
+        }
+    }
+}
+
+/// The initial contents of a described texture, given row by row from the bottom of the texture
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TextureData {
+    /// Used for `UNSIGNED_BYTE` data
+    U8(Vec<u8>),
+    /// Used for `UNSIGNED_SHORT` data, as well as `HALF_FLOAT` data that has already been converted to bits
+    U16(Vec<u16>),
+    /// Used for `FLOAT` data
+    F32(Vec<f32>),
+}
+
+impl TextureData {
+    /// The number of values in the data
+    pub fn len(&self) -> usize {
+        match self {
+            TextureData::U8(data) => data.len(),
+            TextureData::U16(data) => data.len(),
+            TextureData::F32(data) => data.len(),
+        }
+    }
+
+    /// Whether there are no values in the data
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the data can be uploaded as the given WebGL2 type
+    pub fn matches_type(&self, data_type: u32) -> bool {
+        match self {
+            TextureData::U8(_) => data_type == WebGl2RenderingContext::UNSIGNED_BYTE,
+            TextureData::U16(_) => matches!(
+                data_type,
+                WebGl2RenderingContext::UNSIGNED_SHORT
+                    | WebGl2RenderingContext::HALF_FLOAT
+                    | WebGl2RenderingContext::UNSIGNED_SHORT_5_6_5
+                    | WebGl2RenderingContext::UNSIGNED_SHORT_4_4_4_4
+                    | WebGl2RenderingContext::UNSIGNED_SHORT_5_5_5_1
+            ),
+            TextureData::F32(_) => data_type == WebGl2RenderingContext::FLOAT,
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            TextureData::U8(_) => "u8",
+            TextureData::U16(_) => "u16",
+            TextureData::F32(_) => "f32",
+        }
+    }
+
+    fn to_array_buffer_view(&self) -> Object {
+        match self {
+            TextureData::U8(data) => Uint8Array::from(data.as_slice()).into(),
+            TextureData::U16(data) => Uint16Array::from(data.as_slice()).into(),
+            TextureData::F32(data) => Float32Array::from(data.as_slice()).into(),
+        }
+    }
+}
+
+/// Describes a [TextureLink] whose texture is created and configured from data, rather than from a callback.
+///
+/// Unless initial `data` is given, the texture is allocated empty: its contents are expected to be
+/// rendered into (e.g. through a framebuffer).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TextureDescriptor<TextureId: Id> {
+    /// The id of the texture
+    pub texture_id: TextureId,
+    /// The kind of texture to create
+    #[serde(default)]
+    pub target: TextureTarget,
+    /// The width and height of the texture
+    #[serde(default)]
+    pub size: TextureSize,
+    /// The storage format of the texture
+    #[serde(default)]
+    pub format: TextureFormat,
+    /// The minification filter
+    #[serde(default)]
+    pub min_filter: TextureFilter,
+    /// The magnification filter, which must not use mipmaps
+    #[serde(default)]
+    pub mag_filter: TextureFilter,
+    /// The horizontal wrap mode
+    #[serde(default)]
+    pub wrap_s: TextureWrap,
+    /// The vertical wrap mode
+    #[serde(default)]
+    pub wrap_t: TextureWrap,
+    /// Only used by 3D textures
+    #[serde(default)]
+    pub wrap_r: TextureWrap,
+    /// Whether to generate mipmaps once the texture is created
+    #[serde(default)]
+    pub generate_mipmaps: bool,
+    /// The initial contents of the texture
+    #[serde(default)]
+    pub data: Option<TextureData>,
+}
+
+impl<TextureId: Id> TextureDescriptor<TextureId> {
+    /// Creates a descriptor for an empty, canvas-sized `RGBA8` 2D texture
+    pub fn new(texture_id: TextureId) -> Self {
+        Self {
+            texture_id,
+            target: Default::default(),
+            size: Default::default(),
+            format: Default::default(),
+            min_filter: Default::default(),
+            mag_filter: Default::default(),
+            wrap_s: Default::default(),
+            wrap_t: Default::default(),
+            wrap_r: Default::default(),
+            generate_mipmaps: false,
+            data: None,
+        }
+    }
+
+    /// Builds a [TextureLink] that creates and configures the texture as described
+    pub fn texture_link(&self) -> TextureLink<TextureId> {
+        TextureLink::from_descriptor(self.clone())
+    }
+
+    /// The width and height of the texture when it is created alongside `canvas`
+    pub fn resolve_size(&self, canvas: &HtmlCanvasElement) -> (i32, i32) {
+        match self.size {
+            TextureSize::Canvas => (canvas.width() as i32, canvas.height() as i32),
+            TextureSize::Fixed { width, height } => (width, height),
+        }
+    }
+
+    /// Checks the descriptor for anything that WebGL2 would reject, without making any WebGL calls:
+    /// the format / type combination, the filters, and the type and length of the initial data.
+    pub fn validate(&self) -> Result<(), TextureDescriptorError> {
+        let (internal_format, format, data_type) = self.format.gl_formats();
+        if !is_valid_texture_format(internal_format, format, data_type) {
+            return Err(TextureDescriptorError::InvalidFormat {
+                internal_format,
+                format,
+                data_type,
+            });
+        }
+
+        if self.mag_filter.uses_mipmaps() {
+            return Err(TextureDescriptorError::MipmapMagFilter);
+        }
+        if self.min_filter.uses_mipmaps() && !self.generate_mipmaps {
+            return Err(TextureDescriptorError::MipmapsNotGenerated);
+        }
+        if self.generate_mipmaps && is_integer_texture_format(format) {
+            return Err(TextureDescriptorError::IntegerMipmaps);
+        }
+        let is_filterable = is_filterable_texture_format(internal_format);
+        if self.generate_mipmaps
+            && !(is_filterable && is_color_renderable_texture_format(internal_format))
+        {
+            return Err(TextureDescriptorError::MipmapsUnsupported { internal_format });
+        }
+        if !is_filterable && (self.min_filter.interpolates() || self.mag_filter.interpolates()) {
+            return Err(TextureDescriptorError::UnfilterableFormat { internal_format });
+        }
+
+        if let Some(data) = &self.data {
+            let TextureSize::Fixed { width, height } = self.size else {
+                return Err(TextureDescriptorError::DataWithoutFixedSize);
+            };
+            if !data.matches_type(data_type) {
+                return Err(TextureDescriptorError::DataTypeMismatch {
+                    data_type: data.type_name().to_string(),
+                    gl_type: data_type,
+                });
+            }
+            let texels = (width * height * self.target.depth()).max(0) as usize;
+            let expected = texels * texel_len(format, data_type);
+            if data.len() != expected {
+                return Err(TextureDescriptorError::DataLengthMismatch {
+                    expected,
+                    actual: data.len(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates, allocates and configures the described texture
+    pub(crate) fn create_texture(&self, ctx: &TextureCreateContext) -> WebGlTexture {
+        let gl = ctx.gl();
+        let target: u32 = self.target.into();
+        let (width, height) = self.resolve_size(ctx.canvas());
+        let (internal_format, format, data_type) = self.format.gl_formats();
+        let data = self.data.as_ref().map(TextureData::to_array_buffer_view);
+
+        let texture = gl
+            .create_texture()
+            .expect("WebGL2 should be able to create a texture");
+        gl.bind_texture(target, Some(&texture));
+
+        // rows of initial data are tightly packed, rather than aligned to 4 bytes
+        if data.is_some() {
+            gl.pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, 1);
+        }
+        match self.target {
+            TextureTarget::Texture2D => gl
+                .tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_array_buffer_view(
+                    target,
+                    0,
+                    internal_format as i32,
+                    width,
+                    height,
+                    0,
+                    format,
+                    data_type,
+                    data.as_ref(),
+                ),
+            TextureTarget::Texture2DArray { .. } | TextureTarget::Texture3D { .. } => gl
+                .tex_image_3d_with_opt_array_buffer_view(
+                    target,
+                    0,
+                    internal_format as i32,
+                    width,
+                    height,
+                    self.target.depth(),
+                    0,
+                    format,
+                    data_type,
+                    data.as_ref(),
+                ),
+        }
+        .expect("WebGL2 should be able to allocate texture storage");
+        if data.is_some() {
+            gl.pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, 4);
+        }
+
+        for (pname, param) in [
+            (
+                WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                u32::from(self.min_filter),
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                u32::from(self.mag_filter),
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_WRAP_S,
+                u32::from(self.wrap_s),
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_WRAP_T,
+                u32::from(self.wrap_t),
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_WRAP_R,
+                u32::from(self.wrap_r),
+            ),
+        ] {
+            gl.tex_parameteri(target, pname, param as i32);
+        }
+
+        if self.generate_mipmaps {
+            gl.generate_mipmap(target);
+        }
+        gl.bind_texture(target, None);
+
+        texture
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn descriptor() -> TextureDescriptor<&'static str> {
+        let mut texture_descriptor = TextureDescriptor::new("texture");
+        texture_descriptor.size = TextureSize::Fixed {
+            width: 2,
+            height: 2,
+        };
+        texture_descriptor
+    }
+
+    #[test]
+    fn accepts_valid_descriptors() {
+        let mut texture_descriptor = descriptor();
+        texture_descriptor.data = Some(TextureData::U8(vec![0; 16]));
+        assert_eq!(texture_descriptor.validate(), Ok(()));
+
+        texture_descriptor.min_filter = TextureFilter::LinearMipmapLinear;
+        texture_descriptor.mag_filter = TextureFilter::Linear;
+        texture_descriptor.generate_mipmaps = true;
+        assert_eq!(texture_descriptor.validate(), Ok(()));
+
+        texture_descriptor.format = TextureFormat::Rgba32F;
+        texture_descriptor.data = Some(TextureData::F32(vec![0.0; 16]));
+        texture_descriptor.min_filter = TextureFilter::Nearest;
+        texture_descriptor.mag_filter = TextureFilter::Nearest;
+        texture_descriptor.generate_mipmaps = false;
+        assert_eq!(texture_descriptor.validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_filtering_unfilterable_formats() {
+        let mut texture_descriptor = descriptor();
+        texture_descriptor.format = TextureFormat::Rgba32F;
+        texture_descriptor.min_filter = TextureFilter::LinearMipmapLinear;
+        texture_descriptor.generate_mipmaps = true;
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::MipmapsUnsupported {
+                internal_format: WebGl2RenderingContext::RGBA32F
+            })
+        );
+
+        texture_descriptor.min_filter = TextureFilter::Linear;
+        texture_descriptor.generate_mipmaps = false;
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::UnfilterableFormat {
+                internal_format: WebGl2RenderingContext::RGBA32F
+            })
+        );
+
+        texture_descriptor.format = TextureFormat::Custom {
+            internal_format: WebGl2RenderingContext::DEPTH_COMPONENT24,
+            format: WebGl2RenderingContext::DEPTH_COMPONENT,
+            data_type: WebGl2RenderingContext::UNSIGNED_INT,
+        };
+        texture_descriptor.min_filter = TextureFilter::Nearest;
+        texture_descriptor.mag_filter = TextureFilter::Linear;
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::UnfilterableFormat {
+                internal_format: WebGl2RenderingContext::DEPTH_COMPONENT24
+            })
+        );
+
+        texture_descriptor.mag_filter = TextureFilter::Nearest;
+        texture_descriptor.generate_mipmaps = true;
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::MipmapsUnsupported {
+                internal_format: WebGl2RenderingContext::DEPTH_COMPONENT24
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_format_combinations() {
+        let mut texture_descriptor = descriptor();
+        texture_descriptor.format = TextureFormat::Custom {
+            internal_format: WebGl2RenderingContext::RGBA8,
+            format: WebGl2RenderingContext::RGBA,
+            data_type: WebGl2RenderingContext::FLOAT,
+        };
+
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::InvalidFormat {
+                internal_format: WebGl2RenderingContext::RGBA8,
+                format: WebGl2RenderingContext::RGBA,
+                data_type: WebGl2RenderingContext::FLOAT,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_initial_data() {
+        let mut texture_descriptor = descriptor();
+        texture_descriptor.data = Some(TextureData::F32(vec![0.0; 16]));
+        assert!(matches!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::DataTypeMismatch { .. })
+        ));
+
+        texture_descriptor.target = TextureTarget::Texture2DArray { layers: 2 };
+        texture_descriptor.data = Some(TextureData::U8(vec![0; 16]));
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::DataLengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        );
+
+        texture_descriptor.size = TextureSize::Canvas;
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::DataWithoutFixedSize)
+        );
+    }
+
+    #[test]
+    fn rejects_unusable_mipmap_filters() {
+        let mut texture_descriptor = descriptor();
+        texture_descriptor.min_filter = TextureFilter::NearestMipmapNearest;
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::MipmapsNotGenerated)
+        );
+
+        texture_descriptor.format = TextureFormat::R32UI;
+        texture_descriptor.generate_mipmaps = true;
+        assert_eq!(
+            texture_descriptor.validate(),
+            Err(TextureDescriptorError::IntegerMipmaps)
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use web_sys::WebGl2RenderingContext as Gl;
+
+/// Every valid `(internal_format, format, [type])` combination for `texImage2D` / `texImage3D`,
+/// following the tables in section 3.7.6 of the WebGL2 specification.
+const TEXTURE_FORMATS: &[(u32, u32, &[u32])] = &[
+    // unsized formats
+    (
+        Gl::RGBA,
+        Gl::RGBA,
+        &[
+            Gl::UNSIGNED_BYTE,
+            Gl::UNSIGNED_SHORT_4_4_4_4,
+            Gl::UNSIGNED_SHORT_5_5_5_1,
+        ],
+    ),
+    (
+        Gl::RGB,
+        Gl::RGB,
+        &[Gl::UNSIGNED_BYTE, Gl::UNSIGNED_SHORT_5_6_5],
+    ),
+    (
+        Gl::LUMINANCE_ALPHA,
+        Gl::LUMINANCE_ALPHA,
+        &[Gl::UNSIGNED_BYTE],
+    ),
+    (Gl::LUMINANCE, Gl::LUMINANCE, &[Gl::UNSIGNED_BYTE]),
+    (Gl::ALPHA, Gl::ALPHA, &[Gl::UNSIGNED_BYTE]),
+    // sized formats
+    (Gl::R8, Gl::RED, &[Gl::UNSIGNED_BYTE]),
+    (Gl::R8_SNORM, Gl::RED, &[Gl::BYTE]),
+    (Gl::R16F, Gl::RED, &[Gl::HALF_FLOAT, Gl::FLOAT]),
+    (Gl::R32F, Gl::RED, &[Gl::FLOAT]),
+    (Gl::R8UI, Gl::RED_INTEGER, &[Gl::UNSIGNED_BYTE]),
+    (Gl::R8I, Gl::RED_INTEGER, &[Gl::BYTE]),
+    (Gl::R16UI, Gl::RED_INTEGER, &[Gl::UNSIGNED_SHORT]),
+    (Gl::R16I, Gl::RED_INTEGER, &[Gl::SHORT]),
+    (Gl::R32UI, Gl::RED_INTEGER, &[Gl::UNSIGNED_INT]),
+    (Gl::R32I, Gl::RED_INTEGER, &[Gl::INT]),
+    (Gl::RG8, Gl::RG, &[Gl::UNSIGNED_BYTE]),
+    (Gl::RG8_SNORM, Gl::RG, &[Gl::BYTE]),
+    (Gl::RG16F, Gl::RG, &[Gl::HALF_FLOAT, Gl::FLOAT]),
+    (Gl::RG32F, Gl::RG, &[Gl::FLOAT]),
+    (Gl::RG8UI, Gl::RG_INTEGER, &[Gl::UNSIGNED_BYTE]),
+    (Gl::RG8I, Gl::RG_INTEGER, &[Gl::BYTE]),
+    (Gl::RG16UI, Gl::RG_INTEGER, &[Gl::UNSIGNED_SHORT]),
+    (Gl::RG16I, Gl::RG_INTEGER, &[Gl::SHORT]),
+    (Gl::RG32UI, Gl::RG_INTEGER, &[Gl::UNSIGNED_INT]),
+    (Gl::RG32I, Gl::RG_INTEGER, &[Gl::INT]),
+    (Gl::RGB8, Gl::RGB, &[Gl::UNSIGNED_BYTE]),
+    (Gl::SRGB8, Gl::RGB, &[Gl::UNSIGNED_BYTE]),
+    (
+        Gl::RGB565,
+        Gl::RGB,
+        &[Gl::UNSIGNED_BYTE, Gl::UNSIGNED_SHORT_5_6_5],
+    ),
+    (Gl::RGB8_SNORM, Gl::RGB, &[Gl::BYTE]),
+    (
+        Gl::R11F_G11F_B10F,
+        Gl::RGB,
+        &[Gl::UNSIGNED_INT_10F_11F_11F_REV, Gl::HALF_FLOAT, Gl::FLOAT],
+    ),
+    (
+        Gl::RGB9_E5,
+        Gl::RGB,
+        &[Gl::UNSIGNED_INT_5_9_9_9_REV, Gl::HALF_FLOAT, Gl::FLOAT],
+    ),
+    (Gl::RGB16F, Gl::RGB, &[Gl::HALF_FLOAT, Gl::FLOAT]),
+    (Gl::RGB32F, Gl::RGB, &[Gl::FLOAT]),
+    (Gl::RGB8UI, Gl::RGB_INTEGER, &[Gl::UNSIGNED_BYTE]),
+    (Gl::RGB8I, Gl::RGB_INTEGER, &[Gl::BYTE]),
+    (Gl::RGB16UI, Gl::RGB_INTEGER, &[Gl::UNSIGNED_SHORT]),
+    (Gl::RGB16I, Gl::RGB_INTEGER, &[Gl::SHORT]),
+    (Gl::RGB32UI, Gl::RGB_INTEGER, &[Gl::UNSIGNED_INT]),
+    (Gl::RGB32I, Gl::RGB_INTEGER, &[Gl::INT]),
+    (Gl::RGBA8, Gl::RGBA, &[Gl::UNSIGNED_BYTE]),
+    (Gl::SRGB8_ALPHA8, Gl::RGBA, &[Gl::UNSIGNED_BYTE]),
+    (Gl::RGBA8_SNORM, Gl::RGBA, &[Gl::BYTE]),
+    (
+        Gl::RGB5_A1,
+        Gl::RGBA,
+        &[
+            Gl::UNSIGNED_BYTE,
+            Gl::UNSIGNED_SHORT_5_5_5_1,
+            Gl::UNSIGNED_INT_2_10_10_10_REV,
+        ],
+    ),
+    (
+        Gl::RGBA4,
+        Gl::RGBA,
+        &[Gl::UNSIGNED_BYTE, Gl::UNSIGNED_SHORT_4_4_4_4],
+    ),
+    (Gl::RGB10_A2, Gl::RGBA, &[Gl::UNSIGNED_INT_2_10_10_10_REV]),
+    (Gl::RGBA16F, Gl::RGBA, &[Gl::HALF_FLOAT, Gl::FLOAT]),
+    (Gl::RGBA32F, Gl::RGBA, &[Gl::FLOAT]),
+    (Gl::RGBA8UI, Gl::RGBA_INTEGER, &[Gl::UNSIGNED_BYTE]),
+    (Gl::RGBA8I, Gl::RGBA_INTEGER, &[Gl::BYTE]),
+    (
+        Gl::RGB10_A2UI,
+        Gl::RGBA_INTEGER,
+        &[Gl::UNSIGNED_INT_2_10_10_10_REV],
+    ),
+    (Gl::RGBA16UI, Gl::RGBA_INTEGER, &[Gl::UNSIGNED_SHORT]),
+    (Gl::RGBA16I, Gl::RGBA_INTEGER, &[Gl::SHORT]),
+    (Gl::RGBA32I, Gl::RGBA_INTEGER, &[Gl::INT]),
+    (Gl::RGBA32UI, Gl::RGBA_INTEGER, &[Gl::UNSIGNED_INT]),
+    (
+        Gl::DEPTH_COMPONENT16,
+        Gl::DEPTH_COMPONENT,
+        &[Gl::UNSIGNED_SHORT, Gl::UNSIGNED_INT],
+    ),
+    (
+        Gl::DEPTH_COMPONENT24,
+        Gl::DEPTH_COMPONENT,
+        &[Gl::UNSIGNED_INT],
+    ),
+    (Gl::DEPTH_COMPONENT32F, Gl::DEPTH_COMPONENT, &[Gl::FLOAT]),
+    (
+        Gl::DEPTH24_STENCIL8,
+        Gl::DEPTH_STENCIL,
+        &[Gl::UNSIGNED_INT_24_8],
+    ),
+    (
+        Gl::DEPTH32F_STENCIL8,
+        Gl::DEPTH_STENCIL,
+        &[Gl::FLOAT_32_UNSIGNED_INT_24_8_REV],
+    ),
+];
+
+/// Whether WebGL2 accepts textures with this combination of internal format, format and type
+pub fn is_valid_texture_format(internal_format: u32, format: u32, data_type: u32) -> bool {
+    TEXTURE_FORMATS
+        .iter()
+        .any(|(valid_internal_format, valid_format, valid_types)| {
+            *valid_internal_format == internal_format
+                && *valid_format == format
+                && valid_types.contains(&data_type)
+        })
+}
+
+/// Whether the format is read as integers (through an `isampler` / `usampler`),
+/// in which case the texture cannot be linearly filtered or mipmapped
+pub fn is_integer_texture_format(format: u32) -> bool {
+    matches!(
+        format,
+        Gl::RED_INTEGER | Gl::RG_INTEGER | Gl::RGB_INTEGER | Gl::RGBA_INTEGER
+    )
+}
+
+/// Whether the sized internal format stores integers, as with `R32UI` or `RGBA8I`
+pub fn is_integer_internal_format(internal_format: u32) -> bool {
+    TEXTURE_FORMATS
+        .iter()
+        .any(|(valid_internal_format, format, _)| {
+            *valid_internal_format == internal_format && is_integer_texture_format(*format)
+        })
+}
+
+/// Whether textures with this internal format can be sampled with `LINEAR` (or any mipmap filter other than
+/// `NEAREST_MIPMAP_NEAREST`) in WebGL2 without extensions.
+///
+/// Integer, depth / stencil and 32-bit float formats can only be sampled with nearest filtering
+/// (32-bit float formats can be filtered once `OES_texture_float_linear` is enabled).
+pub fn is_filterable_texture_format(internal_format: u32) -> bool {
+    // unsized internal formats are their own format
+    let format = TEXTURE_FORMATS
+        .iter()
+        .find(|(valid_internal_format, ..)| *valid_internal_format == internal_format)
+        .map_or(internal_format, |(_, format, _)| *format);
+
+    !is_integer_texture_format(format)
+        && !matches!(format, Gl::DEPTH_COMPONENT | Gl::DEPTH_STENCIL)
+        && !matches!(
+            internal_format,
+            Gl::R32F | Gl::RG32F | Gl::RGB32F | Gl::RGBA32F
+        )
+}
+
+/// Whether textures with this internal format can be rendered to as a color attachment
+/// in WebGL2 without extensions (following table 3.13 of the OpenGL ES 3.0 specification).
+///
+/// Float formats are only color-renderable once `EXT_color_buffer_float` is enabled.
+pub fn is_color_renderable_texture_format(internal_format: u32) -> bool {
+    matches!(
+        internal_format,
+        // unsized formats
+        Gl::RGBA
+            | Gl::RGB
+            // sized normalized formats
+            | Gl::R8
+            | Gl::RG8
+            | Gl::RGB8
+            | Gl::RGB565
+            | Gl::RGBA4
+            | Gl::RGB5_A1
+            | Gl::RGBA8
+            | Gl::RGB10_A2
+            | Gl::SRGB8_ALPHA8
+            // sized integer formats
+            | Gl::RGB10_A2UI
+            | Gl::R8I
+            | Gl::R8UI
+            | Gl::R16I
+            | Gl::R16UI
+            | Gl::R32I
+            | Gl::R32UI
+            | Gl::RG8I
+            | Gl::RG8UI
+            | Gl::RG16I
+            | Gl::RG16UI
+            | Gl::RG32I
+            | Gl::RG32UI
+            | Gl::RGBA8I
+            | Gl::RGBA8UI
+            | Gl::RGBA16I
+            | Gl::RGBA16UI
+            | Gl::RGBA32I
+            | Gl::RGBA32UI
+    )
+}
+
+/// The number of values that make up a single texel of initial data
+pub fn texel_len(format: u32, data_type: u32) -> usize {
+    let is_packed = matches!(
+        data_type,
+        Gl::UNSIGNED_SHORT_5_6_5
+            | Gl::UNSIGNED_SHORT_4_4_4_4
+            | Gl::UNSIGNED_SHORT_5_5_5_1
+            | Gl::UNSIGNED_INT_2_10_10_10_REV
+            | Gl::UNSIGNED_INT_10F_11F_11F_REV
+            | Gl::UNSIGNED_INT_5_9_9_9_REV
+            | Gl::UNSIGNED_INT_24_8
+    );
+    if is_packed {
+        return 1;
+    }
+
+    match format {
+        Gl::RGBA | Gl::RGBA_INTEGER => 4,
+        Gl::RGB | Gl::RGB_INTEGER => 3,
+        Gl::RG | Gl::RG_INTEGER | Gl::LUMINANCE_ALPHA => 2,
+        // `FLOAT_32_UNSIGNED_INT_24_8_REV` packs depth and stencil into two 32-bit values
+        Gl::DEPTH_STENCIL if data_type == Gl::FLOAT_32_UNSIGNED_INT_24_8_REV => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_combinations_from_the_webgl2_tables() {
+        assert!(is_valid_texture_format(
+            Gl::RGBA8,
+            Gl::RGBA,
+            Gl::UNSIGNED_BYTE
+        ));
+        assert!(is_valid_texture_format(Gl::RGBA16F, Gl::RGBA, Gl::FLOAT));
+        assert!(is_valid_texture_format(
+            Gl::DEPTH24_STENCIL8,
+            Gl::DEPTH_STENCIL,
+            Gl::UNSIGNED_INT_24_8
+        ));
+
+        assert!(!is_valid_texture_format(Gl::RGBA8, Gl::RGBA, Gl::FLOAT));
+        assert!(!is_valid_texture_format(
+            Gl::R32UI,
+            Gl::RED,
+            Gl::UNSIGNED_INT
+        ));
+        assert!(!is_valid_texture_format(
+            Gl::RGBA32F,
+            Gl::RGBA,
+            Gl::HALF_FLOAT
+        ));
+    }
+
+    #[test]
+    fn only_filters_formats_that_webgl2_can_interpolate() {
+        assert!(is_filterable_texture_format(Gl::RGBA8));
+        assert!(is_filterable_texture_format(Gl::RGBA16F));
+        assert!(is_filterable_texture_format(Gl::RGBA));
+
+        assert!(!is_filterable_texture_format(Gl::RGBA32F));
+        assert!(!is_filterable_texture_format(Gl::R32F));
+        assert!(!is_filterable_texture_format(Gl::R32UI));
+        assert!(!is_filterable_texture_format(Gl::DEPTH_COMPONENT24));
+        assert!(!is_filterable_texture_format(Gl::DEPTH24_STENCIL8));
+    }
+
+    #[test]
+    fn only_renders_to_core_color_formats() {
+        assert!(is_color_renderable_texture_format(Gl::RGBA8));
+        assert!(is_color_renderable_texture_format(Gl::R32UI));
+
+        assert!(!is_color_renderable_texture_format(Gl::RGBA16F));
+        assert!(!is_color_renderable_texture_format(Gl::RGBA8_SNORM));
+        assert!(!is_color_renderable_texture_format(Gl::DEPTH_COMPONENT24));
+    }
+
+    #[test]
+    fn finds_integer_internal_formats() {
+        assert!(is_integer_internal_format(Gl::R32UI));
+        assert!(is_integer_internal_format(Gl::RGBA8I));
+        assert!(!is_integer_internal_format(Gl::RGBA8));
+        assert!(!is_integer_internal_format(Gl::DEPTH24_STENCIL8));
+    }
+
+    #[test]
+    fn counts_values_per_texel() {
+        assert_eq!(texel_len(Gl::RGBA, Gl::UNSIGNED_BYTE), 4);
+        assert_eq!(texel_len(Gl::RG_INTEGER, Gl::UNSIGNED_INT), 2);
+        assert_eq!(texel_len(Gl::RGB, Gl::UNSIGNED_SHORT_5_6_5), 1);
+    }
+}
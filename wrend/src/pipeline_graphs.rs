@@ -0,0 +1,7 @@
+mod pipeline_edge;
+mod pipeline_graph;
+mod pipeline_node;
+
+pub use pipeline_edge::*;
+pub use pipeline_graph::*;
+pub use pipeline_node::*;
@@ -0,0 +1,59 @@
+use crate::PipelineNode;
+use serde::{Deserialize, Serialize};
+
+/// How two resources in a [crate::PipelineGraph] relate to one another
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PipelineRelation {
+    /// A vertex shader is linked into a program
+    VertexShader,
+    /// A fragment shader is linked into a program
+    FragmentShader,
+    /// A uniform is bound to a program
+    Uniform,
+    /// A uniform block is bound to a program
+    UniformBlock,
+    /// A buffer backs an attribute
+    AttributeBuffer,
+    /// An attribute is initialized within a VAO
+    VertexArrayObject,
+    /// An index buffer is attached to a VAO
+    IndexBuffer,
+    /// A texture or renderbuffer is attached to a framebuffer
+    FramebufferAttachment,
+}
+
+impl PipelineRelation {
+    /// Human-readable label used when exporting the graph
+    pub fn label(&self) -> &'static str {
+        match self {
+            PipelineRelation::VertexShader => "vertex shader",
+            PipelineRelation::FragmentShader => "fragment shader",
+            PipelineRelation::Uniform => "uniform",
+            PipelineRelation::UniformBlock => "uniform block",
+            PipelineRelation::AttributeBuffer => "buffer",
+            PipelineRelation::VertexArrayObject => "vao",
+            PipelineRelation::IndexBuffer => "indices",
+            PipelineRelation::FramebufferAttachment => "attachment",
+        }
+    }
+}
+
+/// A directed relationship between two resources in a [crate::PipelineGraph].
+///
+/// Edges point in the direction that data flows: e.g. from a shader to the program it is linked into.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PipelineEdge {
+    /// The resource that data flows from
+    pub from: PipelineNode,
+    /// The resource that data flows into
+    pub to: PipelineNode,
+    /// How the two resources relate
+    pub relation: PipelineRelation,
+}
+
+impl PipelineEdge {
+    /// Creates an edge pointing from `from` to `to`
+    pub fn new(from: PipelineNode, to: PipelineNode, relation: PipelineRelation) -> Self {
+        Self { from, to, relation }
+    }
+}
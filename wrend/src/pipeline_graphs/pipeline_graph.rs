@@ -0,0 +1,175 @@
+use crate::{PipelineEdge, PipelineNode};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Describes how every resource in a pipeline relates to the others:
+/// which shaders feed which programs, which uniforms are bound to which programs,
+/// which buffers back which attributes, which attributes live in which VAOs,
+/// and which textures back which framebuffers.
+///
+/// Can be serialized (e.g. to JSON) or exported as a Graphviz DOT or Mermaid diagram.
+/// Nodes and edges are sorted, so that the output is stable between builds.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct PipelineGraph {
+    nodes: Vec<PipelineNode>,
+    edges: Vec<PipelineEdge>,
+}
+
+impl PipelineGraph {
+    pub(crate) fn new(mut nodes: Vec<PipelineNode>, mut edges: Vec<PipelineEdge>) -> Self {
+        // every node that is referenced by an edge should appear in the graph
+        for edge in &edges {
+            nodes.push(edge.from.clone());
+            nodes.push(edge.to.clone());
+        }
+        nodes.sort();
+        nodes.dedup();
+        edges.sort();
+        edges.dedup();
+
+        Self { nodes, edges }
+    }
+
+    /// Every resource in the pipeline, sorted by kind and then by id
+    pub fn nodes(&self) -> &[PipelineNode] {
+        &self.nodes
+    }
+
+    /// Every relationship between the resources, sorted
+    pub fn edges(&self) -> &[PipelineEdge] {
+        &self.edges
+    }
+
+    /// Renders the graph in the Graphviz DOT language
+    pub fn to_dot(&self) -> String {
+        let quote = |node: &PipelineNode| format!("\"{}\"", node.label().replace('"', "\\\""));
+        let mut dot = String::from("digraph pipeline {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        for node in &self.nodes {
+            writeln!(dot, "    {};", quote(node)).unwrap();
+        }
+        for edge in &self.edges {
+            writeln!(
+                dot,
+                "    {} -> {} [label=\"{}\"];",
+                quote(&edge.from),
+                quote(&edge.to),
+                edge.relation.label()
+            )
+            .unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as a Mermaid flowchart
+    pub fn to_mermaid(&self) -> String {
+        // Mermaid node ids must be plain identifiers, so nodes are referred to by index
+        let node_key = |node: &PipelineNode| {
+            let index = self
+                .nodes
+                .binary_search(node)
+                .expect("Every node referenced by an edge should be in the graph");
+            format!("n{index}")
+        };
+        let mut mermaid = String::from("flowchart LR\n");
+
+        for node in &self.nodes {
+            writeln!(
+                mermaid,
+                "    {}[\"{}\"]",
+                node_key(node),
+                node.label().replace('"', "#quot;")
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            writeln!(
+                mermaid,
+                "    {} -->|{}| {}",
+                node_key(&edge.from),
+                edge.relation.label(),
+                node_key(&edge.to)
+            )
+            .unwrap();
+        }
+
+        mermaid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IdKind, PipelineRelation};
+
+    fn example_graph() -> PipelineGraph {
+        let program = PipelineNode::new(IdKind::Program, &"program");
+        PipelineGraph::new(
+            vec![PipelineNode::new(IdKind::TransformFeedback, &0u32)],
+            vec![
+                PipelineEdge::new(
+                    PipelineNode::new(IdKind::FragmentShader, &"fragment"),
+                    program.clone(),
+                    PipelineRelation::FragmentShader,
+                ),
+                PipelineEdge::new(
+                    PipelineNode::new(IdKind::VertexShader, &"vertex"),
+                    program,
+                    PipelineRelation::VertexShader,
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn nodes_are_collected_from_edges() {
+        let graph = example_graph();
+        let kinds: Vec<_> = graph.nodes().iter().map(|node| node.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                IdKind::VertexShader,
+                IdKind::FragmentShader,
+                IdKind::Program,
+                IdKind::TransformFeedback
+            ]
+        );
+        assert_eq!(graph.nodes()[0].id, "vertex");
+        assert_eq!(graph.nodes()[3].id, "0");
+    }
+
+    #[test]
+    fn exports_dot() {
+        assert_eq!(
+            example_graph().to_dot(),
+            r#"digraph pipeline {
+    rankdir=LR;
+    node [shape=box];
+    "VertexShader: vertex";
+    "FragmentShader: fragment";
+    "Program: program";
+    "TransformFeedback: 0";
+    "VertexShader: vertex" -> "Program: program" [label="vertex shader"];
+    "FragmentShader: fragment" -> "Program: program" [label="fragment shader"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn exports_mermaid() {
+        assert_eq!(
+            example_graph().to_mermaid(),
+            r#"flowchart LR
+    n0["VertexShader: vertex"]
+    n1["FragmentShader: fragment"]
+    n2["Program: program"]
+    n3["TransformFeedback: 0"]
+    n0 -->|vertex shader| n2
+    n1 -->|fragment shader| n2
+"#
+        );
+    }
+}
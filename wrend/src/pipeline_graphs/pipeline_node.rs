@@ -0,0 +1,34 @@
+use crate::IdKind;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// A single resource in a [crate::PipelineGraph]
+///
+/// Ids are stored as their `Debug` representation (without surrounding quotes for string ids),
+/// so that graphs can be described regardless of the concrete id types used by the pipeline.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PipelineNode {
+    /// The kind of resource
+    pub kind: IdKind,
+    /// The id of the resource
+    pub id: String,
+}
+
+impl PipelineNode {
+    /// Creates a node for the resource of the given kind with the given id
+    pub fn new(kind: IdKind, id: &impl Debug) -> Self {
+        let id = format!("{id:?}");
+        let id = id
+            .strip_prefix('"')
+            .and_then(|id| id.strip_suffix('"'))
+            .map(str::to_string)
+            .unwrap_or(id);
+
+        Self { kind, id }
+    }
+
+    /// Human-readable label used when exporting the graph
+    pub fn label(&self) -> String {
+        format!("{:?}: {}", self.kind, self.id)
+    }
+}
@@ -1,7 +1,9 @@
 mod program_link;
 mod program_link_builder_js;
 mod program_link_js;
+mod program_reflection;
 
 pub use program_link::*;
 pub use program_link_builder_js::*;
 pub use program_link_js::*;
+pub use program_reflection::*;
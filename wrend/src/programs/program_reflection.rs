@@ -0,0 +1,286 @@
+use std::collections::HashSet;
+use web_sys::{WebGl2RenderingContext, WebGlProgram, WebGlUniformLocation};
+
+/// A uniform that is declared and used by a linked program, as reported by `gl.getActiveUniform`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveUniform {
+    name: String,
+    gl_type: u32,
+    size: i32,
+    location: Option<WebGlUniformLocation>,
+    in_uniform_block: bool,
+}
+
+impl ActiveUniform {
+    /// Describes an active uniform. `location` is `None` for members of a uniform block
+    pub fn new(
+        name: impl Into<String>,
+        gl_type: u32,
+        size: i32,
+        location: Option<WebGlUniformLocation>,
+        in_uniform_block: bool,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            gl_type,
+            size,
+            location,
+            in_uniform_block,
+        }
+    }
+
+    /// The uniform's name. Arrays are named after their first element (`u_weights[0]`),
+    /// and every member of a struct is listed separately (`u_light.color`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The uniform's GLSL type, such as `WebGl2RenderingContext::FLOAT_VEC3`
+    pub fn gl_type(&self) -> u32 {
+        self.gl_type
+    }
+
+    /// The number of elements in the uniform, which is `1` unless the uniform is an array
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    /// The uniform's location, which is `None` for members of a uniform block
+    pub fn location(&self) -> Option<&WebGlUniformLocation> {
+        self.location.as_ref()
+    }
+
+    /// Whether the uniform is a member of a uniform block, rather than being set through a location
+    pub fn in_uniform_block(&self) -> bool {
+        self.in_uniform_block
+    }
+
+    /// Whether this uniform is the one named `name`, or the first element of the array named `name`
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name == name
+            || self
+                .name
+                .strip_suffix("[0]")
+                .is_some_and(|array_name| array_name == name)
+    }
+}
+
+/// A vertex attribute that is declared and used by a linked program, as reported by `gl.getActiveAttrib`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActiveAttribute {
+    name: String,
+    gl_type: u32,
+    size: i32,
+    location: i32,
+}
+
+impl ActiveAttribute {
+    /// Describes an active attribute
+    pub fn new(name: impl Into<String>, gl_type: u32, size: i32, location: i32) -> Self {
+        Self {
+            name: name.into(),
+            gl_type,
+            size,
+            location,
+        }
+    }
+
+    /// The attribute's name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The attribute's GLSL type, such as `WebGl2RenderingContext::FLOAT_VEC2`
+    pub fn gl_type(&self) -> u32 {
+        self.gl_type
+    }
+
+    /// The number of elements in the attribute, which is `1` unless the attribute is an array
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    /// The attribute's location
+    pub fn location(&self) -> i32 {
+        self.location
+    }
+}
+
+/// A uniform block that is declared and used by a linked program.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActiveUniformBlock {
+    name: String,
+    index: u32,
+    data_size: i32,
+}
+
+impl ActiveUniformBlock {
+    /// Describes an active uniform block
+    pub fn new(name: impl Into<String>, index: u32, data_size: i32) -> Self {
+        Self {
+            name: name.into(),
+            index,
+            data_size,
+        }
+    }
+
+    /// The block's name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The block's index within its program
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The minimum number of bytes that the block's buffer must hold
+    pub fn data_size(&self) -> i32 {
+        self.data_size
+    }
+}
+
+/// Every uniform, attribute and uniform block that a linked program actually uses.
+///
+/// Anything that a shader declares but never reads is optimized out when the program is linked,
+/// and so does not appear here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgramReflection {
+    uniforms: Vec<ActiveUniform>,
+    attributes: Vec<ActiveAttribute>,
+    uniform_blocks: Vec<ActiveUniformBlock>,
+}
+
+impl ProgramReflection {
+    /// Collects reflection data that has already been queried
+    pub fn new(
+        uniforms: Vec<ActiveUniform>,
+        attributes: Vec<ActiveAttribute>,
+        uniform_blocks: Vec<ActiveUniformBlock>,
+    ) -> Self {
+        Self {
+            uniforms,
+            attributes,
+            uniform_blocks,
+        }
+    }
+
+    /// Queries every active uniform, attribute and uniform block from a program that has already been linked
+    pub fn from_program(gl: &WebGl2RenderingContext, program: &WebGlProgram) -> Self {
+        let count = |pname: u32| {
+            gl.get_program_parameter(program, pname)
+                .as_f64()
+                .unwrap_or(0.0) as u32
+        };
+
+        let uniforms = (0..count(WebGl2RenderingContext::ACTIVE_UNIFORMS))
+            .filter_map(|index| gl.get_active_uniform(program, index))
+            .map(|info| {
+                let location = gl.get_uniform_location(program, &info.name());
+                let in_uniform_block = location.is_none();
+                ActiveUniform::new(
+                    info.name(),
+                    info.type_(),
+                    info.size(),
+                    location,
+                    in_uniform_block,
+                )
+            })
+            .collect();
+
+        let attributes = (0..count(WebGl2RenderingContext::ACTIVE_ATTRIBUTES))
+            .filter_map(|index| gl.get_active_attrib(program, index))
+            .map(|info| {
+                let location = gl.get_attrib_location(program, &info.name());
+                ActiveAttribute::new(info.name(), info.type_(), info.size(), location)
+            })
+            .collect();
+
+        let uniform_blocks = (0..count(WebGl2RenderingContext::ACTIVE_UNIFORM_BLOCKS))
+            .filter_map(|index| {
+                let name = gl.get_active_uniform_block_name(program, index)?;
+                let data_size = gl
+                    .get_active_uniform_block_parameter(
+                        program,
+                        index,
+                        WebGl2RenderingContext::UNIFORM_BLOCK_DATA_SIZE,
+                    )
+                    .ok()
+                    .and_then(|data_size| data_size.as_f64())
+                    .unwrap_or(0.0) as i32;
+                Some(ActiveUniformBlock::new(name, index, data_size))
+            })
+            .collect();
+
+        Self::new(uniforms, attributes, uniform_blocks)
+    }
+
+    /// Every active uniform, including members of uniform blocks
+    pub fn uniforms(&self) -> &Vec<ActiveUniform> {
+        &self.uniforms
+    }
+
+    /// Every active attribute
+    pub fn attributes(&self) -> &Vec<ActiveAttribute> {
+        &self.attributes
+    }
+
+    /// Every active uniform block
+    pub fn uniform_blocks(&self) -> &Vec<ActiveUniformBlock> {
+        &self.uniform_blocks
+    }
+
+    /// Finds the uniform named `name`, or the array whose name is `name` (see [ActiveUniform::has_name])
+    pub fn uniform(&self, name: &str) -> Option<&ActiveUniform> {
+        self.uniforms.iter().find(|uniform| uniform.has_name(name))
+    }
+
+    /// Finds the attribute named `name`
+    pub fn attribute(&self, name: &str) -> Option<&ActiveAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name() == name)
+    }
+
+    /// Finds the uniform block named `name`
+    pub fn uniform_block(&self, name: &str) -> Option<&ActiveUniformBlock> {
+        self.uniform_blocks
+            .iter()
+            .find(|uniform_block| uniform_block.name() == name)
+    }
+
+    /// Uniforms outside of any uniform block that aren't named by any of `linked_names`
+    pub fn unlinked_uniforms<'a>(
+        &'a self,
+        linked_names: &'a HashSet<String>,
+    ) -> impl Iterator<Item = &'a ActiveUniform> + 'a {
+        self.uniforms.iter().filter(|uniform| {
+            !uniform.in_uniform_block()
+                && !linked_names.contains(uniform.name())
+                && !uniform
+                    .name()
+                    .strip_suffix("[0]")
+                    .is_some_and(|array_name| linked_names.contains(array_name))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_arrays_by_their_own_name() {
+        let uniform = ActiveUniform::new(
+            "u_weights[0]",
+            WebGl2RenderingContext::FLOAT,
+            4,
+            None,
+            false,
+        );
+
+        assert!(uniform.has_name("u_weights"));
+        assert!(uniform.has_name("u_weights[0]"));
+        assert!(!uniform.has_name("u_weight"));
+    }
+}
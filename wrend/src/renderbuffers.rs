@@ -0,0 +1,7 @@
+mod renderbuffer;
+mod renderbuffer_link;
+mod renderbuffer_link_js;
+
+pub use renderbuffer::*;
+pub use renderbuffer_link::*;
+pub use renderbuffer_link_js::*;
@@ -0,0 +1,93 @@
+use crate::Id;
+use std::fmt::Debug;
+use std::hash::Hash;
+use web_sys::WebGlRenderbuffer;
+
+/// A renderbuffer that has been created from a [crate::RenderbufferLink], along with its storage parameters
+#[derive(Clone)]
+pub struct Renderbuffer<RenderbufferId: Id> {
+    renderbuffer_id: RenderbufferId,
+    webgl_renderbuffer: WebGlRenderbuffer,
+    internal_format: u32,
+    width: i32,
+    height: i32,
+    samples: i32,
+}
+
+impl<RenderbufferId: Id> Renderbuffer<RenderbufferId> {
+    /// Wraps a renderbuffer whose storage has already been allocated with the given parameters
+    pub fn new(
+        renderbuffer_id: RenderbufferId,
+        webgl_renderbuffer: WebGlRenderbuffer,
+        internal_format: u32,
+        (width, height): (i32, i32),
+        samples: i32,
+    ) -> Self {
+        Self {
+            renderbuffer_id,
+            webgl_renderbuffer,
+            internal_format,
+            width,
+            height,
+            samples,
+        }
+    }
+
+    /// The id of the [crate::RenderbufferLink] that this renderbuffer was created from
+    pub fn renderbuffer_id(&self) -> &RenderbufferId {
+        &self.renderbuffer_id
+    }
+
+    /// The underlying WebGL renderbuffer
+    pub fn webgl_renderbuffer(&self) -> &WebGlRenderbuffer {
+        &self.webgl_renderbuffer
+    }
+
+    /// The sized format of the renderbuffer's storage
+    pub fn internal_format(&self) -> u32 {
+        self.internal_format
+    }
+
+    /// The width of the renderbuffer's storage, in pixels
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// The height of the renderbuffer's storage, in pixels
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// The number of samples per pixel, or `0` if the renderbuffer is not multisampled
+    pub fn samples(&self) -> i32 {
+        self.samples
+    }
+}
+
+impl<RenderbufferId: Id> Debug for Renderbuffer<RenderbufferId> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Renderbuffer")
+            .field("renderbuffer_id", &self.renderbuffer_id)
+            .field("webgl_renderbuffer", &self.webgl_renderbuffer)
+            .field("internal_format", &self.internal_format)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("samples", &self.samples)
+            .finish()
+    }
+}
+
+impl<RenderbufferId: Id> Hash for Renderbuffer<RenderbufferId> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.renderbuffer_id.hash(state);
+    }
+}
+
+impl<RenderbufferId: Id> PartialEq for Renderbuffer<RenderbufferId> {
+    fn eq(&self, other: &Self) -> bool {
+        self.renderbuffer_id == other.renderbuffer_id
+            && self.webgl_renderbuffer == other.webgl_renderbuffer
+    }
+}
+
+impl<RenderbufferId: Id> Eq for Renderbuffer<RenderbufferId> {}
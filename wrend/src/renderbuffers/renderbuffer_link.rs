@@ -0,0 +1,199 @@
+use crate::{is_integer_internal_format, CreateRenderbufferError, Id, TextureSize};
+use std::fmt::Debug;
+use std::hash::Hash;
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlRenderbuffer};
+
+/// This link is used to create a renderbuffer: storage that can be rendered into through a framebuffer,
+/// but never sampled from.
+///
+/// Renderbuffers are useful for depth buffers and multisampled (MSAA) render targets.
+/// They are attached to framebuffers with [crate::FramebufferLink::add_color_renderbuffer]
+/// and [crate::FramebufferLink::set_depth_stencil_renderbuffer].
+#[derive(Clone)]
+pub struct RenderbufferLink<RenderbufferId: Id> {
+    renderbuffer_id: RenderbufferId,
+    internal_format: u32,
+    size: TextureSize,
+    samples: i32,
+}
+
+impl<RenderbufferId: Id> RenderbufferLink<RenderbufferId> {
+    /// `internal_format` is a sized format such as `DEPTH_COMPONENT24`, `DEPTH24_STENCIL8` or `RGBA8`
+    pub fn new(renderbuffer_id: RenderbufferId, internal_format: u32, size: TextureSize) -> Self {
+        Self {
+            renderbuffer_id,
+            internal_format,
+            size,
+            samples: 0,
+        }
+    }
+
+    /// The id that framebuffers use to attach the renderbuffer
+    pub fn renderbuffer_id(&self) -> &RenderbufferId {
+        &self.renderbuffer_id
+    }
+
+    /// The sized format that the renderbuffer's storage is allocated with
+    pub fn internal_format(&self) -> u32 {
+        self.internal_format
+    }
+
+    /// Either the canvas's size at build time or a fixed size
+    pub fn size(&self) -> TextureSize {
+        self.size
+    }
+
+    /// The number of samples per pixel, or `0` if the renderbuffer is not multisampled
+    pub fn samples(&self) -> i32 {
+        self.samples
+    }
+
+    /// Makes the renderbuffer multisampled, for antialiasing.
+    ///
+    /// Its contents then need to be resolved into a texture with `blitFramebuffer` before they can be sampled.
+    /// The sample count is checked against `MAX_SAMPLES` when the renderbuffer is created,
+    /// and integer formats cannot be multisampled (see [RenderbufferLink::validate_samples]).
+    pub fn set_samples(&mut self, samples: i32) -> &mut Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Checks that the sample count can be used with this renderbuffer's format,
+    /// given the context's `MAX_SAMPLES`, without making any WebGL calls
+    pub fn validate_samples(&self, max_samples: i32) -> Result<(), CreateRenderbufferError> {
+        let renderbuffer_id = format!("{:?}", self.renderbuffer_id);
+        if self.samples < 0 {
+            return Err(CreateRenderbufferError::NegativeSamples {
+                renderbuffer_id,
+                samples: self.samples,
+            });
+        }
+        if self.samples > max_samples {
+            return Err(CreateRenderbufferError::TooManySamples {
+                renderbuffer_id,
+                samples: self.samples,
+                max_samples,
+            });
+        }
+        if self.samples > 0 && is_integer_internal_format(self.internal_format) {
+            return Err(CreateRenderbufferError::IntegerMultisample {
+                renderbuffer_id,
+                internal_format: self.internal_format,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// The width and height of the renderbuffer when it is created alongside `canvas`
+    pub fn resolve_size(&self, canvas: &HtmlCanvasElement) -> (i32, i32) {
+        match self.size {
+            TextureSize::Canvas => (canvas.width() as i32, canvas.height() as i32),
+            TextureSize::Fixed { width, height } => (width, height),
+        }
+    }
+
+    /// Creates the renderbuffer and allocates its storage
+    pub fn create_renderbuffer(
+        &self,
+        gl: &WebGl2RenderingContext,
+        canvas: &HtmlCanvasElement,
+    ) -> Option<WebGlRenderbuffer> {
+        let (width, height) = self.resolve_size(canvas);
+        let webgl_renderbuffer = gl.create_renderbuffer()?;
+
+        gl.bind_renderbuffer(
+            WebGl2RenderingContext::RENDERBUFFER,
+            Some(&webgl_renderbuffer),
+        );
+        if self.samples > 0 {
+            gl.renderbuffer_storage_multisample(
+                WebGl2RenderingContext::RENDERBUFFER,
+                self.samples,
+                self.internal_format,
+                width,
+                height,
+            );
+        } else {
+            gl.renderbuffer_storage(
+                WebGl2RenderingContext::RENDERBUFFER,
+                self.internal_format,
+                width,
+                height,
+            );
+        }
+        gl.bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, None);
+
+        Some(webgl_renderbuffer)
+    }
+}
+
+impl<RenderbufferId: Id> Debug for RenderbufferLink<RenderbufferId> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RenderbufferLink")
+            .field("renderbuffer_id", &self.renderbuffer_id)
+            .field("internal_format", &self.internal_format)
+            .field("size", &self.size)
+            .field("samples", &self.samples)
+            .finish()
+    }
+}
+
+impl<RenderbufferId: Id> Hash for RenderbufferLink<RenderbufferId> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.renderbuffer_id.hash(state);
+    }
+}
+
+impl<RenderbufferId: Id> PartialEq for RenderbufferLink<RenderbufferId> {
+    fn eq(&self, other: &Self) -> bool {
+        self.renderbuffer_id == other.renderbuffer_id
+            && self.internal_format == other.internal_format
+            && self.size == other.size
+            && self.samples == other.samples
+    }
+}
+
+impl<RenderbufferId: Id> Eq for RenderbufferLink<RenderbufferId> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_samples_against_format_and_max_samples() {
+        let mut renderbuffer_link =
+            RenderbufferLink::new("color", WebGl2RenderingContext::RGBA8, TextureSize::Canvas);
+        renderbuffer_link.set_samples(4);
+        assert_eq!(renderbuffer_link.validate_samples(4), Ok(()));
+        assert_eq!(
+            renderbuffer_link.validate_samples(2),
+            Err(CreateRenderbufferError::TooManySamples {
+                renderbuffer_id: "\"color\"".to_string(),
+                samples: 4,
+                max_samples: 2,
+            })
+        );
+
+        renderbuffer_link.set_samples(-1);
+        assert_eq!(
+            renderbuffer_link.validate_samples(4),
+            Err(CreateRenderbufferError::NegativeSamples {
+                renderbuffer_id: "\"color\"".to_string(),
+                samples: -1,
+            })
+        );
+
+        let mut renderbuffer_link =
+            RenderbufferLink::new("ids", WebGl2RenderingContext::R32UI, TextureSize::Canvas);
+        assert_eq!(renderbuffer_link.validate_samples(4), Ok(()));
+        renderbuffer_link.set_samples(4);
+        assert_eq!(
+            renderbuffer_link.validate_samples(4),
+            Err(CreateRenderbufferError::IntegerMultisample {
+                renderbuffer_id: "\"ids\"".to_string(),
+                internal_format: WebGl2RenderingContext::R32UI,
+            })
+        );
+    }
+}
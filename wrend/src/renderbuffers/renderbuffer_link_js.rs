@@ -0,0 +1,84 @@
+use std::ops::{Deref, DerefMut};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{RenderbufferLink, TextureSize};
+
+/// The [RenderbufferLink] type that is exposed to JavaScript, which uses strings for ids
+pub type RenderbufferLinkJsInner = RenderbufferLink<String>;
+
+/// JavaScript wrapper around a [RenderbufferLink]
+#[wasm_bindgen(inspectable, js_name = RenderbufferLink)]
+pub struct RenderbufferLinkJs(RenderbufferLinkJsInner);
+
+#[wasm_bindgen(js_class = RenderbufferLink)]
+impl RenderbufferLinkJs {
+    /// Creates a renderbuffer of a fixed size, or of the canvas's size if no width and height are given
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        renderbuffer_id: String,
+        internal_format: u32,
+        width: Option<i32>,
+        height: Option<i32>,
+    ) -> Self {
+        let size = match (width, height) {
+            (Some(width), Some(height)) => TextureSize::Fixed { width, height },
+            _ => TextureSize::Canvas,
+        };
+        Self(RenderbufferLinkJsInner::new(
+            renderbuffer_id,
+            internal_format,
+            size,
+        ))
+    }
+
+    /// The id that framebuffers use to attach the renderbuffer
+    #[wasm_bindgen(js_name = renderbufferId)]
+    pub fn renderbuffer_id(&self) -> String {
+        self.deref().renderbuffer_id().to_owned()
+    }
+
+    /// The sized format that the renderbuffer's storage is allocated with
+    #[wasm_bindgen(js_name = internalFormat)]
+    pub fn internal_format(&self) -> u32 {
+        self.deref().internal_format()
+    }
+
+    /// The number of samples per pixel, or `0` if the renderbuffer is not multisampled
+    pub fn samples(&self) -> i32 {
+        self.deref().samples()
+    }
+
+    /// Makes the renderbuffer multisampled, for antialiasing
+    #[wasm_bindgen(js_name = setSamples)]
+    pub fn set_samples(&mut self, samples: i32) {
+        self.deref_mut().set_samples(samples);
+    }
+}
+
+impl RenderbufferLinkJs {
+    /// Unwraps the underlying [RenderbufferLink]
+    pub fn into_inner(self) -> RenderbufferLinkJsInner {
+        self.0
+    }
+}
+
+impl Deref for RenderbufferLinkJs {
+    type Target = RenderbufferLinkJsInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for RenderbufferLinkJs {
+    fn deref_mut(&mut self) -> &mut RenderbufferLinkJsInner {
+        &mut self.0
+    }
+}
+
+impl From<RenderbufferLinkJs> for RenderbufferLinkJsInner {
+    fn from(renderbuffer_link_js: RenderbufferLinkJs) -> Self {
+        renderbuffer_link_js.into_inner()
+    }
+}
@@ -1,7 +1,14 @@
+mod duplicate_id_policy;
+mod js_types;
+mod program_utils;
 mod renderer_data;
 mod renderer_data_builder_js;
 mod renderer_data_js;
+mod renderer_types;
 
+pub use duplicate_id_policy::*;
+pub use js_types::*;
 pub use renderer_data::*;
 pub use renderer_data_builder_js::*;
 pub use renderer_data_js::*;
+pub use renderer_types::*;
@@ -0,0 +1,15 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Determines what the [crate::RendererDataBuilder] does when a link or shader source
+/// is added with an id that has already been used for that kind of resource.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum DuplicateIdPolicy {
+    /// The duplicate is rejected, and building returns a [crate::RendererBuilderError::DuplicateId]
+    #[default]
+    Error,
+    /// The duplicate replaces the previous value, and a warning is logged
+    Warn,
+    /// The duplicate silently replaces the previous value
+    Replace,
+}
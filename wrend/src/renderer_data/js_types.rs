@@ -0,0 +1,25 @@
+use crate::RendererTypes;
+use js_sys::Object;
+
+/// The [`RendererTypes`] used when building and rendering from JavaScript.
+///
+/// Types are adjusted to only use JavaScript-compatible types: all ids are `String`s,
+/// and the user context is an arbitrary JavaScript `Object`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JsTypes;
+
+impl RendererTypes for JsTypes {
+    type VertexShaderId = String;
+    type FragmentShaderId = String;
+    type ProgramId = String;
+    type UniformId = String;
+    type UniformBlockId = String;
+    type BufferId = String;
+    type AttributeId = String;
+    type TextureId = String;
+    type FramebufferId = String;
+    type RenderbufferId = String;
+    type TransformFeedbackId = String;
+    type VertexArrayObjectId = String;
+    type UserCtx = Object;
+}
@@ -0,0 +1,225 @@
+use crate::{CompileShaderError, Id, IdName, LinkProgramError, ShaderType};
+use js_sys::Promise;
+use std::collections::HashMap;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, WebGl2RenderingContext, WebGlProgram, WebGlShader, WebGlUniformLocation};
+
+/// The name of the WebGL extension that allows shaders to be compiled & linked off of the main thread
+const PARALLEL_SHADER_COMPILE_EXTENSION: &str = "KHR_parallel_shader_compile";
+
+/// `COMPLETION_STATUS_KHR`, as defined by the `KHR_parallel_shader_compile` extension
+const COMPLETION_STATUS_KHR: u32 = 0x91B1;
+
+/// Takes the string source of a shader and compiles it using the supplied WebGL2RenderingContext
+///
+/// If compilation fails, the `WebGlShader` that was created is deleted before the error is returned.
+pub(crate) fn compile_shader<ShaderId: Id>(
+    gl: &WebGl2RenderingContext,
+    shader_id: &ShaderId,
+    shader_type: ShaderType,
+    source: &str,
+) -> Result<WebGlShader, CompileShaderError> {
+    let shader = start_compile_shader(gl, shader_id, shader_type, source)?;
+    finish_compile_shader(gl, shader_id, shader)
+}
+
+/// Creates a shader and starts compiling it *without* querying its status,
+/// so that the driver is free to compile it in the background.
+///
+/// The result must later be checked with [finish_compile_shader].
+pub(crate) fn start_compile_shader<ShaderId: Id>(
+    gl: &WebGl2RenderingContext,
+    shader_id: &ShaderId,
+    shader_type: ShaderType,
+    source: &str,
+) -> Result<WebGlShader, CompileShaderError> {
+    let shader =
+        gl.create_shader(shader_type.into())
+            .ok_or(CompileShaderError::NoShaderReturned {
+                shader_id: format!("{shader_id:#?}"),
+            })?;
+
+    gl.shader_source(&shader, source);
+    gl.compile_shader(&shader);
+
+    Ok(shader)
+}
+
+/// Checks the compile status of a shader that was started with [start_compile_shader].
+///
+/// This blocks until compilation has finished, unless [is_shader_complete] already returns `true`.
+pub(crate) fn finish_compile_shader<ShaderId: Id>(
+    gl: &WebGl2RenderingContext,
+    shader_id: &ShaderId,
+    shader: WebGlShader,
+) -> Result<WebGlShader, CompileShaderError> {
+    if gl
+        .get_shader_parameter(&shader, WebGl2RenderingContext::COMPILE_STATUS)
+        .as_bool()
+        .unwrap_or(false)
+    {
+        Ok(shader)
+    } else {
+        let inner_error = match gl.get_shader_info_log(&shader) {
+            Some(known_error) => CompileShaderError::KnownError {
+                shader_id: format!("{shader_id:#?}"),
+                error: known_error,
+            },
+            None => CompileShaderError::UnknownError {
+                shader_id: format!("{shader_id:#?}"),
+            },
+        };
+        gl.delete_shader(Some(&shader));
+        Err(inner_error)
+    }
+}
+
+/// Links a vertex shader and a fragment shader together into a new program,
+/// binding every attribute to its pre-assigned location before linking.
+///
+/// If linking fails, the `WebGlProgram` that was created is deleted before the error is returned.
+pub(crate) fn link_program<AttributeId: Id + IdName>(
+    gl: &WebGl2RenderingContext,
+    vertex_shader: &WebGlShader,
+    fragment_shader: &WebGlShader,
+    transform_feedback_varyings: &[String],
+    attribute_locations: &HashMap<AttributeId, u32>,
+) -> Result<WebGlProgram, LinkProgramError> {
+    let webgl_program = start_link_program(
+        gl,
+        vertex_shader,
+        fragment_shader,
+        transform_feedback_varyings,
+        attribute_locations,
+    )?;
+    finish_link_program(gl, webgl_program)
+}
+
+/// Creates a program and starts linking it *without* querying its status,
+/// so that the driver is free to link it in the background.
+///
+/// The result must later be checked with [finish_link_program].
+pub(crate) fn start_link_program<AttributeId: Id + IdName>(
+    gl: &WebGl2RenderingContext,
+    vertex_shader: &WebGlShader,
+    fragment_shader: &WebGlShader,
+    transform_feedback_varyings: &[String],
+    attribute_locations: &HashMap<AttributeId, u32>,
+) -> Result<WebGlProgram, LinkProgramError> {
+    let webgl_program = gl.create_program().ok_or(LinkProgramError::NoProgram)?;
+
+    // assign attribute locations
+    for (attribute_id, attribute_location) in attribute_locations.iter() {
+        gl.bind_attrib_location(&webgl_program, *attribute_location, &attribute_id.name());
+    }
+
+    gl.attach_shader(&webgl_program, vertex_shader);
+    gl.attach_shader(&webgl_program, fragment_shader);
+
+    if !transform_feedback_varyings.is_empty() {
+        let varyings_js_value =
+            JsValue::from_serde(&transform_feedback_varyings).map_err(|_| {
+                gl.delete_program(Some(&webgl_program));
+                LinkProgramError::CouldNotConvertVaryingsToArray
+            })?;
+        gl.transform_feedback_varyings(
+            &webgl_program,
+            &varyings_js_value,
+            WebGl2RenderingContext::INTERLEAVED_ATTRIBS,
+        )
+    }
+
+    gl.link_program(&webgl_program);
+
+    Ok(webgl_program)
+}
+
+/// Checks the link status of a program that was started with [start_link_program].
+///
+/// This blocks until linking has finished, unless [is_program_complete] already returns `true`.
+pub(crate) fn finish_link_program(
+    gl: &WebGl2RenderingContext,
+    webgl_program: WebGlProgram,
+) -> Result<WebGlProgram, LinkProgramError> {
+    if gl
+        .get_program_parameter(&webgl_program, WebGl2RenderingContext::LINK_STATUS)
+        .as_bool()
+        .unwrap_or(false)
+    {
+        Ok(webgl_program)
+    } else {
+        let inner_error = match gl.get_program_info_log(&webgl_program) {
+            Some(known_error) => LinkProgramError::KnownError(known_error),
+            None => LinkProgramError::UnknownError,
+        };
+        gl.delete_program(Some(&webgl_program));
+        Err(inner_error)
+    }
+}
+
+/// Finds the location of every member of a struct or array uniform, skipping any that the program doesn't use.
+///
+/// Returns `None` if none of the members can be found, which usually means that the uniform's name is wrong.
+pub(crate) fn find_field_locations(
+    gl: &WebGl2RenderingContext,
+    program: &WebGlProgram,
+    uniform_name: &str,
+    fields: &[String],
+) -> Option<HashMap<String, WebGlUniformLocation>> {
+    let field_locations: HashMap<_, _> = fields
+        .iter()
+        .filter_map(|field| {
+            gl.get_uniform_location(program, &format!("{uniform_name}{field}"))
+                .map(|field_location| (field.clone(), field_location))
+        })
+        .collect();
+
+    if field_locations.is_empty() {
+        None
+    } else {
+        Some(field_locations)
+    }
+}
+
+/// Enables the `KHR_parallel_shader_compile` extension, returning `false` if it is unavailable
+pub(crate) fn enable_parallel_shader_compile(gl: &WebGl2RenderingContext) -> bool {
+    matches!(
+        gl.get_extension(PARALLEL_SHADER_COMPILE_EXTENSION),
+        Ok(Some(_))
+    )
+}
+
+/// Returns whether the shader has finished compiling, without blocking.
+///
+/// Must only be called once `KHR_parallel_shader_compile` has been enabled.
+pub(crate) fn is_shader_complete(gl: &WebGl2RenderingContext, shader: &WebGlShader) -> bool {
+    gl.get_shader_parameter(shader, COMPLETION_STATUS_KHR)
+        .as_bool()
+        .unwrap_or(true)
+}
+
+/// Returns whether the program has finished linking, without blocking.
+///
+/// Must only be called once `KHR_parallel_shader_compile` has been enabled.
+pub(crate) fn is_program_complete(gl: &WebGl2RenderingContext, program: &WebGlProgram) -> bool {
+    gl.get_program_parameter(program, COMPLETION_STATUS_KHR)
+        .as_bool()
+        .unwrap_or(true)
+}
+
+/// Resolves on the next animation frame (or immediately if there is no window)
+pub(crate) async fn next_animation_frame() {
+    let promise = Promise::new(&mut |resolve, _reject| {
+        let requested = window()
+            .map(|window| window.request_animation_frame(&resolve).is_ok())
+            .unwrap_or(false);
+
+        if !requested {
+            let _ = resolve.call0(&JsValue::NULL);
+        }
+    });
+
+    // resolving with `undefined` never fails
+    let _ = JsFuture::from(promise).await;
+}
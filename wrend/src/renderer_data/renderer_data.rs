@@ -1,104 +1,71 @@
+mod buffers;
+mod draw;
+
 use crate::{
-    Attribute, AttributeLink, Bridge, Buffer, BufferLink, BuildRendererError, Callback,
-    CompileShaderError, CreateAttributeError, CreateBufferError, CreateTextureError,
-    CreateTransformFeedbackError, CreateUniformError, CreateVAOError, Framebuffer, FramebufferLink,
-    GetContextCallback, Id, IdDefault, IdName, LinkProgramError, ProgramLink, RenderCallback,
-    Renderer, RendererBuilderError, RendererDataJs, RendererDataJsInner, SaveContextError,
-    ShaderType, Texture, TextureLink, TransformFeedbackLink, Uniform, UniformContext, UniformLink,
-    WebGlContextError,
+    uniform_array_values, uniform_struct_values, AssetLoader, Attribute, AttributeLink,
+    BindTexturesError, Bridge, Buffer, BufferLink, BuildRendererError, Callback,
+    CreateAttributeError, CreateFramebufferError, CreateRenderbufferError, CreateTextureError,
+    CreateTransformFeedbackError, CreateUniformBlockError, CreateUniformError, DuplicateIdPolicy,
+    FetchAssetLoader, Framebuffer, FramebufferAttachment, FramebufferLink, GetContextCallback,
+    IdKind, IdName, IndexBuffer, IndexBufferLink, JsTypes, LinkProgramError, LoadAssetError,
+    LoadProgress, LoadProgressCallback, MissingUniformPolicy, PipelineDescriptor, PipelineEdge,
+    PipelineGraph, PipelineNode, PipelineRelation, ProgramLink, ProgramReflection, RenderCallback,
+    Renderbuffer, RenderbufferLink, Renderer, RendererBuilderError, RendererDataJs, RendererTypes,
+    ReplaceShaderError, SaveContextError, ShaderType, Std140, Texture, TextureDescriptor,
+    TextureLink, TextureTarget, TextureUnits, TransformFeedbackLink, Uniform, UniformBlock,
+    UniformBlockLink, UniformContext, UniformHandle, UniformLink, UniformValue, Uniforms,
+    UpdateUniformBlockError, ValidationError, ValidationProblem, WebGlContextError,
 };
 
+use super::program_utils;
+use js_sys::Array;
+use log::warn;
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
 
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{
-    window, HtmlAnchorElement, HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram,
-    WebGlShader, WebGlTransformFeedback, WebGlVertexArrayObject,
+    window, HtmlAnchorElement, HtmlCanvasElement, HtmlImageElement, WebGl2RenderingContext,
+    WebGlProgram, WebGlShader, WebGlTransformFeedback, WebGlVertexArrayObject, WebglLoseContext,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RendererData<
-    VertexShaderId: Id = IdDefault,
-    FragmentShaderId: Id = IdDefault,
-    ProgramId: Id = IdDefault,
-    UniformId: Id + IdName = IdDefault,
-    BufferId: Id = IdDefault,
-    AttributeId: Id + IdName = IdDefault,
-    TextureId: Id = IdDefault,
-    FramebufferId: Id = IdDefault,
-    TransformFeedbackId: Id = IdDefault,
-    VertexArrayObjectId: Id = IdDefault,
-    UserCtx: Clone + 'static = (),
-> {
+/// Owns every WebGL resource that was created while building the rendering pipeline.
+///
+/// All of these resources are deleted when `RendererData` is dropped (or when [RendererData::dispose]
+/// is called), so this type is intentionally not `Clone`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RendererData<T: RendererTypes> {
     canvas: HtmlCanvasElement,
     gl: WebGl2RenderingContext,
-    fragment_shaders: HashMap<FragmentShaderId, WebGlShader>,
-    vertex_shaders: HashMap<VertexShaderId, WebGlShader>,
-    programs: HashMap<ProgramId, WebGlProgram>,
-    render_callback: RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >,
-    uniforms: HashMap<UniformId, Uniform<ProgramId, UniformId>>,
-    user_ctx: Option<UserCtx>,
-    attributes: HashMap<AttributeId, Attribute<VertexArrayObjectId, BufferId, AttributeId>>,
-    buffers: HashMap<BufferId, Buffer<BufferId>>,
-    textures: HashMap<TextureId, Texture<TextureId>>,
-    vertex_array_objects: HashMap<VertexArrayObjectId, WebGlVertexArrayObject>,
-    framebuffers: HashMap<FramebufferId, Framebuffer<FramebufferId>>,
-    transform_feedbacks: HashMap<TransformFeedbackId, WebGlTransformFeedback>,
+    fragment_shaders: HashMap<T::FragmentShaderId, WebGlShader>,
+    vertex_shaders: HashMap<T::VertexShaderId, WebGlShader>,
+    programs: HashMap<T::ProgramId, WebGlProgram>,
+    program_reflections: HashMap<T::ProgramId, ProgramReflection>,
+    render_callback: RenderCallback<T>,
+    uniforms: HashMap<T::UniformId, Uniform<T::ProgramId, T::UniformId>>,
+    uniform_blocks: HashMap<T::UniformBlockId, UniformBlock<T::ProgramId, T::UniformBlockId>>,
+    user_ctx: Option<T::UserCtx>,
+    attributes:
+        HashMap<T::AttributeId, Attribute<T::VertexArrayObjectId, T::BufferId, T::AttributeId>>,
+    buffers: HashMap<T::BufferId, Buffer<T::BufferId>>,
+    index_buffers: HashMap<T::BufferId, IndexBuffer<T::VertexArrayObjectId, T::BufferId>>,
+    textures: HashMap<T::TextureId, Texture<T::TextureId>>,
+    vertex_array_objects: HashMap<T::VertexArrayObjectId, WebGlVertexArrayObject>,
+    framebuffers: HashMap<T::FramebufferId, Framebuffer<T::FramebufferId>>,
+    renderbuffers: HashMap<T::RenderbufferId, Renderbuffer<T::RenderbufferId>>,
+    transform_feedbacks: HashMap<T::TransformFeedbackId, WebGlTransformFeedback>,
+    /// The texture units assigned to each program's samplers by [RendererData::bind_textures]
+    texture_units: RefCell<HashMap<T::ProgramId, TextureUnits<T::UniformId>>>,
+    /// The links and callbacks that this `RendererData` was built from,
+    /// kept so that the pipeline can be rebuilt after the WebGL2 context is restored
+    builder: RendererDataBuilder<T>,
+    is_disposed: bool,
 }
 
 /// Public API
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    >
-    RendererData<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
-    pub fn builder() -> RendererDataBuilder<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    > {
+impl<T: RendererTypes> RendererData<T> {
+    pub fn builder() -> RendererDataBuilder<T> {
         RendererDataBuilder::default()
     }
 
@@ -110,71 +77,248 @@ impl<
         &self.gl
     }
 
-    pub fn fragment_shader(&self, fragment_shader_id: &FragmentShaderId) -> Option<&WebGlShader> {
+    pub fn fragment_shader(
+        &self,
+        fragment_shader_id: &T::FragmentShaderId,
+    ) -> Option<&WebGlShader> {
         self.fragment_shaders.get(fragment_shader_id)
     }
 
-    pub fn fragment_shaders(&self) -> &HashMap<FragmentShaderId, WebGlShader> {
+    pub fn fragment_shaders(&self) -> &HashMap<T::FragmentShaderId, WebGlShader> {
         &self.fragment_shaders
     }
 
-    pub fn vertex_shader(&self, vertex_shader_id: &VertexShaderId) -> Option<&WebGlShader> {
+    pub fn vertex_shader(&self, vertex_shader_id: &T::VertexShaderId) -> Option<&WebGlShader> {
         self.vertex_shaders.get(vertex_shader_id)
     }
 
-    pub fn vertex_shaders(&self) -> &HashMap<VertexShaderId, WebGlShader> {
+    pub fn vertex_shaders(&self) -> &HashMap<T::VertexShaderId, WebGlShader> {
         &self.vertex_shaders
     }
 
-    pub fn program(&self, program_id: &ProgramId) -> Option<&WebGlProgram> {
+    /// Gets the source that the fragment shader was most recently compiled from
+    pub fn fragment_shader_src(&self, fragment_shader_id: &T::FragmentShaderId) -> Option<&str> {
+        self.builder
+            .fragment_shader_sources
+            .get(fragment_shader_id)
+            .map(String::as_str)
+    }
+
+    /// Gets the source that the vertex shader was most recently compiled from
+    pub fn vertex_shader_src(&self, vertex_shader_id: &T::VertexShaderId) -> Option<&str> {
+        self.builder
+            .vertex_shader_sources
+            .get(vertex_shader_id)
+            .map(String::as_str)
+    }
+
+    /// Recompiles a fragment shader from a new source and relinks every program that uses it.
+    ///
+    /// The uniforms of each relinked program have their locations re-resolved (and their initialize
+    /// callbacks re-run), and attributes are bound to the same locations as before, so existing VAOs
+    /// remain valid.
+    ///
+    /// If the shader fails to compile or any dependent program fails to link, nothing is replaced:
+    /// the previous shader and programs stay active and the error is returned.
+    pub fn replace_fragment_shader_src(
+        &mut self,
+        fragment_shader_id: &T::FragmentShaderId,
+        fragment_shader_src: impl Into<String>,
+    ) -> Result<&mut Self, ReplaceShaderError> {
+        let fragment_shader_src = fragment_shader_src.into();
+        if !self.fragment_shaders.contains_key(fragment_shader_id) {
+            return Err(ReplaceShaderError::ShaderNotFound {
+                shader_id: format!("{fragment_shader_id:?}"),
+            });
+        }
+
+        let new_shader = program_utils::compile_shader(
+            &self.gl,
+            fragment_shader_id,
+            ShaderType::FragmentShader,
+            &fragment_shader_src,
+        )?;
+
+        let program_links: Vec<_> = self
+            .builder
+            .program_links
+            .iter()
+            .filter(|program_link| program_link.fragment_shader_id() == fragment_shader_id)
+            .cloned()
+            .collect();
+
+        let relink_result = self.relink_programs(&program_links, |program_link| {
+            let vertex_shader = self
+                .vertex_shaders
+                .get(program_link.vertex_shader_id())
+                .ok_or(LinkProgramError::VertexShaderNotFound)?;
+            Ok((vertex_shader, &new_shader))
+        });
+
+        if let Err(error) =
+            relink_result.and_then(|new_programs| self.replace_programs(new_programs))
+        {
+            self.gl.delete_shader(Some(&new_shader));
+            return Err(error);
+        }
+
+        if let Some(old_shader) = self
+            .fragment_shaders
+            .insert(fragment_shader_id.clone(), new_shader)
+        {
+            self.gl.delete_shader(Some(&old_shader));
+        }
+        self.builder
+            .fragment_shader_sources
+            .insert(fragment_shader_id.clone(), fragment_shader_src);
+
+        Ok(self)
+    }
+
+    /// Recompiles a vertex shader from a new source and relinks every program that uses it.
+    ///
+    /// See [RendererData::replace_fragment_shader_src] for details.
+    pub fn replace_vertex_shader_src(
+        &mut self,
+        vertex_shader_id: &T::VertexShaderId,
+        vertex_shader_src: impl Into<String>,
+    ) -> Result<&mut Self, ReplaceShaderError> {
+        let vertex_shader_src = vertex_shader_src.into();
+        if !self.vertex_shaders.contains_key(vertex_shader_id) {
+            return Err(ReplaceShaderError::ShaderNotFound {
+                shader_id: format!("{vertex_shader_id:?}"),
+            });
+        }
+
+        let new_shader = program_utils::compile_shader(
+            &self.gl,
+            vertex_shader_id,
+            ShaderType::VertexShader,
+            &vertex_shader_src,
+        )?;
+
+        let program_links: Vec<_> = self
+            .builder
+            .program_links
+            .iter()
+            .filter(|program_link| program_link.vertex_shader_id() == vertex_shader_id)
+            .cloned()
+            .collect();
+
+        let relink_result = self.relink_programs(&program_links, |program_link| {
+            let fragment_shader = self
+                .fragment_shaders
+                .get(program_link.fragment_shader_id())
+                .ok_or(LinkProgramError::FragmentShaderNotFound)?;
+            Ok((&new_shader, fragment_shader))
+        });
+
+        if let Err(error) =
+            relink_result.and_then(|new_programs| self.replace_programs(new_programs))
+        {
+            self.gl.delete_shader(Some(&new_shader));
+            return Err(error);
+        }
+
+        if let Some(old_shader) = self
+            .vertex_shaders
+            .insert(vertex_shader_id.clone(), new_shader)
+        {
+            self.gl.delete_shader(Some(&old_shader));
+        }
+        self.builder
+            .vertex_shader_sources
+            .insert(vertex_shader_id.clone(), vertex_shader_src);
+
+        Ok(self)
+    }
+
+    pub fn program(&self, program_id: &T::ProgramId) -> Option<&WebGlProgram> {
         self.programs.get(program_id)
     }
 
-    pub fn programs(&self) -> &HashMap<ProgramId, WebGlProgram> {
+    pub fn programs(&self) -> &HashMap<T::ProgramId, WebGlProgram> {
         &self.programs
     }
 
-    pub fn uniform(&self, uniform_id: &UniformId) -> Option<&Uniform<ProgramId, UniformId>> {
+    /// Gets every uniform, attribute and uniform block that the program actually uses,
+    /// as queried from WebGL after it was linked
+    pub fn program_reflection(&self, program_id: &T::ProgramId) -> Option<&ProgramReflection> {
+        self.program_reflections.get(program_id)
+    }
+
+    pub fn program_reflections(&self) -> &HashMap<T::ProgramId, ProgramReflection> {
+        &self.program_reflections
+    }
+
+    pub fn uniform(
+        &self,
+        uniform_id: &T::UniformId,
+    ) -> Option<&Uniform<T::ProgramId, T::UniformId>> {
         self.uniforms.get(uniform_id)
     }
 
-    pub fn uniforms(&self) -> &HashMap<UniformId, Uniform<ProgramId, UniformId>> {
+    pub fn uniforms(&self) -> &HashMap<T::UniformId, Uniform<T::ProgramId, T::UniformId>> {
         &self.uniforms
     }
 
-    pub fn buffer(&self, buffer_id: &BufferId) -> Option<&Buffer<BufferId>> {
+    /// Gets a handle for setting a uniform's value directly, so that it is only uploaded when it changes.
+    ///
+    /// See [UniformHandle].
+    pub fn uniform_handle<V: Into<UniformValue>>(
+        &self,
+        uniform_id: &T::UniformId,
+    ) -> Option<UniformHandle<V>> {
+        self.uniforms.get(uniform_id).map(Uniform::handle)
+    }
+
+    pub fn uniform_block(
+        &self,
+        uniform_block_id: &T::UniformBlockId,
+    ) -> Option<&UniformBlock<T::ProgramId, T::UniformBlockId>> {
+        self.uniform_blocks.get(uniform_block_id)
+    }
+
+    pub fn uniform_blocks(
+        &self,
+    ) -> &HashMap<T::UniformBlockId, UniformBlock<T::ProgramId, T::UniformBlockId>> {
+        &self.uniform_blocks
+    }
+
+    pub fn buffer(&self, buffer_id: &T::BufferId) -> Option<&Buffer<T::BufferId>> {
         self.buffers.get(buffer_id)
     }
 
-    pub fn buffers(&self) -> &HashMap<BufferId, Buffer<BufferId>> {
+    pub fn buffers(&self) -> &HashMap<T::BufferId, Buffer<T::BufferId>> {
         &self.buffers
     }
 
     pub fn attribute(
         &self,
-        attribute_id: &AttributeId,
-    ) -> Option<&Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
+        attribute_id: &T::AttributeId,
+    ) -> Option<&Attribute<T::VertexArrayObjectId, T::BufferId, T::AttributeId>> {
         self.attributes.get(attribute_id)
     }
 
     pub fn attributes(
         &self,
-    ) -> &HashMap<AttributeId, Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
+    ) -> &HashMap<T::AttributeId, Attribute<T::VertexArrayObjectId, T::BufferId, T::AttributeId>>
+    {
         &self.attributes
     }
 
-    pub fn texture(&self, texture_id: &TextureId) -> Option<&Texture<TextureId>> {
+    pub fn texture(&self, texture_id: &T::TextureId) -> Option<&Texture<T::TextureId>> {
         self.textures.get(texture_id)
     }
 
-    pub fn textures(&self) -> &HashMap<TextureId, Texture<TextureId>> {
+    pub fn textures(&self) -> &HashMap<T::TextureId, Texture<T::TextureId>> {
         &self.textures
     }
 
     pub fn textures_by_id(
         &self,
-        texture_ids: impl Into<Bridge<TextureId>>,
-    ) -> Vec<&Texture<TextureId>> {
+        texture_ids: impl Into<Bridge<T::TextureId>>,
+    ) -> Vec<&Texture<T::TextureId>> {
         let texture_ids: Bridge<_> = texture_ids.into();
         let texture_ids: Vec<_> = texture_ids.into();
         let mut textures = Vec::with_capacity(texture_ids.len());
@@ -187,31 +331,120 @@ impl<
         textures
     }
 
+    /// Binds each texture to a texture unit and points its sampler uniform at that unit,
+    /// making `program_id` the current program.
+    ///
+    /// Each sampler of a program is given its own unit the first time it is bound, and keeps that unit
+    /// afterwards (see [TextureUnits]), so a program's textures can be bound over several calls.
+    /// Nothing is bound if any id is unknown, or if the program's samplers would need more units than
+    /// `MAX_TEXTURE_IMAGE_UNITS` allows.
+    pub fn bind_textures(
+        &self,
+        program_id: &T::ProgramId,
+        samplers: &[(T::UniformId, T::TextureId)],
+    ) -> Result<&Self, BindTexturesError> {
+        let program =
+            self.programs
+                .get(program_id)
+                .ok_or_else(|| BindTexturesError::ProgramNotFound {
+                    program_id: format!("{program_id:?}"),
+                })?;
+
+        // units are only committed once every sampler has been assigned one successfully
+        let mut texture_units = self
+            .texture_units
+            .borrow()
+            .get(program_id)
+            .cloned()
+            .unwrap_or_default();
+        let units: Vec<_> = samplers
+            .iter()
+            .map(|(uniform_id, _)| texture_units.assign(uniform_id))
+            .collect();
+        let max_texture_image_units = self.max_texture_image_units();
+        if texture_units.len() > max_texture_image_units {
+            return Err(BindTexturesError::TooManyTextures {
+                program_id: format!("{program_id:?}"),
+                sampler_count: texture_units.len(),
+                max_texture_image_units,
+            });
+        }
+
+        let mut bindings = Vec::with_capacity(samplers.len());
+        for (uniform_id, texture_id) in samplers {
+            let uniform = self.uniforms.get(uniform_id).ok_or_else(|| {
+                BindTexturesError::UniformNotFound {
+                    uniform_id: format!("{uniform_id:?}"),
+                }
+            })?;
+            let texture = self.textures.get(texture_id).ok_or_else(|| {
+                BindTexturesError::TextureNotFound {
+                    texture_id: format!("{texture_id:?}"),
+                }
+            })?;
+            bindings.push((uniform, texture));
+        }
+
+        self.texture_units
+            .borrow_mut()
+            .insert(program_id.clone(), texture_units);
+        self.gl.use_program(Some(program));
+        for ((uniform, texture), unit) in bindings.into_iter().zip(units) {
+            let target = texture
+                .descriptor()
+                .map(|descriptor| descriptor.target.into())
+                .unwrap_or(WebGl2RenderingContext::TEXTURE_2D);
+            self.gl
+                .active_texture(WebGl2RenderingContext::TEXTURE0 + unit);
+            self.gl.bind_texture(target, Some(texture.webgl_texture()));
+
+            // samplers that were optimized out of the program have no location
+            if let Some(location) = uniform.uniform_locations().get(program_id) {
+                self.gl.uniform1i(Some(location), unit as i32);
+            }
+        }
+
+        Ok(self)
+    }
+
     pub fn framebuffer(
         &self,
-        framebuffer_id: &FramebufferId,
-    ) -> Option<&Framebuffer<FramebufferId>> {
+        framebuffer_id: &T::FramebufferId,
+    ) -> Option<&Framebuffer<T::FramebufferId>> {
         self.framebuffers.get(framebuffer_id)
     }
 
+    /// The renderbuffer created from the [RenderbufferLink] with the given id
+    pub fn renderbuffer(
+        &self,
+        renderbuffer_id: &T::RenderbufferId,
+    ) -> Option<&Renderbuffer<T::RenderbufferId>> {
+        self.renderbuffers.get(renderbuffer_id)
+    }
+
+    /// Every renderbuffer, by id
+    pub fn renderbuffers(&self) -> &HashMap<T::RenderbufferId, Renderbuffer<T::RenderbufferId>> {
+        &self.renderbuffers
+    }
+
     pub fn transform_feedback(
         &self,
-        transform_feedback_id: &TransformFeedbackId,
+        transform_feedback_id: &T::TransformFeedbackId,
     ) -> Option<&WebGlTransformFeedback> {
         self.transform_feedbacks.get(transform_feedback_id)
     }
 
-    pub fn vao(&self, vao_id: &VertexArrayObjectId) -> Option<&WebGlVertexArrayObject> {
+    pub fn vao(&self, vao_id: &T::VertexArrayObjectId) -> Option<&WebGlVertexArrayObject> {
         self.vertex_array_objects.get(vao_id)
     }
 
     // @todo - enable ctx to be returned unconditionally (depending on if it's set or not)
-    pub fn user_ctx(&self) -> Option<&UserCtx> {
+    pub fn user_ctx(&self) -> Option<&T::UserCtx> {
         self.user_ctx.as_ref()
     }
 
     /// Switches to using new program and its associated VAO
-    pub fn use_program(&self, program_id: &ProgramId) -> &Self {
+    pub fn use_program(&self, program_id: &T::ProgramId) -> &Self {
         let program = self
             .programs
             .get(program_id)
@@ -222,7 +455,7 @@ impl<
         self
     }
 
-    pub fn use_vao(&self, vao_id: &VertexArrayObjectId) -> &Self {
+    pub fn use_vao(&self, vao_id: &T::VertexArrayObjectId) -> &Self {
         let vao = self
             .vertex_array_objects
             .get(vao_id)
@@ -238,7 +471,7 @@ impl<
     ///
     /// Calls "use_program" on the appropriate program before each uniform's update function (so this is not
     /// necessary to do within the callback itself, unless you need to change programs, for whatever reason).
-    pub fn update_uniform(&self, uniform_id: &UniformId) -> &Self {
+    pub fn update_uniform(&self, uniform_id: &T::UniformId) -> &Self {
         let now = Self::now();
         let _user_ctx = self.user_ctx();
         let gl = self.gl();
@@ -262,6 +495,57 @@ impl<
         self
     }
 
+    /// Uploads every element of `values` to a uniform created with [crate::UniformLink::array_of],
+    /// in every program where it is used.
+    ///
+    /// Elements beyond the length of the array in the shader, and members the shader doesn't use, are skipped.
+    pub fn update_uniform_array<V: Uniforms>(
+        &self,
+        uniform_id: &T::UniformId,
+        values: &[V],
+    ) -> &Self {
+        self.uniforms
+            .get(uniform_id)
+            .expect("UniformId should exist in registered uniforms")
+            .update_fields(&self.gl, &self.programs, uniform_array_values(values));
+
+        self
+    }
+
+    /// Uploads every member of `value` to a uniform created with [crate::UniformLink::struct_of],
+    /// in every program where it is used.
+    pub fn update_uniform_struct<V: Uniforms>(
+        &self,
+        uniform_id: &T::UniformId,
+        value: &V,
+    ) -> &Self {
+        self.uniforms
+            .get(uniform_id)
+            .expect("UniformId should exist in registered uniforms")
+            .update_fields(&self.gl, &self.programs, uniform_struct_values(value));
+
+        self
+    }
+
+    /// Packs `value` in `std140` layout and uploads it to the uniform block's buffer.
+    ///
+    /// Unlike [RendererData::update_uniform], this only needs to be done once for every program
+    /// that uses the block, since they all share the same buffer.
+    pub fn update_uniform_block(
+        &self,
+        uniform_block_id: &T::UniformBlockId,
+        value: &impl Std140,
+    ) -> Result<&Self, UpdateUniformBlockError> {
+        self.uniform_blocks
+            .get(uniform_block_id)
+            .ok_or_else(|| UpdateUniformBlockError::UniformBlockNotFound {
+                uniform_block_id: format!("{uniform_block_id:?}"),
+            })?
+            .update(&self.gl, value)?;
+
+        Ok(self)
+    }
+
     /// Note: if a JavaScript `render` callback has been supplied, then this function will NOT call
     /// try to call it with `RendererData` passed in, because doing so would require copying all internal
     /// data in order to pass that data into JavaScript, which would be very slow for large `RenderData` objects.
@@ -274,6 +558,87 @@ impl<
         self
     }
 
+    /// Returns `true` once [RendererData::dispose] has been called
+    pub fn is_disposed(&self) -> bool {
+        self.is_disposed
+    }
+
+    /// Deletes every WebGL object owned by this `RendererData`: programs, shaders, buffers,
+    /// textures, framebuffers, VAOs and transform feedbacks.
+    ///
+    /// If `lose_context_on_dispose` was set on the builder, the WebGL2 context is also released
+    /// through the `WEBGL_lose_context` extension, which frees up one of the browser's limited context slots.
+    ///
+    /// This is called automatically when `RendererData` is dropped. Calling it more than once is a no-op.
+    /// After disposal, all resource getters return `None`.
+    pub fn dispose(&mut self) {
+        if self.is_disposed {
+            return;
+        }
+        self.is_disposed = true;
+        self.delete_resources();
+
+        if self.builder.lose_context_on_dispose {
+            let lose_context_extension = self
+                .gl
+                .get_extension("WEBGL_lose_context")
+                .ok()
+                .flatten()
+                .and_then(|extension| extension.dyn_into::<WebglLoseContext>().ok());
+
+            match lose_context_extension {
+                Some(lose_context_extension) => lose_context_extension.lose_context(),
+                None => warn!("`WEBGL_lose_context` extension is not available. The WebGL2 context could not be released"),
+            }
+        }
+    }
+
+    /// Describes how the resources in this `RendererData` relate to one another.
+    ///
+    /// See [RendererDataBuilder::describe].
+    pub fn describe(&self) -> PipelineGraph {
+        self.builder.describe()
+    }
+
+    /// Returns `true` if the WebGL2 context has been lost and not yet restored
+    pub fn is_context_lost(&self) -> bool {
+        self.gl.is_context_lost()
+    }
+
+    /// Recreates every WebGL resource using the links and callbacks that this `RendererData` was built from.
+    ///
+    /// This is how the pipeline is restored after a `webglcontextrestored` event, since every resource
+    /// created before the context was lost is no longer usable. Shaders are compiled from their most recent
+    /// sources, and all create and initialize callbacks are run again.
+    ///
+    /// If rebuilding fails, every resource that was created before the failure is deleted and the error
+    /// is returned. The previous resources are left as they were, which means they are still unusable
+    /// if the context was lost.
+    pub fn rebuild(&mut self) -> Result<&mut Self, RendererBuilderError> {
+        let mut builder = self.builder.clone();
+        builder.user_ctx = self.user_ctx.clone();
+        builder.create_resources()?;
+
+        self.delete_resources();
+        self.fragment_shaders = builder.fragment_shaders;
+        self.vertex_shaders = builder.vertex_shaders;
+        self.programs = builder.programs;
+        self.program_reflections = builder.program_reflections;
+        self.uniforms = builder.uniforms;
+        self.uniform_blocks = builder.uniform_blocks;
+        self.buffers = builder.buffers;
+        self.index_buffers = builder.index_buffers;
+        self.textures = builder.textures;
+        self.framebuffers = builder.framebuffers;
+        self.renderbuffers = builder.renderbuffers;
+        self.attributes = builder.attributes;
+        self.vertex_array_objects = builder.vertex_array_objects;
+        self.transform_feedbacks = builder.transform_feedbacks;
+        self.is_disposed = false;
+
+        Ok(self)
+    }
+
     pub fn save_image(&self) {
         let window = window().unwrap();
         let document = window.document().unwrap();
@@ -296,21 +661,7 @@ impl<
 
     /// Moves Renderer into a `Renderer` struct, providing additional functionality like
     /// managed animations and recording.
-    pub fn into_renderer(
-        self,
-    ) -> Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    > {
+    pub fn into_renderer(self) -> Renderer<T> {
         self.into()
     }
 
@@ -321,151 +672,366 @@ impl<
         window().unwrap().performance().unwrap().now()
     }
 
-    pub fn render_callback(
-        &self,
-    ) -> RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    > {
+    pub fn render_callback(&self) -> RenderCallback<T> {
         self.render_callback.to_owned()
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    > AsRef<HtmlCanvasElement>
-    for RendererData<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+/// Private API
+impl<T: RendererTypes> RendererData<T> {
+    /// The number of texture units available to a fragment shader.
+    ///
+    /// Falls back to 16, the minimum that every WebGL2 implementation provides, if the query fails
+    /// (e.g. while the context is lost).
+    fn max_texture_image_units(&self) -> usize {
+        const MIN_MAX_TEXTURE_IMAGE_UNITS: usize = 16;
+
+        self.gl
+            .get_parameter(WebGl2RenderingContext::MAX_TEXTURE_IMAGE_UNITS)
+            .ok()
+            .and_then(|max_texture_image_units| max_texture_image_units.as_f64())
+            .map(|max_texture_image_units| max_texture_image_units as usize)
+            .unwrap_or(MIN_MAX_TEXTURE_IMAGE_UNITS)
+    }
+
+    /// Deletes every WebGL object owned by this `RendererData`, leaving all resource maps empty.
+    ///
+    /// Deleting objects that belong to a lost context is a no-op, so this is always safe to call.
+    fn delete_resources(&mut self) {
+        self.gl.use_program(None);
+        self.gl.bind_vertex_array(None);
+
+        for (_, program) in self.programs.drain() {
+            self.gl.delete_program(Some(&program));
+        }
+        for (_, shader) in self.vertex_shaders.drain() {
+            self.gl.delete_shader(Some(&shader));
+        }
+        for (_, shader) in self.fragment_shaders.drain() {
+            self.gl.delete_shader(Some(&shader));
+        }
+        for (_, vao) in self.vertex_array_objects.drain() {
+            self.gl.delete_vertex_array(Some(&vao));
+        }
+        for (_, buffer) in self.buffers.drain() {
+            self.gl.delete_buffer(Some(buffer.webgl_buffer()));
+        }
+        for (_, index_buffer) in self.index_buffers.drain() {
+            self.gl.delete_buffer(Some(index_buffer.webgl_buffer()));
+        }
+        for (_, uniform_block) in self.uniform_blocks.drain() {
+            self.gl.delete_buffer(Some(uniform_block.webgl_buffer()));
+        }
+        for (_, framebuffer) in self.framebuffers.drain() {
+            self.gl
+                .delete_framebuffer(Some(framebuffer.webgl_framebuffer()));
+        }
+        for (_, renderbuffer) in self.renderbuffers.drain() {
+            self.gl
+                .delete_renderbuffer(Some(renderbuffer.webgl_renderbuffer()));
+        }
+        for (_, texture) in self.textures.drain() {
+            self.gl.delete_texture(Some(texture.webgl_texture()));
+        }
+        for (_, transform_feedback) in self.transform_feedbacks.drain() {
+            self.gl.delete_transform_feedback(Some(&transform_feedback));
+        }
+        self.attributes.clear();
+        self.uniforms.clear();
+        self.program_reflections.clear();
+        self.texture_units.get_mut().clear();
+    }
+
+    /// Links a new program for every program link supplied, using `get_shaders` to select the
+    /// vertex and fragment shader for each one.
+    ///
+    /// If any program fails to link, all of the newly linked programs are deleted.
+    fn relink_programs<'a>(
+        &self,
+        program_links: &[ProgramLink<T::ProgramId, T::VertexShaderId, T::FragmentShaderId>],
+        get_shaders: impl Fn(
+            &ProgramLink<T::ProgramId, T::VertexShaderId, T::FragmentShaderId>,
+        ) -> Result<(&'a WebGlShader, &'a WebGlShader), LinkProgramError>,
+    ) -> Result<HashMap<T::ProgramId, WebGlProgram>, ReplaceShaderError> {
+        let mut new_programs = HashMap::new();
+
+        for program_link in program_links {
+            let linked_program =
+                get_shaders(program_link).and_then(|(vertex_shader, fragment_shader)| {
+                    program_utils::link_program(
+                        &self.gl,
+                        vertex_shader,
+                        fragment_shader,
+                        program_link.transform_feedback_varyings(),
+                        &self.builder.attribute_locations,
+                    )
+                });
+
+            match linked_program {
+                Ok(program) => {
+                    new_programs.insert(program_link.program_id().clone(), program);
+                }
+                Err(error) => {
+                    for program in new_programs.values() {
+                        self.gl.delete_program(Some(program));
+                    }
+                    return Err(error.into());
+                }
+            }
+        }
+
+        Ok(new_programs)
+    }
+
+    /// Swaps in newly linked programs, re-resolving the location of every uniform that belongs to them.
+    ///
+    /// If any uniform location cannot be found (and its [MissingUniformPolicy] is `Fail`),
+    /// the new programs are deleted and the old programs are kept.
+    fn replace_programs(
+        &mut self,
+        new_programs: HashMap<T::ProgramId, WebGlProgram>,
+    ) -> Result<(), ReplaceShaderError> {
+        let mut new_uniform_locations = Vec::new();
+        let mut new_field_locations = Vec::new();
+        let mut missing_uniforms = Vec::new();
+
+        for uniform in self.uniforms.values() {
+            let uniform_id = uniform.uniform_id();
+            for program_id in uniform.program_ids() {
+                let Some(program) = new_programs.get(program_id) else {
+                    continue;
+                };
+
+                let is_found = if uniform.fields().is_empty() {
+                    self.gl
+                        .get_uniform_location(program, &uniform_id.name())
+                        .map(|uniform_location| {
+                            new_uniform_locations.push((
+                                uniform_id.clone(),
+                                program_id.clone(),
+                                uniform_location,
+                            ))
+                        })
+                        .is_some()
+                } else {
+                    program_utils::find_field_locations(
+                        &self.gl,
+                        program,
+                        &uniform_id.name(),
+                        uniform.fields(),
+                    )
+                    .map(|field_locations| {
+                        new_field_locations.push((
+                            uniform_id.clone(),
+                            program_id.clone(),
+                            field_locations,
+                        ))
+                    })
+                    .is_some()
+                };
+
+                if !is_found {
+                    let missing_uniform_policy = uniform
+                        .missing_uniform_policy()
+                        .unwrap_or(self.builder.missing_uniform_policy);
+                    if missing_uniform_policy != MissingUniformPolicy::Fail {
+                        missing_uniforms.push((uniform_id.clone(), program_id.clone()));
+                        continue;
+                    }
+
+                    for program in new_programs.values() {
+                        self.gl.delete_program(Some(program));
+                    }
+                    return Err(CreateUniformError::UniformLocationNotFound {
+                        uniform_id: uniform_id.name(),
+                        program_id: format!("{program_id:?}"),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        let mut new_uniform_block_bindings = Vec::new();
+
+        for uniform_block in self.uniform_blocks.values() {
+            let uniform_block_id = uniform_block.uniform_block_id();
+            for program_id in uniform_block.program_ids() {
+                let Some(program) = new_programs.get(program_id) else {
+                    continue;
+                };
+
+                let uniform_block_index = self
+                    .gl
+                    .get_uniform_block_index(program, &uniform_block_id.name());
+                if uniform_block_index == WebGl2RenderingContext::INVALID_INDEX {
+                    for program in new_programs.values() {
+                        self.gl.delete_program(Some(program));
+                    }
+                    return Err(CreateUniformBlockError::UniformBlockIndexNotFound {
+                        uniform_block_id: uniform_block_id.name(),
+                        program_id: format!("{program_id:?}"),
+                    }
+                    .into());
+                }
+
+                new_uniform_block_bindings.push((
+                    program.clone(),
+                    uniform_block_index,
+                    uniform_block.binding_point(),
+                ));
+            }
+        }
+
+        // block bindings belong to the program object, so relinked programs must be bound again
+        for (program, uniform_block_index, binding_point) in new_uniform_block_bindings {
+            self.gl
+                .uniform_block_binding(&program, uniform_block_index, binding_point);
+        }
+
+        for (program_id, program) in new_programs {
+            let program_reflection = ProgramReflection::from_program(&self.gl, &program);
+            for warning in self
+                .builder
+                .uniform_warnings(&program_id, &program_reflection)
+            {
+                warn!("{warning}");
+            }
+            self.program_reflections
+                .insert(program_id.clone(), program_reflection);
+
+            if let Some(old_program) = self.programs.insert(program_id, program) {
+                self.gl.delete_program(Some(&old_program));
+            }
+        }
+
+        let now = Self::now();
+        for (uniform_id, program_id, uniform_location) in new_uniform_locations {
+            let uniform = self
+                .uniforms
+                .get_mut(&uniform_id)
+                .expect("UniformId should exist in registered uniforms");
+            let program = self
+                .programs
+                .get(&program_id)
+                .expect("Relinked program should have been saved");
+
+            // a newly linked program starts with default uniform values, so they must be re-initialized
+            self.gl.use_program(Some(program));
+            let uniform_context =
+                UniformContext::new(self.gl.clone(), now, uniform_location.clone());
+            uniform
+                .initialize_callback()
+                .call_with_into_js_arg(&uniform_context);
+            uniform.upload_handle_value(&self.gl, &uniform_location);
+            self.gl.use_program(None);
+
+            uniform.set_uniform_location(program_id, uniform_location);
+        }
+
+        // the old location belongs to the old program, so it must not be used with the new one
+        for (uniform_id, program_id) in missing_uniforms {
+            self.uniforms
+                .get_mut(&uniform_id)
+                .expect("UniformId should exist in registered uniforms")
+                .remove_uniform_location(&program_id);
+        }
+
+        for (uniform_id, program_id, field_locations) in new_field_locations {
+            let uniform = self
+                .uniforms
+                .get_mut(&uniform_id)
+                .expect("UniformId should exist in registered uniforms");
+            let program = self
+                .programs
+                .get(&program_id)
+                .expect("Relinked program should have been saved");
+
+            self.gl.use_program(Some(program));
+            Uniform::<T::ProgramId, T::UniformId>::upload_field_values(
+                &self.gl,
+                &uniform.field_values().borrow(),
+                &field_locations,
+            );
+            self.gl.use_program(None);
+
+            uniform.set_field_locations(program_id, field_locations);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: RendererTypes> Drop for RendererData<T> {
+    fn drop(&mut self) {
+        self.dispose();
+    }
+}
+
+impl<T: RendererTypes> AsRef<HtmlCanvasElement> for RendererData<T> {
     fn as_ref(&self) -> &HtmlCanvasElement {
         self.canvas()
     }
 }
 
-impl From<RendererDataJsInner> for JsValue {
-    fn from(renderer_data: RendererDataJsInner) -> Self {
+impl From<RendererData<JsTypes>> for JsValue {
+    fn from(renderer_data: RendererData<JsTypes>) -> Self {
         let js_renderer: RendererDataJs = renderer_data.into();
         js_renderer.into()
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct RendererDataBuilder<
-    VertexShaderId: Id = IdDefault,
-    FragmentShaderId: Id = IdDefault,
-    ProgramId: Id = IdDefault,
-    UniformId: Id + IdName = IdDefault,
-    BufferId: Id = IdDefault,
-    AttributeId: Id + IdName = IdDefault,
-    TextureId: Id = IdDefault,
-    FramebufferId: Id = IdDefault,
-    TransformFeedbackId: Id = IdDefault,
-    VertexArrayObjectId: Id = IdDefault,
-    UserCtx: Clone + 'static = (),
-> {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RendererDataBuilder<T: RendererTypes> {
     canvas: Option<HtmlCanvasElement>,
     gl: Option<WebGl2RenderingContext>,
-    vertex_shader_sources: HashMap<VertexShaderId, String>,
-    fragment_shader_sources: HashMap<FragmentShaderId, String>,
-    vertex_shaders: HashMap<VertexShaderId, WebGlShader>,
-    fragment_shaders: HashMap<FragmentShaderId, WebGlShader>,
-    program_links: HashSet<ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>>,
-    programs: HashMap<ProgramId, WebGlProgram>,
-    uniform_links: HashSet<UniformLink<ProgramId, UniformId>>,
-    uniforms: HashMap<UniformId, Uniform<ProgramId, UniformId>>,
-    buffer_links: HashSet<BufferLink<BufferId>>,
-    buffers: HashMap<BufferId, Buffer<BufferId>>,
-    attribute_links: HashSet<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>,
-    attribute_locations: HashMap<AttributeId, u32>,
-    attributes: HashMap<AttributeId, Attribute<VertexArrayObjectId, BufferId, AttributeId>>,
-    texture_links: HashSet<TextureLink<TextureId>>,
-    textures: HashMap<TextureId, Texture<TextureId>>,
-    framebuffer_links: HashSet<FramebufferLink<FramebufferId, TextureId>>,
-    framebuffers: HashMap<FramebufferId, Framebuffer<FramebufferId>>,
-    render_callback: Option<
-        RenderCallback<
-            VertexShaderId,
-            FragmentShaderId,
-            ProgramId,
-            UniformId,
-            BufferId,
-            AttributeId,
-            TextureId,
-            FramebufferId,
-            TransformFeedbackId,
-            VertexArrayObjectId,
-            UserCtx,
-        >,
-    >,
-    user_ctx: Option<UserCtx>,
-    vertex_array_object_links: HashSet<VertexArrayObjectId>,
-    vertex_array_objects: HashMap<VertexArrayObjectId, WebGlVertexArrayObject>,
-    transform_feedback_links: HashSet<TransformFeedbackLink<TransformFeedbackId>>,
-    transform_feedbacks: HashMap<TransformFeedbackId, WebGlTransformFeedback>,
+    vertex_shader_sources: HashMap<T::VertexShaderId, String>,
+    fragment_shader_sources: HashMap<T::FragmentShaderId, String>,
+    vertex_shader_urls: HashMap<T::VertexShaderId, String>,
+    fragment_shader_urls: HashMap<T::FragmentShaderId, String>,
+    texture_urls: HashMap<T::TextureId, String>,
+    texture_images: HashMap<T::TextureId, HtmlImageElement>,
+    load_progress_callback: Option<LoadProgressCallback>,
+    vertex_shaders: HashMap<T::VertexShaderId, WebGlShader>,
+    fragment_shaders: HashMap<T::FragmentShaderId, WebGlShader>,
+    program_links: HashSet<ProgramLink<T::ProgramId, T::VertexShaderId, T::FragmentShaderId>>,
+    programs: HashMap<T::ProgramId, WebGlProgram>,
+    program_reflections: HashMap<T::ProgramId, ProgramReflection>,
+    uniform_links: HashSet<UniformLink<T::ProgramId, T::UniformId>>,
+    uniforms: HashMap<T::UniformId, Uniform<T::ProgramId, T::UniformId>>,
+    uniform_block_links: HashSet<UniformBlockLink<T::ProgramId, T::UniformBlockId>>,
+    uniform_blocks: HashMap<T::UniformBlockId, UniformBlock<T::ProgramId, T::UniformBlockId>>,
+    buffer_links: HashSet<BufferLink<T::BufferId>>,
+    index_buffer_links: HashSet<IndexBufferLink<T::VertexArrayObjectId, T::BufferId>>,
+    buffers: HashMap<T::BufferId, Buffer<T::BufferId>>,
+    index_buffers: HashMap<T::BufferId, IndexBuffer<T::VertexArrayObjectId, T::BufferId>>,
+    attribute_links: HashSet<AttributeLink<T::VertexArrayObjectId, T::BufferId, T::AttributeId>>,
+    attribute_locations: HashMap<T::AttributeId, u32>,
+    attributes:
+        HashMap<T::AttributeId, Attribute<T::VertexArrayObjectId, T::BufferId, T::AttributeId>>,
+    texture_links: HashSet<TextureLink<T::TextureId>>,
+    textures: HashMap<T::TextureId, Texture<T::TextureId>>,
+    framebuffer_links: HashSet<FramebufferLink<T::FramebufferId, T::TextureId, T::RenderbufferId>>,
+    framebuffers: HashMap<T::FramebufferId, Framebuffer<T::FramebufferId>>,
+    renderbuffer_links: HashSet<RenderbufferLink<T::RenderbufferId>>,
+    renderbuffers: HashMap<T::RenderbufferId, Renderbuffer<T::RenderbufferId>>,
+    render_callback: Option<RenderCallback<T>>,
+    user_ctx: Option<T::UserCtx>,
+    vertex_array_object_links: HashSet<T::VertexArrayObjectId>,
+    vertex_array_objects: HashMap<T::VertexArrayObjectId, WebGlVertexArrayObject>,
+    transform_feedback_links: HashSet<TransformFeedbackLink<T::TransformFeedbackId>>,
+    transform_feedbacks: HashMap<T::TransformFeedbackId, WebGlTransformFeedback>,
     get_context_callback: GetContextCallback,
+    duplicate_id_policy: DuplicateIdPolicy,
+    missing_uniform_policy: MissingUniformPolicy,
+    duplicate_id_error: Option<RendererBuilderError>,
+    lose_context_on_dispose: bool,
 }
 
 /// Public API
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone + 'static,
-    >
-    RendererDataBuilder<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes> RendererDataBuilder<T> {
     /// This is the only internal storage available publicly from the builder,
     /// because it is necessary to use it during the build process for framebuffers.
-    pub fn texture(&self, texture_id: &TextureId) -> Option<&Texture<TextureId>> {
+    pub fn texture(&self, texture_id: &T::TextureId) -> Option<&Texture<T::TextureId>> {
         self.textures.get(texture_id)
     }
 
@@ -476,14 +1042,43 @@ impl<
         self
     }
 
+    /// Sets what happens when a shader source or link is added with an id that is already in use.
+    ///
+    /// By default, duplicates are rejected with a [RendererBuilderError::DuplicateId] at build time.
+    pub fn set_duplicate_id_policy(&mut self, duplicate_id_policy: DuplicateIdPolicy) -> &mut Self {
+        self.duplicate_id_policy = duplicate_id_policy;
+
+        self
+    }
+
+    /// Sets what happens when a uniform cannot be found in one of its programs, which is usually because
+    /// the GLSL compiler optimized it away. This can be overridden for a single uniform with
+    /// [UniformLink::set_missing_uniform_policy].
+    ///
+    /// By default, the build fails with a [CreateUniformError::UniformLocationNotFound]. Otherwise, the uniform
+    /// is still registered, but it has no location in that program, so updating it does nothing there.
+    pub fn set_missing_uniform_policy(
+        &mut self,
+        missing_uniform_policy: MissingUniformPolicy,
+    ) -> &mut Self {
+        self.missing_uniform_policy = missing_uniform_policy;
+
+        self
+    }
+
     /// Saves a fragment shader source and its corresponding id
     pub fn add_fragment_shader_src(
         &mut self,
-        id: FragmentShaderId,
+        id: T::FragmentShaderId,
         fragment_shader_src: impl Into<String>,
     ) -> &mut Self {
-        self.fragment_shader_sources
-            .insert(id, fragment_shader_src.into());
+        let is_duplicate = self.fragment_shader_sources.contains_key(&id)
+            || self.fragment_shader_urls.contains_key(&id);
+        if self.accept_id(IdKind::FragmentShader, &id, is_duplicate) {
+            self.fragment_shader_urls.remove(&id);
+            self.fragment_shader_sources
+                .insert(id, fragment_shader_src.into());
+        }
 
         self
     }
@@ -491,12 +1086,79 @@ impl<
     /// Saves a vertex shader source and its corresponding id
     pub fn add_vertex_shader_src(
         &mut self,
-        id: VertexShaderId,
+        id: T::VertexShaderId,
         vertex_shader_src: impl Into<String>,
     ) -> &mut Self {
-        self.vertex_shader_sources
-            .insert(id, vertex_shader_src.into());
+        let is_duplicate = self.vertex_shader_sources.contains_key(&id)
+            || self.vertex_shader_urls.contains_key(&id);
+        if self.accept_id(IdKind::VertexShader, &id, is_duplicate) {
+            self.vertex_shader_urls.remove(&id);
+            self.vertex_shader_sources
+                .insert(id, vertex_shader_src.into());
+        }
+
+        self
+    }
+
+    /// Saves the URL of a fragment shader source, which is fetched by [RendererDataBuilder::load_assets]
+    pub fn add_fragment_shader_url(
+        &mut self,
+        id: T::FragmentShaderId,
+        fragment_shader_url: impl Into<String>,
+    ) -> &mut Self {
+        let is_duplicate = self.fragment_shader_sources.contains_key(&id)
+            || self.fragment_shader_urls.contains_key(&id);
+        if self.accept_id(IdKind::FragmentShader, &id, is_duplicate) {
+            self.fragment_shader_sources.remove(&id);
+            self.fragment_shader_urls
+                .insert(id, fragment_shader_url.into());
+        }
+
+        self
+    }
+
+    /// Saves the URL of a vertex shader source, which is fetched by [RendererDataBuilder::load_assets]
+    pub fn add_vertex_shader_url(
+        &mut self,
+        id: T::VertexShaderId,
+        vertex_shader_url: impl Into<String>,
+    ) -> &mut Self {
+        let is_duplicate = self.vertex_shader_sources.contains_key(&id)
+            || self.vertex_shader_urls.contains_key(&id);
+        if self.accept_id(IdKind::VertexShader, &id, is_duplicate) {
+            self.vertex_shader_sources.remove(&id);
+            self.vertex_shader_urls.insert(id, vertex_shader_url.into());
+        }
+
+        self
+    }
+
+    /// Saves the URL of an image, which is loaded by [RendererDataBuilder::load_assets].
+    ///
+    /// Once loaded, the image is available to the texture's `create_texture` callback
+    /// through [crate::TextureCreateContext::image]. A URL added for a texture that has no
+    /// [TextureLink] is reported by [RendererDataBuilder::validate].
+    pub fn add_texture_url(
+        &mut self,
+        id: T::TextureId,
+        texture_url: impl Into<String>,
+    ) -> &mut Self {
+        let is_duplicate =
+            self.texture_urls.contains_key(&id) || self.texture_images.contains_key(&id);
+        if self.accept_id(IdKind::Texture, &id, is_duplicate) {
+            self.texture_images.remove(&id);
+            self.texture_urls.insert(id, texture_url.into());
+        }
+
+        self
+    }
 
+    /// Sets a callback that is called each time an asset added by URL finishes loading
+    pub fn set_load_progress_callback(
+        &mut self,
+        load_progress_callback: impl Into<LoadProgressCallback>,
+    ) -> &mut Self {
+        self.load_progress_callback = Some(load_progress_callback.into());
         self
     }
 
@@ -506,20 +1168,32 @@ impl<
     /// together by associating the vertex shader id and the fragment shader id with their corresponding compiled shaders.
     pub fn add_program_link(
         &mut self,
-        program_link: impl Into<ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>>,
+        program_link: impl Into<ProgramLink<T::ProgramId, T::VertexShaderId, T::FragmentShaderId>>,
     ) -> &mut Self {
         let program_link = program_link.into();
-        self.program_links.insert(program_link);
+        let program_id = program_link.program_id().clone();
+        let is_duplicate = self
+            .program_links
+            .iter()
+            .any(|existing_link| existing_link.program_id() == &program_id);
+        if self.accept_id(IdKind::Program, &program_id, is_duplicate) {
+            self.program_links
+                .retain(|existing_link| existing_link.program_id() != &program_id);
+            self.program_links.insert(program_link);
+        }
 
         self
     }
 
     pub fn add_program_links(
         &mut self,
-        program_links: impl Into<Bridge<ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>>>,
+        program_links: impl Into<
+            Bridge<ProgramLink<T::ProgramId, T::VertexShaderId, T::FragmentShaderId>>,
+        >,
     ) -> &mut Self {
-        let program_link_bridge: Bridge<ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>> =
-            program_links.into();
+        let program_link_bridge: Bridge<
+            ProgramLink<T::ProgramId, T::VertexShaderId, T::FragmentShaderId>,
+        > = program_links.into();
         let program_links: Vec<_> = program_link_bridge.into();
 
         for program_link in program_links {
@@ -532,21 +1206,7 @@ impl<
     /// Save a callback that will be called each time it is time to render a new frame
     pub fn set_render_callback(
         &mut self,
-        render_callback: impl Into<
-            RenderCallback<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        >,
+        render_callback: impl Into<RenderCallback<T>>,
     ) -> &mut Self {
         self.render_callback = Some(render_callback.into());
 
@@ -557,7 +1217,7 @@ impl<
     ///
     /// This can include stateful data and anything else that might be necessary to access
     /// while performing a render.
-    pub fn set_user_ctx(&mut self, ctx: impl Into<UserCtx>) -> &mut Self {
+    pub fn set_user_ctx(&mut self, ctx: impl Into<T::UserCtx>) -> &mut Self {
         self.user_ctx = Some(ctx.into());
 
         self
@@ -570,16 +1230,26 @@ impl<
     /// saved with their associated update functions.
     pub fn add_uniform_link(
         &mut self,
-        uniform_link: impl Into<UniformLink<ProgramId, UniformId>>,
+        uniform_link: impl Into<UniformLink<T::ProgramId, T::UniformId>>,
     ) -> &mut Self {
-        self.uniform_links.insert(uniform_link.into());
+        let uniform_link = uniform_link.into();
+        let uniform_id = uniform_link.uniform_id().clone();
+        let is_duplicate = self
+            .uniform_links
+            .iter()
+            .any(|existing_link| existing_link.uniform_id() == &uniform_id);
+        if self.accept_id(IdKind::Uniform, &uniform_id, is_duplicate) {
+            self.uniform_links
+                .retain(|existing_link| existing_link.uniform_id() != &uniform_id);
+            self.uniform_links.insert(uniform_link);
+        }
 
         self
     }
 
     pub fn add_uniform_links(
         &mut self,
-        uniform_links: impl Into<Bridge<UniformLink<ProgramId, UniformId>>>,
+        uniform_links: impl Into<Bridge<UniformLink<T::ProgramId, T::UniformId>>>,
     ) -> &mut Self {
         let uniform_link_bridge: Bridge<_> = uniform_links.into();
         let uniform_links: Vec<_> = uniform_link_bridge.into();
@@ -591,48 +1261,159 @@ impl<
         self
     }
 
-    /// Saves a link that will be used to build a WebGL buffer at build time.
-    pub fn add_buffer_link(&mut self, buffer_link: impl Into<BufferLink<BufferId>>) -> &mut Self {
-        self.buffer_links.insert(buffer_link.into());
+    /// Saves a link that will be used to build a uniform block at build time.
+    ///
+    /// Once all programs are linked, a uniform buffer is created for the block and bound to its binding point,
+    /// and the block is bound to that same binding point within each of its associated programs.
+    pub fn add_uniform_block_link(
+        &mut self,
+        uniform_block_link: impl Into<UniformBlockLink<T::ProgramId, T::UniformBlockId>>,
+    ) -> &mut Self {
+        let uniform_block_link = uniform_block_link.into();
+        let uniform_block_id = uniform_block_link.uniform_block_id().clone();
+        let is_duplicate = self
+            .uniform_block_links
+            .iter()
+            .any(|existing_link| existing_link.uniform_block_id() == &uniform_block_id);
+        if self.accept_id(IdKind::UniformBlock, &uniform_block_id, is_duplicate) {
+            self.uniform_block_links
+                .retain(|existing_link| existing_link.uniform_block_id() != &uniform_block_id);
+            self.uniform_block_links.insert(uniform_block_link);
+        }
 
         self
     }
 
-    pub fn add_buffer_links(
+    pub fn add_uniform_block_links(
         &mut self,
-        buffer_links: impl Into<Bridge<BufferLink<BufferId>>>,
+        uniform_block_links: impl Into<Bridge<UniformBlockLink<T::ProgramId, T::UniformBlockId>>>,
     ) -> &mut Self {
-        let buffer_link_bridge: Bridge<_> = buffer_links.into();
-        let buffer_links: Vec<_> = buffer_link_bridge.into();
+        let uniform_block_link_bridge: Bridge<_> = uniform_block_links.into();
+        let uniform_block_links: Vec<_> = uniform_block_link_bridge.into();
 
-        for buffer_link in buffer_links {
-            self.add_buffer_link(buffer_link);
+        for uniform_block_link in uniform_block_links {
+            self.add_uniform_block_link(uniform_block_link);
         }
 
         self
     }
 
-    /// Saves a link that will be used to build a a WebGL attribute at build time.
-    pub fn add_attribute_link(
+    /// Saves a link that will be used to build a WebGL buffer at build time.
+    pub fn add_buffer_link(
         &mut self,
-        attribute_link: impl Into<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>,
+        buffer_link: impl Into<BufferLink<T::BufferId>>,
     ) -> &mut Self {
-        let attribute_link = attribute_link.into();
-        let attribute_id = attribute_link.attribute_id().to_owned();
-        let new_attribute_location = self.attribute_links.len() as u32;
-        self.attribute_links.insert(attribute_link);
-        self.attribute_locations
-            .insert(attribute_id, new_attribute_location);
+        let buffer_link = buffer_link.into();
+        let buffer_id = buffer_link.buffer_id().clone();
+        let is_duplicate = self
+            .buffer_links
+            .iter()
+            .any(|existing_link| existing_link.buffer_id() == &buffer_id)
+            || self
+                .index_buffer_links
+                .iter()
+                .any(|existing_link| existing_link.buffer_id() == &buffer_id);
+        if self.accept_id(IdKind::Buffer, &buffer_id, is_duplicate) {
+            self.buffer_links
+                .retain(|existing_link| existing_link.buffer_id() != &buffer_id);
+            self.index_buffer_links
+                .retain(|existing_link| existing_link.buffer_id() != &buffer_id);
+            self.buffer_links.insert(buffer_link);
+        }
 
         self
     }
 
-    pub fn add_attribute_links(
+    pub fn add_buffer_links(
         &mut self,
-        attribute_links: impl Into<Bridge<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>>,
+        buffer_links: impl Into<Bridge<BufferLink<T::BufferId>>>,
     ) -> &mut Self {
-        let attribute_link_bridge: Bridge<_> = attribute_links.into();
-        let attribute_links: Vec<_> = attribute_link_bridge.into();
+        let buffer_link_bridge: Bridge<_> = buffer_links.into();
+        let buffer_links: Vec<_> = buffer_link_bridge.into();
+
+        for buffer_link in buffer_links {
+            self.add_buffer_link(buffer_link);
+        }
+
+        self
+    }
+
+    /// Saves a link that will be used to create an index buffer and attach it to its VAOs at build time.
+    ///
+    /// Index buffers share their ids with the buffers created from [BufferLink]s.
+    pub fn add_index_buffer_link(
+        &mut self,
+        index_buffer_link: impl Into<IndexBufferLink<T::VertexArrayObjectId, T::BufferId>>,
+    ) -> &mut Self {
+        let index_buffer_link = index_buffer_link.into();
+        let buffer_id = index_buffer_link.buffer_id().clone();
+        let is_duplicate = self
+            .buffer_links
+            .iter()
+            .any(|existing_link| existing_link.buffer_id() == &buffer_id)
+            || self
+                .index_buffer_links
+                .iter()
+                .any(|existing_link| existing_link.buffer_id() == &buffer_id);
+        if self.accept_id(IdKind::Buffer, &buffer_id, is_duplicate) {
+            self.buffer_links
+                .retain(|existing_link| existing_link.buffer_id() != &buffer_id);
+            self.index_buffer_links
+                .retain(|existing_link| existing_link.buffer_id() != &buffer_id);
+            self.index_buffer_links.insert(index_buffer_link);
+        }
+
+        self
+    }
+
+    pub fn add_index_buffer_links(
+        &mut self,
+        index_buffer_links: impl Into<Bridge<IndexBufferLink<T::VertexArrayObjectId, T::BufferId>>>,
+    ) -> &mut Self {
+        let index_buffer_link_bridge: Bridge<_> = index_buffer_links.into();
+        let index_buffer_links: Vec<_> = index_buffer_link_bridge.into();
+
+        for index_buffer_link in index_buffer_links {
+            self.add_index_buffer_link(index_buffer_link);
+        }
+
+        self
+    }
+
+    /// Saves a link that will be used to build a a WebGL attribute at build time.
+    pub fn add_attribute_link(
+        &mut self,
+        attribute_link: impl Into<AttributeLink<T::VertexArrayObjectId, T::BufferId, T::AttributeId>>,
+    ) -> &mut Self {
+        let attribute_link = attribute_link.into();
+        let attribute_id = attribute_link.attribute_id().to_owned();
+        let is_duplicate = self.attribute_locations.contains_key(&attribute_id);
+        if !self.accept_id(IdKind::Attribute, &attribute_id, is_duplicate) {
+            return self;
+        }
+
+        if is_duplicate {
+            // a replaced attribute keeps the location it was originally assigned
+            self.attribute_links
+                .retain(|existing_link| existing_link.attribute_id() != &attribute_id);
+        } else {
+            let new_attribute_location = self.attribute_links.len() as u32;
+            self.attribute_locations
+                .insert(attribute_id, new_attribute_location);
+        }
+        self.attribute_links.insert(attribute_link);
+
+        self
+    }
+
+    pub fn add_attribute_links(
+        &mut self,
+        attribute_links: impl Into<
+            Bridge<AttributeLink<T::VertexArrayObjectId, T::BufferId, T::AttributeId>>,
+        >,
+    ) -> &mut Self {
+        let attribute_link_bridge: Bridge<_> = attribute_links.into();
+        let attribute_links: Vec<_> = attribute_link_bridge.into();
 
         for attribute_link in attribute_links {
             self.add_attribute_link(attribute_link);
@@ -644,16 +1425,26 @@ impl<
     /// Saves a link that will be used to build a buffer/attribute pair at build time.
     pub fn add_texture_link(
         &mut self,
-        texture_link: impl Into<TextureLink<TextureId>>,
+        texture_link: impl Into<TextureLink<T::TextureId>>,
     ) -> &mut Self {
-        self.texture_links.insert(texture_link.into());
+        let texture_link = texture_link.into();
+        let texture_id = texture_link.texture_id().clone();
+        let is_duplicate = self
+            .texture_links
+            .iter()
+            .any(|existing_link| existing_link.texture_id() == &texture_id);
+        if self.accept_id(IdKind::Texture, &texture_id, is_duplicate) {
+            self.texture_links
+                .retain(|existing_link| existing_link.texture_id() != &texture_id);
+            self.texture_links.insert(texture_link);
+        }
 
         self
     }
 
     pub fn add_texture_links(
         &mut self,
-        texture_links: impl Into<Bridge<TextureLink<TextureId>>>,
+        texture_links: impl Into<Bridge<TextureLink<T::TextureId>>>,
     ) -> &mut Self {
         let texture_link_bridge: Bridge<_> = texture_links.into();
         let texture_links: Vec<_> = texture_link_bridge.into();
@@ -668,16 +1459,28 @@ impl<
     /// Saves a link that will be used to build a framebuffer at build time
     pub fn add_framebuffer_link(
         &mut self,
-        framebuffer_link: impl Into<FramebufferLink<FramebufferId, TextureId>>,
+        framebuffer_link: impl Into<FramebufferLink<T::FramebufferId, T::TextureId, T::RenderbufferId>>,
     ) -> &mut Self {
-        self.framebuffer_links.insert(framebuffer_link.into());
+        let framebuffer_link = framebuffer_link.into();
+        let framebuffer_id = framebuffer_link.framebuffer_id().clone();
+        let is_duplicate = self
+            .framebuffer_links
+            .iter()
+            .any(|existing_link| existing_link.framebuffer_id() == &framebuffer_id);
+        if self.accept_id(IdKind::Framebuffer, &framebuffer_id, is_duplicate) {
+            self.framebuffer_links
+                .retain(|existing_link| existing_link.framebuffer_id() != &framebuffer_id);
+            self.framebuffer_links.insert(framebuffer_link);
+        }
 
         self
     }
 
     pub fn add_framebuffer_links(
         &mut self,
-        framebuffer_links: impl Into<Bridge<FramebufferLink<FramebufferId, TextureId>>>,
+        framebuffer_links: impl Into<
+            Bridge<FramebufferLink<T::FramebufferId, T::TextureId, T::RenderbufferId>>,
+        >,
     ) -> &mut Self {
         let framebuffer_link_bridge: Bridge<_> = framebuffer_links.into();
         let framebuffer_links: Vec<_> = framebuffer_link_bridge.into();
@@ -689,20 +1492,67 @@ impl<
         self
     }
 
+    /// Saves a link that will be used to build a renderbuffer at build time
+    pub fn add_renderbuffer_link(
+        &mut self,
+        renderbuffer_link: impl Into<RenderbufferLink<T::RenderbufferId>>,
+    ) -> &mut Self {
+        let renderbuffer_link = renderbuffer_link.into();
+        let renderbuffer_id = renderbuffer_link.renderbuffer_id().clone();
+        let is_duplicate = self
+            .renderbuffer_links
+            .iter()
+            .any(|existing_link| existing_link.renderbuffer_id() == &renderbuffer_id);
+        if self.accept_id(IdKind::Renderbuffer, &renderbuffer_id, is_duplicate) {
+            self.renderbuffer_links
+                .retain(|existing_link| existing_link.renderbuffer_id() != &renderbuffer_id);
+            self.renderbuffer_links.insert(renderbuffer_link);
+        }
+
+        self
+    }
+
+    /// Saves every link that will be used to build a renderbuffer at build time
+    pub fn add_renderbuffer_links(
+        &mut self,
+        renderbuffer_links: impl Into<Bridge<RenderbufferLink<T::RenderbufferId>>>,
+    ) -> &mut Self {
+        let renderbuffer_link_bridge: Bridge<_> = renderbuffer_links.into();
+        let renderbuffer_links: Vec<_> = renderbuffer_link_bridge.into();
+
+        for renderbuffer_link in renderbuffer_links {
+            self.add_renderbuffer_link(renderbuffer_link);
+        }
+
+        self
+    }
+
     /// Saves a link that will be used to build a transformFeedback at build time
     pub fn add_transform_feedback_link(
         &mut self,
-        transform_feedback_link: impl Into<TransformFeedbackLink<TransformFeedbackId>>,
+        transform_feedback_link: impl Into<TransformFeedbackLink<T::TransformFeedbackId>>,
     ) -> &mut Self {
-        self.transform_feedback_links
-            .insert(transform_feedback_link.into());
+        let transform_feedback_link = transform_feedback_link.into();
+        let transform_feedback_id = transform_feedback_link.transform_feedback_id().clone();
+        let is_duplicate = self
+            .transform_feedback_links
+            .iter()
+            .any(|existing_link| existing_link.transform_feedback_id() == &transform_feedback_id);
+        if self.accept_id(
+            IdKind::TransformFeedback,
+            &transform_feedback_id,
+            is_duplicate,
+        ) {
+            self.transform_feedback_links
+                .replace(transform_feedback_link);
+        }
 
         self
     }
 
     pub fn add_transform_feedback_links(
         &mut self,
-        transform_feedback_links: impl Into<Bridge<TransformFeedbackLink<TransformFeedbackId>>>,
+        transform_feedback_links: impl Into<Bridge<TransformFeedbackLink<T::TransformFeedbackId>>>,
     ) -> &mut Self {
         let transform_feedback_link_bridge: Bridge<_> = transform_feedback_links.into();
         let transform_feedback_links: Vec<_> = transform_feedback_link_bridge.into();
@@ -719,21 +1569,31 @@ impl<
     /// This VAO can then be referenced by `AttributeLink`s
     pub fn add_vao_link(
         &mut self,
-        vertex_array_object_id: impl Into<VertexArrayObjectId>,
+        vertex_array_object_id: impl Into<T::VertexArrayObjectId>,
     ) -> &mut Self {
-        self.vertex_array_object_links
-            .insert(vertex_array_object_id.into());
+        let vertex_array_object_id = vertex_array_object_id.into();
+        let is_duplicate = self
+            .vertex_array_object_links
+            .contains(&vertex_array_object_id);
+        if self.accept_id(
+            IdKind::VertexArrayObject,
+            &vertex_array_object_id,
+            is_duplicate,
+        ) {
+            self.vertex_array_object_links
+                .insert(vertex_array_object_id);
+        }
 
         self
     }
 
     pub fn add_vao_links(
         &mut self,
-        vao_links: impl Into<Bridge<VertexArrayObjectId>>,
+        vao_links: impl Into<Bridge<T::VertexArrayObjectId>>,
     ) -> &mut Self {
         let vao_link_bridge: Bridge<_> = vao_links.into();
         let vao_links: Vec<_> = vao_link_bridge.into();
-        let vao_links: Vec<VertexArrayObjectId> = vao_links.into_iter().collect();
+        let vao_links: Vec<T::VertexArrayObjectId> = vao_links.into_iter().collect();
 
         for vao_link in vao_links {
             self.add_vao_link(vao_link);
@@ -742,6 +1602,77 @@ impl<
         self
     }
 
+    /// If set to `true`, the WebGL2 context is released using the `WEBGL_lose_context` extension
+    /// when the built `RendererData` is disposed or dropped.
+    ///
+    /// Only enable this if the canvas's context is not shared with anything else.
+    pub fn set_lose_context_on_dispose(&mut self, lose_context_on_dispose: bool) -> &mut Self {
+        self.lose_context_on_dispose = lose_context_on_dispose;
+
+        self
+    }
+
+    /// Adds every shader, link and resource described by a [PipelineDescriptor] to the builder.
+    ///
+    /// Descriptor entries go through the same `add_*` methods as links added in code, so
+    /// the [DuplicateIdPolicy] applies to them as well.
+    pub fn add_pipeline_descriptor(
+        &mut self,
+        pipeline_descriptor: &PipelineDescriptor<
+            T::VertexShaderId,
+            T::FragmentShaderId,
+            T::ProgramId,
+            T::BufferId,
+            T::AttributeId,
+            T::TextureId,
+            T::FramebufferId,
+            T::TransformFeedbackId,
+            T::VertexArrayObjectId,
+            T::RenderbufferId,
+        >,
+    ) -> &mut Self {
+        for shader_descriptor in &pipeline_descriptor.vertex_shaders {
+            self.add_vertex_shader_src(
+                shader_descriptor.shader_id.clone(),
+                shader_descriptor.src.clone(),
+            );
+        }
+        for shader_descriptor in &pipeline_descriptor.fragment_shaders {
+            self.add_fragment_shader_src(
+                shader_descriptor.shader_id.clone(),
+                shader_descriptor.src.clone(),
+            );
+        }
+        for program_descriptor in &pipeline_descriptor.programs {
+            self.add_program_link(program_descriptor.program_link());
+        }
+        for vao_id in &pipeline_descriptor.vertex_array_objects {
+            self.add_vao_link(vao_id.clone());
+        }
+        for transform_feedback_id in &pipeline_descriptor.transform_feedbacks {
+            self.add_transform_feedback_link(TransformFeedbackLink::new(
+                transform_feedback_id.clone(),
+            ));
+        }
+        for buffer_descriptor in &pipeline_descriptor.buffers {
+            self.add_buffer_link(buffer_descriptor.buffer_link());
+        }
+        for attribute_descriptor in &pipeline_descriptor.attributes {
+            self.add_attribute_link(attribute_descriptor.attribute_link());
+        }
+        for texture_descriptor in &pipeline_descriptor.textures {
+            self.add_texture_link(texture_descriptor.texture_link());
+        }
+        for renderbuffer_descriptor in &pipeline_descriptor.renderbuffers {
+            self.add_renderbuffer_link(renderbuffer_descriptor.renderbuffer_link());
+        }
+        for framebuffer_descriptor in &pipeline_descriptor.framebuffers {
+            self.add_framebuffer_link(framebuffer_descriptor.framebuffer_link());
+        }
+
+        self
+    }
+
     pub fn set_get_context_callback(
         &mut self,
         get_context_callback: impl Into<GetContextCallback>,
@@ -750,116 +1681,730 @@ impl<
         self
     }
 
+    /// Checks every cross-reference between the links that have been added to the builder
+    /// without making any WebGL calls.
+    ///
+    /// Unlike the build process itself, which stops at the first error, this collects
+    /// *every* problem found (e.g. a `ProgramLink` referencing a shader that was never added,
+    /// or an `AttributeLink` referencing an unknown buffer) into a single [ValidationError].
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
+
+        for program_link in &self.program_links {
+            let program_id = program_link.program_id();
+            let vertex_shader_id = program_link.vertex_shader_id();
+            let fragment_shader_id = program_link.fragment_shader_id();
+
+            if !self.vertex_shader_sources.contains_key(vertex_shader_id)
+                && !self.vertex_shader_urls.contains_key(vertex_shader_id)
+            {
+                problems.push(ValidationProblem::VertexShaderNotFound {
+                    program_id: format!("{program_id:?}"),
+                    vertex_shader_id: format!("{vertex_shader_id:?}"),
+                });
+            }
+
+            if !self
+                .fragment_shader_sources
+                .contains_key(fragment_shader_id)
+                && !self.fragment_shader_urls.contains_key(fragment_shader_id)
+            {
+                problems.push(ValidationProblem::FragmentShaderNotFound {
+                    program_id: format!("{program_id:?}"),
+                    fragment_shader_id: format!("{fragment_shader_id:?}"),
+                });
+            }
+        }
+
+        let program_ids: HashSet<&T::ProgramId> = self
+            .program_links
+            .iter()
+            .map(|program_link| program_link.program_id())
+            .collect();
+
+        for uniform_link in &self.uniform_links {
+            let uniform_id = uniform_link.uniform_id();
+            for program_id in uniform_link.program_ids() {
+                if !program_ids.contains(program_id) {
+                    problems.push(ValidationProblem::UniformProgramNotFound {
+                        uniform_id: format!("{uniform_id:?}"),
+                        program_id: format!("{program_id:?}"),
+                    });
+                }
+            }
+        }
+
+        for uniform_block_link in &self.uniform_block_links {
+            let uniform_block_id = uniform_block_link.uniform_block_id();
+            for program_id in uniform_block_link.program_ids() {
+                if !program_ids.contains(program_id) {
+                    problems.push(ValidationProblem::UniformBlockProgramNotFound {
+                        uniform_block_id: format!("{uniform_block_id:?}"),
+                        program_id: format!("{program_id:?}"),
+                    });
+                }
+            }
+        }
+
+        let buffer_ids: HashSet<&T::BufferId> = self
+            .buffer_links
+            .iter()
+            .map(|buffer_link| buffer_link.buffer_id())
+            .collect();
+
+        for attribute_link in &self.attribute_links {
+            let attribute_id = attribute_link.attribute_id();
+            let buffer_id = attribute_link.buffer_id();
+
+            if !buffer_ids.contains(buffer_id) {
+                problems.push(ValidationProblem::AttributeBufferNotFound {
+                    attribute_id: format!("{attribute_id:?}"),
+                    buffer_id: format!("{buffer_id:?}"),
+                });
+            }
+
+            for vao_id in attribute_link.vao_ids() {
+                if !self.vertex_array_object_links.contains(vao_id) {
+                    problems.push(ValidationProblem::AttributeVAONotFound {
+                        attribute_id: format!("{attribute_id:?}"),
+                        vao_id: format!("{vao_id:?}"),
+                    });
+                }
+            }
+        }
+
+        for index_buffer_link in &self.index_buffer_links {
+            for vao_id in index_buffer_link.vao_ids() {
+                if !self.vertex_array_object_links.contains(vao_id) {
+                    let buffer_id = index_buffer_link.buffer_id();
+                    problems.push(ValidationProblem::IndexBufferVAONotFound {
+                        buffer_id: format!("{buffer_id:?}"),
+                        vao_id: format!("{vao_id:?}"),
+                    });
+                }
+            }
+        }
+
+        let mut vao_index_buffer_ids: HashMap<&T::VertexArrayObjectId, Vec<String>> =
+            HashMap::new();
+        for index_buffer_link in &self.index_buffer_links {
+            let buffer_id = index_buffer_link.buffer_id();
+            for vao_id in index_buffer_link.vao_ids() {
+                vao_index_buffer_ids
+                    .entry(vao_id)
+                    .or_default()
+                    .push(format!("{buffer_id:?}"));
+            }
+        }
+        for (vao_id, mut buffer_ids) in vao_index_buffer_ids {
+            if buffer_ids.len() > 1 {
+                buffer_ids.sort();
+                problems.push(ValidationProblem::MultipleIndexBuffers {
+                    vao_id: format!("{vao_id:?}"),
+                    buffer_ids,
+                });
+            }
+        }
+
+        let texture_ids: HashSet<&T::TextureId> = self
+            .texture_links
+            .iter()
+            .map(|texture_link| texture_link.texture_id())
+            .collect();
+        let renderbuffer_ids: HashSet<&T::RenderbufferId> = self
+            .renderbuffer_links
+            .iter()
+            .map(|renderbuffer_link| renderbuffer_link.renderbuffer_id())
+            .collect();
+
+        for framebuffer_link in &self.framebuffer_links {
+            for texture_id in framebuffer_link.attached_texture_ids() {
+                if !texture_ids.contains(texture_id) {
+                    let framebuffer_id = framebuffer_link.framebuffer_id();
+                    problems.push(ValidationProblem::FramebufferTextureNotFound {
+                        framebuffer_id: format!("{framebuffer_id:?}"),
+                        texture_id: format!("{texture_id:?}"),
+                    });
+                }
+            }
+            for renderbuffer_id in framebuffer_link.attached_renderbuffer_ids() {
+                if !renderbuffer_ids.contains(renderbuffer_id) {
+                    let framebuffer_id = framebuffer_link.framebuffer_id();
+                    problems.push(ValidationProblem::FramebufferRenderbufferNotFound {
+                        framebuffer_id: format!("{framebuffer_id:?}"),
+                        renderbuffer_id: format!("{renderbuffer_id:?}"),
+                    });
+                }
+            }
+        }
+
+        for texture_link in &self.texture_links {
+            if let Some(Err(error)) = texture_link.descriptor().map(TextureDescriptor::validate) {
+                problems.push(ValidationProblem::InvalidTextureDescriptor {
+                    texture_id: format!("{:?}", texture_link.texture_id()),
+                    error,
+                });
+            }
+        }
+
+        for texture_id in self.texture_urls.keys().chain(self.texture_images.keys()) {
+            if !texture_ids.contains(texture_id) {
+                problems.push(ValidationProblem::TextureImageLinkNotFound {
+                    texture_id: format!("{texture_id:?}"),
+                });
+            }
+        }
+
+        // links are partly stored in hash maps and sets, so problems are sorted to keep the report stable
+        problems.sort();
+        problems.dedup();
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError::new(problems))
+        }
+    }
+
+    /// Describes how every link that has been added to the builder relates to the others.
+    ///
+    /// See [PipelineGraph] for how to export the description as a diagram.
+    pub fn describe(&self) -> PipelineGraph {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+
+        // shaders added by URL have no source until their assets are loaded, but are still part of the pipeline
+        for vertex_shader_id in self
+            .vertex_shader_sources
+            .keys()
+            .chain(self.vertex_shader_urls.keys())
+        {
+            nodes.push(PipelineNode::new(IdKind::VertexShader, vertex_shader_id));
+        }
+        for fragment_shader_id in self
+            .fragment_shader_sources
+            .keys()
+            .chain(self.fragment_shader_urls.keys())
+        {
+            nodes.push(PipelineNode::new(
+                IdKind::FragmentShader,
+                fragment_shader_id,
+            ));
+        }
+        for program_link in &self.program_links {
+            let program_node = PipelineNode::new(IdKind::Program, program_link.program_id());
+            edges.push(PipelineEdge::new(
+                PipelineNode::new(IdKind::VertexShader, program_link.vertex_shader_id()),
+                program_node.clone(),
+                PipelineRelation::VertexShader,
+            ));
+            edges.push(PipelineEdge::new(
+                PipelineNode::new(IdKind::FragmentShader, program_link.fragment_shader_id()),
+                program_node,
+                PipelineRelation::FragmentShader,
+            ));
+        }
+        for uniform_link in &self.uniform_links {
+            let uniform_node = PipelineNode::new(IdKind::Uniform, uniform_link.uniform_id());
+            nodes.push(uniform_node.clone());
+            for program_id in uniform_link.program_ids() {
+                edges.push(PipelineEdge::new(
+                    uniform_node.clone(),
+                    PipelineNode::new(IdKind::Program, program_id),
+                    PipelineRelation::Uniform,
+                ));
+            }
+        }
+        for uniform_block_link in &self.uniform_block_links {
+            let uniform_block_node =
+                PipelineNode::new(IdKind::UniformBlock, uniform_block_link.uniform_block_id());
+            nodes.push(uniform_block_node.clone());
+            for program_id in uniform_block_link.program_ids() {
+                edges.push(PipelineEdge::new(
+                    uniform_block_node.clone(),
+                    PipelineNode::new(IdKind::Program, program_id),
+                    PipelineRelation::UniformBlock,
+                ));
+            }
+        }
+        for buffer_link in &self.buffer_links {
+            nodes.push(PipelineNode::new(IdKind::Buffer, buffer_link.buffer_id()));
+        }
+        for attribute_link in &self.attribute_links {
+            let attribute_node =
+                PipelineNode::new(IdKind::Attribute, attribute_link.attribute_id());
+            edges.push(PipelineEdge::new(
+                PipelineNode::new(IdKind::Buffer, attribute_link.buffer_id()),
+                attribute_node.clone(),
+                PipelineRelation::AttributeBuffer,
+            ));
+            for vao_id in attribute_link.vao_ids() {
+                edges.push(PipelineEdge::new(
+                    attribute_node.clone(),
+                    PipelineNode::new(IdKind::VertexArrayObject, vao_id),
+                    PipelineRelation::VertexArrayObject,
+                ));
+            }
+        }
+        for index_buffer_link in &self.index_buffer_links {
+            let buffer_node = PipelineNode::new(IdKind::Buffer, index_buffer_link.buffer_id());
+            nodes.push(buffer_node.clone());
+            for vao_id in index_buffer_link.vao_ids() {
+                edges.push(PipelineEdge::new(
+                    buffer_node.clone(),
+                    PipelineNode::new(IdKind::VertexArrayObject, vao_id),
+                    PipelineRelation::IndexBuffer,
+                ));
+            }
+        }
+        for vao_id in &self.vertex_array_object_links {
+            nodes.push(PipelineNode::new(IdKind::VertexArrayObject, vao_id));
+        }
+        for texture_link in &self.texture_links {
+            nodes.push(PipelineNode::new(
+                IdKind::Texture,
+                texture_link.texture_id(),
+            ));
+        }
+        for renderbuffer_link in &self.renderbuffer_links {
+            nodes.push(PipelineNode::new(
+                IdKind::Renderbuffer,
+                renderbuffer_link.renderbuffer_id(),
+            ));
+        }
+        for framebuffer_link in &self.framebuffer_links {
+            let framebuffer_node =
+                PipelineNode::new(IdKind::Framebuffer, framebuffer_link.framebuffer_id());
+            nodes.push(framebuffer_node.clone());
+            for texture_id in framebuffer_link.attached_texture_ids() {
+                edges.push(PipelineEdge::new(
+                    PipelineNode::new(IdKind::Texture, texture_id),
+                    framebuffer_node.clone(),
+                    PipelineRelation::FramebufferAttachment,
+                ));
+            }
+            for renderbuffer_id in framebuffer_link.attached_renderbuffer_ids() {
+                edges.push(PipelineEdge::new(
+                    PipelineNode::new(IdKind::Renderbuffer, renderbuffer_id),
+                    framebuffer_node.clone(),
+                    PipelineRelation::FramebufferAttachment,
+                ));
+            }
+        }
+        for transform_feedback_link in &self.transform_feedback_links {
+            nodes.push(PipelineNode::new(
+                IdKind::TransformFeedback,
+                transform_feedback_link.transform_feedback_id(),
+            ));
+        }
+
+        PipelineGraph::new(nodes, edges)
+    }
+
     /// Compiles all vertex shaders and fragment shaders.
     /// Links together any programs that have been specified.
     /// Outputs the final RendererData, wrapped in a top-level Renderer.
-    pub fn build_renderer(
-        self,
-    ) -> Result<
-        Renderer<
-            VertexShaderId,
-            FragmentShaderId,
-            ProgramId,
-            UniformId,
-            BufferId,
-            AttributeId,
-            TextureId,
-            FramebufferId,
-            TransformFeedbackId,
-            VertexArrayObjectId,
-            UserCtx,
-        >,
-        RendererBuilderError,
-    > {
+    pub fn build_renderer(self) -> Result<Renderer<T>, RendererBuilderError> {
         let renderer_data = self.build_renderer_data()?;
         Ok(renderer_data.into())
     }
 
-    /// Compiles all vertex shaders and fragment shaders.
-    /// Links together any programs that have been specified.
-    /// Outputs the final RendererData.
-    pub fn build_renderer_data(
-        mut self,
-    ) -> Result<
-        RendererData<
-            VertexShaderId,
-            FragmentShaderId,
-            ProgramId,
-            UniformId,
-            BufferId,
-            AttributeId,
-            TextureId,
-            FramebufferId,
-            TransformFeedbackId,
-            VertexArrayObjectId,
-            UserCtx,
-        >,
-        RendererBuilderError,
-    > {
+    /// Compiles all vertex shaders and fragment shaders.
+    /// Links together any programs that have been specified.
+    /// Outputs the final RendererData.
+    ///
+    /// Every shader compile and program link is issued before any of their statuses are checked, so that
+    /// the driver can work on them in parallel, but checking them still blocks the main thread until they
+    /// are done. Use [RendererDataBuilder::build_renderer_data_async] to wait for them without blocking
+    /// when the `KHR_parallel_shader_compile` extension is available.
+    ///
+    /// If building fails, every WebGL resource that was created before the failure is deleted.
+    pub fn build_renderer_data(mut self) -> Result<RendererData<T>, RendererBuilderError> {
+        self.prepare_build()?;
+        self.create_resources()?;
+        self.finish_build()
+    }
+
+    /// Loads every shader source and texture image that was added by URL.
+    ///
+    /// All loads are started at once and then awaited in turn. Loaded shader sources are saved
+    /// as if they had been added with [RendererDataBuilder::add_vertex_shader_src] /
+    /// [RendererDataBuilder::add_fragment_shader_src], and the load progress callback is called
+    /// after each asset finishes loading.
+    pub async fn load_assets(
+        &mut self,
+        asset_loader: &impl AssetLoader,
+    ) -> Result<&mut Self, LoadAssetError> {
+        let total = (self.vertex_shader_urls.len()
+            + self.fragment_shader_urls.len()
+            + self.texture_urls.len()) as u32;
+
+        let vertex_shader_loads: Vec<_> = self
+            .vertex_shader_urls
+            .iter()
+            .map(|(id, url)| (id.clone(), url.clone(), asset_loader.load_text(url)))
+            .collect();
+        let fragment_shader_loads: Vec<_> = self
+            .fragment_shader_urls
+            .iter()
+            .map(|(id, url)| (id.clone(), url.clone(), asset_loader.load_text(url)))
+            .collect();
+        let texture_loads: Vec<_> = self
+            .texture_urls
+            .iter()
+            .map(|(id, url)| (id.clone(), url.clone(), asset_loader.load_image(url)))
+            .collect();
+
+        let mut loaded = 0;
+
+        for (id, url, load) in vertex_shader_loads {
+            let vertex_shader_src = load.await?;
+            self.vertex_shader_urls.remove(&id);
+            self.vertex_shader_sources.insert(id, vertex_shader_src);
+            loaded += 1;
+            self.report_load_progress(loaded, total, url);
+        }
+
+        for (id, url, load) in fragment_shader_loads {
+            let fragment_shader_src = load.await?;
+            self.fragment_shader_urls.remove(&id);
+            self.fragment_shader_sources.insert(id, fragment_shader_src);
+            loaded += 1;
+            self.report_load_progress(loaded, total, url);
+        }
+
+        for (id, url, load) in texture_loads {
+            let image = load.await?;
+            self.texture_urls.remove(&id);
+            self.texture_images.insert(id, image);
+            loaded += 1;
+            self.report_load_progress(loaded, total, url);
+        }
+
+        Ok(self)
+    }
+
+    /// Loads every asset that was added by URL using the [FetchAssetLoader],
+    /// then builds the renderer as in [RendererDataBuilder::build_renderer].
+    pub async fn build_renderer_async(self) -> Result<Renderer<T>, RendererBuilderError> {
+        let renderer_data = self.build_renderer_data_async().await?;
+        Ok(renderer_data.into())
+    }
+
+    /// Loads every asset that was added by URL using the [FetchAssetLoader],
+    /// then builds the renderer data as in [RendererDataBuilder::build_renderer_data].
+    ///
+    /// Every shader compile and program link is issued up front. When the `KHR_parallel_shader_compile`
+    /// extension is available, their completion is polled across animation frames instead of blocking
+    /// the main thread. Otherwise, their statuses are checked one at a time, as in a synchronous build.
+    ///
+    /// If building fails, every WebGL resource that was created before the failure is deleted.
+    pub async fn build_renderer_data_async(
+        mut self,
+    ) -> Result<RendererData<T>, RendererBuilderError> {
+        self.load_assets(&FetchAssetLoader).await?;
+        self.prepare_build()?;
+        self.create_resources_async().await?;
+        self.finish_build()
+    }
+}
+
+/// Private API
+impl<T: RendererTypes> RendererDataBuilder<T> {
+    /// Applies the current [DuplicateIdPolicy] to an id that is being added to the builder.
+    ///
+    /// Returns `true` if the new value should be saved.
+    fn accept_id(&mut self, kind: IdKind, id: &impl Debug, is_duplicate: bool) -> bool {
+        if !is_duplicate {
+            return true;
+        }
+
+        match self.duplicate_id_policy {
+            DuplicateIdPolicy::Error => {
+                // only the first duplicate is reported
+                if self.duplicate_id_error.is_none() {
+                    self.duplicate_id_error = Some(RendererBuilderError::DuplicateId {
+                        kind,
+                        id: format!("{id:?}"),
+                    });
+                }
+                false
+            }
+            DuplicateIdPolicy::Warn => {
+                warn!("A {kind:?} was added more than once with the id {id:?}. Replacing the previous value");
+                true
+            }
+            DuplicateIdPolicy::Replace => true,
+        }
+    }
+
+    fn has_unloaded_assets(&self) -> bool {
+        !self.vertex_shader_urls.is_empty()
+            || !self.fragment_shader_urls.is_empty()
+            || !self.texture_urls.is_empty()
+    }
+
+    fn report_load_progress(&self, loaded: u32, total: u32, url: String) {
+        if let Some(load_progress_callback) = &self.load_progress_callback {
+            load_progress_callback.call_with_js_arg(LoadProgress::new(loaded, total, url));
+        }
+    }
+
+    /// Runs every check that can fail before any WebGL resources are created,
+    /// then gets the WebGL2 context from the saved canvas
+    fn prepare_build(&mut self) -> Result<&mut Self, RendererBuilderError> {
+        if let Some(duplicate_id_error) = self.duplicate_id_error.take() {
+            return Err(duplicate_id_error);
+        }
+
+        if self.has_unloaded_assets() {
+            return Err(BuildRendererError::UnloadedAssets.into());
+        }
+
+        // catch any broken references before any WebGL resources get created
+        self.validate()?;
+
+        // checked up front, so that the build cannot fail once resources have been created
+        if self.render_callback.is_none() {
+            return Err(BuildRendererError::NoRenderCallback.into());
+        }
+
+        self.save_webgl_context_from_canvas()?;
+
+        Ok(self)
+    }
+
+    /// Moves every created resource out of the builder and into a new [RendererData]
+    fn finish_build(mut self) -> Result<RendererData<T>, RendererBuilderError> {
+        let canvas = self.canvas.clone().ok_or(BuildRendererError::NoCanvas)?;
+        let gl = self.gl.clone().ok_or(BuildRendererError::NoContext)?;
+        let render_callback = self
+            .render_callback
+            .take()
+            .ok_or(BuildRendererError::NoRenderCallback)?;
+        let user_ctx = self.user_ctx.take();
+
+        // created resources are moved out, so that only links and callbacks are left in the builder
+        let renderer_data = RendererData {
+            canvas,
+            gl,
+            fragment_shaders: std::mem::take(&mut self.fragment_shaders),
+            vertex_shaders: std::mem::take(&mut self.vertex_shaders),
+            programs: std::mem::take(&mut self.programs),
+            program_reflections: std::mem::take(&mut self.program_reflections),
+            render_callback,
+            user_ctx,
+            uniforms: std::mem::take(&mut self.uniforms),
+            uniform_blocks: std::mem::take(&mut self.uniform_blocks),
+            buffers: std::mem::take(&mut self.buffers),
+            index_buffers: std::mem::take(&mut self.index_buffers),
+            textures: std::mem::take(&mut self.textures),
+            framebuffers: std::mem::take(&mut self.framebuffers),
+            renderbuffers: std::mem::take(&mut self.renderbuffers),
+            attributes: std::mem::take(&mut self.attributes),
+            vertex_array_objects: std::mem::take(&mut self.vertex_array_objects),
+            transform_feedbacks: std::mem::take(&mut self.transform_feedbacks),
+            texture_units: RefCell::default(),
+            builder: self,
+            is_disposed: false,
+        };
+
+        Ok(renderer_data)
+    }
+
+    /// Creates every WebGL resource from the links that have been saved, using the saved WebGL2 context.
+    ///
+    /// If anything fails, every resource that was created before the failure is deleted.
+    fn create_resources(&mut self) -> Result<(), RendererBuilderError> {
+        let result = self.try_create_resources();
+        if result.is_err() {
+            self.delete_resources();
+        }
+        result
+    }
+
+    fn try_create_resources(&mut self) -> Result<(), RendererBuilderError> {
+        self.start_compiling_and_linking()?;
+        self.finish_compiling_and_linking()?;
+        self.create_program_dependent_resources()?;
+
+        Ok(())
+    }
+
+    /// Creates every WebGL resource as in [RendererDataBuilder::create_resources], but waits for
+    /// shaders and programs across animation frames when `KHR_parallel_shader_compile` is available.
+    async fn create_resources_async(&mut self) -> Result<(), RendererBuilderError> {
+        let result = self.try_create_resources_async().await;
+        if result.is_err() {
+            self.delete_resources();
+        }
+        result
+    }
+
+    async fn try_create_resources_async(&mut self) -> Result<(), RendererBuilderError> {
+        let is_parallel = self.start_compiling_and_linking()?;
+        if is_parallel {
+            while !self.is_compiling_and_linking_complete() {
+                program_utils::next_animation_frame().await;
+            }
+        }
+        self.finish_compiling_and_linking()?;
+        self.create_program_dependent_resources()?;
+
+        Ok(())
+    }
+
+    /// Deletes every WebGL resource that this builder has created so far
+    /// (used to clean up after [RendererDataBuilder::create_resources] fails partway through)
+    fn delete_resources(&mut self) {
+        let Some(gl) = self.gl.clone() else {
+            return;
+        };
+
+        for (_, program) in self.programs.drain() {
+            gl.delete_program(Some(&program));
+        }
+        for (_, shader) in self.vertex_shaders.drain() {
+            gl.delete_shader(Some(&shader));
+        }
+        for (_, shader) in self.fragment_shaders.drain() {
+            gl.delete_shader(Some(&shader));
+        }
+        for (_, vao) in self.vertex_array_objects.drain() {
+            gl.delete_vertex_array(Some(&vao));
+        }
+        for (_, buffer) in self.buffers.drain() {
+            gl.delete_buffer(Some(buffer.webgl_buffer()));
+        }
+        for (_, index_buffer) in self.index_buffers.drain() {
+            gl.delete_buffer(Some(index_buffer.webgl_buffer()));
+        }
+        for (_, uniform_block) in self.uniform_blocks.drain() {
+            gl.delete_buffer(Some(uniform_block.webgl_buffer()));
+        }
+        for (_, framebuffer) in self.framebuffers.drain() {
+            gl.delete_framebuffer(Some(framebuffer.webgl_framebuffer()));
+        }
+        for (_, renderbuffer) in self.renderbuffers.drain() {
+            gl.delete_renderbuffer(Some(renderbuffer.webgl_renderbuffer()));
+        }
+        for (_, texture) in self.textures.drain() {
+            gl.delete_texture(Some(texture.webgl_texture()));
+        }
+        for (_, transform_feedback) in self.transform_feedbacks.drain() {
+            gl.delete_transform_feedback(Some(&transform_feedback));
+        }
+        self.attributes.clear();
+        self.uniforms.clear();
+        self.program_reflections.clear();
+    }
+
+    /// Issues every shader compile and program link without checking any of their statuses,
+    /// so that the driver is free to work on all of them in the background.
+    ///
+    /// The new shaders and programs are saved right away, so that they can be deleted if anything fails.
+    /// Returns whether the `KHR_parallel_shader_compile` extension is enabled, in which case
+    /// [RendererDataBuilder::is_compiling_and_linking_complete] can be polled without blocking.
+    fn start_compiling_and_linking(&mut self) -> Result<bool, RendererBuilderError> {
+        let gl = self.gl.clone().ok_or(BuildRendererError::NoContext)?;
+        let is_parallel = program_utils::enable_parallel_shader_compile(&gl);
+
+        for (id, fragment_shader_src) in self.fragment_shader_sources.iter() {
+            let fragment_shader = program_utils::start_compile_shader(
+                &gl,
+                id,
+                ShaderType::FragmentShader,
+                fragment_shader_src,
+            )?;
+            self.fragment_shaders.insert(id.clone(), fragment_shader);
+        }
+
+        for (id, vertex_shader_src) in self.vertex_shader_sources.iter() {
+            let vertex_shader = program_utils::start_compile_shader(
+                &gl,
+                id,
+                ShaderType::VertexShader,
+                vertex_shader_src,
+            )?;
+            self.vertex_shaders.insert(id.clone(), vertex_shader);
+        }
+
+        for program_link in self.program_links.iter() {
+            let vertex_shader = self
+                .vertex_shaders
+                .get(program_link.vertex_shader_id())
+                .ok_or(LinkProgramError::VertexShaderNotFound)?;
+            let fragment_shader = self
+                .fragment_shaders
+                .get(program_link.fragment_shader_id())
+                .ok_or(LinkProgramError::FragmentShaderNotFound)?;
+            let program = program_utils::start_link_program(
+                &gl,
+                vertex_shader,
+                fragment_shader,
+                program_link.transform_feedback_varyings(),
+                &self.attribute_locations,
+            )?;
+            self.programs
+                .insert(program_link.program_id().clone(), program);
+        }
+
+        Ok(is_parallel)
+    }
+
+    /// Returns whether every shader and program started by [RendererDataBuilder::start_compiling_and_linking]
+    /// is done, without blocking
+    fn is_compiling_and_linking_complete(&self) -> bool {
+        let Some(gl) = self.gl.as_ref() else {
+            return true;
+        };
+
+        self.fragment_shaders
+            .values()
+            .chain(self.vertex_shaders.values())
+            .all(|shader| program_utils::is_shader_complete(gl, shader))
+            && self
+                .programs
+                .values()
+                .all(|program| program_utils::is_program_complete(gl, program))
+    }
+
+    /// Checks the status of every shader and program started by [RendererDataBuilder::start_compiling_and_linking],
+    /// blocking until each one is done
+    fn finish_compiling_and_linking(&self) -> Result<(), RendererBuilderError> {
+        let gl = self.gl.as_ref().ok_or(BuildRendererError::NoContext)?;
+
+        for (id, fragment_shader) in self.fragment_shaders.iter() {
+            program_utils::finish_compile_shader(gl, id, fragment_shader.clone())?;
+        }
+        for (id, vertex_shader) in self.vertex_shaders.iter() {
+            program_utils::finish_compile_shader(gl, id, vertex_shader.clone())?;
+        }
+        for program in self.programs.values() {
+            program_utils::finish_link_program(gl, program.clone())?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates every WebGL resource other than shaders and programs,
+    /// some of which need the linked programs to already exist
+    fn create_program_dependent_resources(&mut self) -> Result<&mut Self, RendererBuilderError> {
         // the order here is fairly important
-        self.save_webgl_context_from_canvas()?;
-        self.compile_fragment_shaders()?;
-        self.compile_vertex_shaders()?;
+        self.reflect_programs();
         self.create_vaos()?;
-        self.link_programs()?;
         self.create_buffers()?;
         self.create_attributes()?;
         self.create_uniforms()?;
+        self.create_uniform_blocks()?;
         self.create_textures()?;
+        self.create_renderbuffers()?;
         self.create_framebuffers()?;
         self.create_transform_feedbacks()?;
 
-        let renderer_data = RendererData {
-            canvas: self.canvas.ok_or(BuildRendererError::NoCanvas)?,
-            gl: self.gl.ok_or(BuildRendererError::NoContext)?,
-            fragment_shaders: self.fragment_shaders,
-            vertex_shaders: self.vertex_shaders,
-            programs: self.programs,
-            render_callback: self
-                .render_callback
-                .ok_or(BuildRendererError::NoRenderCallback)?,
-            user_ctx: self.user_ctx,
-            uniforms: self.uniforms,
-            buffers: self.buffers,
-            textures: self.textures,
-            framebuffers: self.framebuffers,
-            attributes: self.attributes,
-            vertex_array_objects: self.vertex_array_objects,
-            transform_feedbacks: self.transform_feedbacks,
-        };
-
-        Ok(renderer_data)
+        Ok(self)
     }
-}
 
-/// Private API
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    >
-    RendererDataBuilder<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
     /// Gets the WebGL2 context from the canvas saved in state and saves the context in state
     fn save_webgl_context_from_canvas(&mut self) -> Result<&mut Self, RendererBuilderError> {
         let canvas = self
@@ -891,28 +2436,6 @@ impl<
         Ok(gl)
     }
 
-    /// Takes the list of fragment shader sources and their ids and saves compiled `WebGlShader`s to state
-    fn compile_fragment_shaders(&mut self) -> Result<&mut Self, CompileShaderError> {
-        for (id, fragment_shader_src) in self.fragment_shader_sources.iter() {
-            let fragment_shader =
-                self.compile_shader(id.clone(), ShaderType::FragmentShader, fragment_shader_src)?;
-            self.fragment_shaders.insert((*id).clone(), fragment_shader);
-        }
-
-        Ok(self)
-    }
-
-    /// Takes the list of vertex shader sources and their ids and saves compiled `WebGlShader`s to state
-    fn compile_vertex_shaders(&mut self) -> Result<&mut Self, CompileShaderError> {
-        for (id, vertex_shader_src) in self.vertex_shader_sources.iter() {
-            let vertex_shader =
-                self.compile_shader(id.clone(), ShaderType::VertexShader, vertex_shader_src)?;
-            self.vertex_shaders.insert((*id).clone(), vertex_shader);
-        }
-
-        Ok(self)
-    }
-
     fn create_transform_feedbacks(&mut self) -> Result<&mut Self, CreateTransformFeedbackError> {
         let gl = self
             .gl
@@ -931,37 +2454,111 @@ impl<
         Ok(self)
     }
 
-    /// Links together all of the vertex & fragment shaders that have been saved
-    /// according to any ProgramLinks that were provided.
-    ///
-    /// If a ProgramLink does not correspond to an actual shader, returns an Error.
-    fn link_programs(&mut self) -> Result<&mut Self, LinkProgramError> {
-        for program_link in self.program_links.iter() {
-            let program = self.link_program(program_link)?;
-            let program_id = program_link.program_id();
-            self.programs.insert(program_id.clone(), program);
+    /// Queries what every linked program actually uses, logging a warning for every mismatch
+    /// between the program's uniforms and the uniform links associated with it
+    fn reflect_programs(&mut self) -> &mut Self {
+        let Some(gl) = self.gl.as_ref() else {
+            return self;
+        };
+
+        let program_reflections: Vec<_> = self
+            .programs
+            .iter()
+            .map(|(program_id, program)| {
+                (
+                    program_id.clone(),
+                    ProgramReflection::from_program(gl, program),
+                )
+            })
+            .collect();
+
+        for (program_id, program_reflection) in program_reflections {
+            for warning in self.uniform_warnings(&program_id, &program_reflection) {
+                warn!("{warning}");
+            }
+            self.program_reflections
+                .insert(program_id, program_reflection);
         }
 
-        Ok(self)
+        self
+    }
+
+    /// The [MissingUniformPolicy] that applies to a uniform link, which falls back to the builder's policy
+    fn missing_uniform_policy(
+        &self,
+        uniform_link: &UniformLink<T::ProgramId, T::UniformId>,
+    ) -> MissingUniformPolicy {
+        uniform_link
+            .missing_uniform_policy()
+            .unwrap_or(self.missing_uniform_policy)
+    }
+
+    /// Describes every uniform link associated with `program_id` that the program doesn't declare
+    /// (usually because of a typo in its name, or because the shader never reads it),
+    /// and every uniform that the program declares without an associated uniform link.
+    fn uniform_warnings(
+        &self,
+        program_id: &T::ProgramId,
+        program_reflection: &ProgramReflection,
+    ) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let mut linked_names = HashSet::new();
+
+        for uniform_link in self
+            .uniform_links
+            .iter()
+            .filter(|uniform_link| uniform_link.program_ids().contains(program_id))
+        {
+            let uniform_name = uniform_link.uniform_id().name();
+            let names: Vec<String> = if uniform_link.fields().is_empty() {
+                vec![uniform_name.clone()]
+            } else {
+                uniform_link
+                    .fields()
+                    .iter()
+                    .map(|field| format!("{uniform_name}{field}"))
+                    .collect()
+            };
+
+            // struct and array uniforms only need one of their members to be declared
+            if self.missing_uniform_policy(uniform_link) != MissingUniformPolicy::Ignore
+                && !names
+                    .iter()
+                    .any(|name| program_reflection.uniform(name).is_some())
+            {
+                warnings.push(format!(
+                    "Uniform `{uniform_name}` is linked to program {program_id:?}, but the program does not declare it \
+                    (it may be misspelled, or optimized out because it is never read)"
+                ));
+            }
+
+            linked_names.extend(names);
+        }
+
+        for uniform in program_reflection.unlinked_uniforms(&linked_names) {
+            warnings.push(format!(
+                "Program {program_id:?} declares uniform `{}`, but no UniformLink is associated with it",
+                uniform.name()
+            ));
+        }
+
+        warnings
     }
 
     /// Find the uniform's position in a shader and constructs necessary data for each uniform.
     fn create_uniform(
         &self,
-        uniform_link: &UniformLink<ProgramId, UniformId>,
-    ) -> Result<Uniform<ProgramId, UniformId>, CreateUniformError> {
-        let uniform_id = uniform_link.uniform_id().clone();
-        let program_ids = uniform_link.program_ids().clone();
-        let use_init_callback_for_update = uniform_link.use_init_callback_for_update();
+        uniform_link: &UniformLink<T::ProgramId, T::UniformId>,
+    ) -> Result<Uniform<T::ProgramId, T::UniformId>, CreateUniformError> {
+        let uniform_id = uniform_link.uniform_id();
         let gl = self.gl.as_ref().ok_or(CreateUniformError::NoContext)?;
         let now = Self::now();
-        let _user_ctx = self.user_ctx.as_ref().map(Clone::clone);
         let initialize_callback = uniform_link.initialize_callback();
-        let should_update_callback = uniform_link.should_update_callback();
-        let update_callback = uniform_link.update_callback();
+        let handle_state = uniform_link.handle_state();
         let mut uniform_locations = HashMap::new();
+        let mut field_locations = HashMap::new();
 
-        for program_id in &program_ids {
+        for program_id in uniform_link.program_ids() {
             let program = self
                 .programs
                 .get(program_id)
@@ -969,58 +2566,52 @@ impl<
 
             gl.use_program(Some(program));
 
-            let uniform_location = gl
-                .get_uniform_location(program, &uniform_id.name())
-                .ok_or_else(|| CreateUniformError::UniformLocationNotFound {
-                    uniform_id: uniform_id.name(),
-                    program_id: format!("{program_id:?}"),
-                })?;
-            let uniform_context = UniformContext::new(gl.clone(), now, uniform_location.clone());
-            initialize_callback.call_with_into_js_arg(&uniform_context);
-            uniform_locations.insert(program_id.to_owned(), uniform_location.clone());
+            let is_found = if uniform_link.fields().is_empty() {
+                gl.get_uniform_location(program, &uniform_id.name())
+                    .map(|uniform_location| {
+                        let uniform_context =
+                            UniformContext::new(gl.clone(), now, uniform_location.clone());
+                        initialize_callback.call_with_into_js_arg(&uniform_context);
+                        if let Some(value) = handle_state.borrow().value() {
+                            value.upload(gl, &uniform_location);
+                        }
+                        uniform_locations.insert(program_id.to_owned(), uniform_location);
+                    })
+                    .is_some()
+            } else {
+                program_utils::find_field_locations(
+                    gl,
+                    program,
+                    &uniform_id.name(),
+                    uniform_link.fields(),
+                )
+                .map(|program_field_locations| {
+                    Uniform::<T::ProgramId, T::UniformId>::upload_field_values(
+                        gl,
+                        &uniform_link.field_values().borrow(),
+                        &program_field_locations,
+                    );
+                    field_locations.insert(program_id.to_owned(), program_field_locations);
+                })
+                .is_some()
+            };
 
             gl.use_program(None);
-        }
-
-        let uniform = Uniform::new(
-            program_ids,
-            uniform_id,
-            uniform_locations,
-            initialize_callback,
-            update_callback,
-            should_update_callback,
-            use_init_callback_for_update,
-        );
-
-        Ok(uniform)
-    }
 
-    /// Creates all WebGL buffers, using the passed in BufferLinks
-    fn create_buffers(&mut self) -> Result<&mut Self, CreateBufferError> {
-        let gl = self.gl.as_ref().ok_or(CreateBufferError::NoContext)?;
-        let now = Self::now();
-
-        for buffer_link in &self.buffer_links {
-            let buffer_id = buffer_link.buffer_id().clone();
-            let webgl_buffer = buffer_link.create_buffer(gl.clone(), now);
-            let buffer = Buffer::new(buffer_id.clone(), webgl_buffer);
-            self.buffers.insert(buffer_id, buffer);
+            // otherwise, the uniform is registered without a location for this program
+            // (any warning has already been logged when the program was reflected)
+            if !is_found && self.missing_uniform_policy(uniform_link) == MissingUniformPolicy::Fail
+            {
+                return Err(CreateUniformError::UniformLocationNotFound {
+                    uniform_id: uniform_id.name(),
+                    program_id: format!("{program_id:?}"),
+                });
+            }
         }
 
-        Ok(self)
-    }
-
-    fn create_vaos(&mut self) -> Result<&mut Self, CreateVAOError> {
-        let gl = self.gl.as_ref().ok_or(CreateVAOError::NoContext)?;
-
-        for vao_id in self.vertex_array_object_links.iter() {
-            let vao = gl
-                .create_vertex_array()
-                .ok_or(CreateVAOError::NoneWasReturned)?;
-            self.vertex_array_objects.insert(vao_id.to_owned(), vao);
-        }
+        let uniform = Uniform::new(uniform_link, uniform_locations, field_locations);
 
-        Ok(self)
+        Ok(uniform)
     }
 
     /// Creates a WebGL attribute for each AttributeLink that was supplied using the create_callback
@@ -1106,8 +2697,13 @@ impl<
 
         for texture_link in &self.texture_links {
             let texture_id = texture_link.texture_id().clone();
-            let webgl_texture = texture_link.create_texture(gl.clone(), now, canvas.clone());
-            let texture = Texture::new(texture_id.clone(), webgl_texture);
+            let image = self.texture_images.get(&texture_id).cloned();
+            let webgl_texture = texture_link.create_texture(gl.clone(), now, canvas.clone(), image);
+            let mut texture = Texture::new(texture_id.clone(), webgl_texture);
+            if let Some(descriptor) = texture_link.descriptor() {
+                let (width, height) = descriptor.resolve_size(&canvas);
+                texture.set_descriptor(descriptor.clone(), width, height);
+            }
 
             self.textures.insert(texture_id, texture);
         }
@@ -1115,30 +2711,199 @@ impl<
         Ok(self)
     }
 
-    /// Creates a WebGL Framebuffer for each FramebufferLink that was supplied using the callback
-    fn create_framebuffers(&mut self) -> Result<&mut Self, CreateBufferError> {
-        let gl = self.gl.as_ref().ok_or(CreateBufferError::NoContext)?;
+    /// Creates a WebGL renderbuffer for each RenderbufferLink and allocates its storage
+    fn create_renderbuffers(&mut self) -> Result<&mut Self, CreateRenderbufferError> {
+        let gl = self.gl.as_ref().ok_or(CreateRenderbufferError::NoContext)?;
+        let canvas = self
+            .canvas
+            .as_ref()
+            .ok_or(CreateRenderbufferError::NoCanvas)?;
+        let max_samples = gl
+            .get_parameter(WebGl2RenderingContext::MAX_SAMPLES)
+            .ok()
+            .and_then(|max_samples| max_samples.as_f64())
+            .unwrap_or(0.0) as i32;
+
+        for renderbuffer_link in &self.renderbuffer_links {
+            // otherwise the renderbuffer is created without any storage
+            renderbuffer_link.validate_samples(max_samples)?;
+            let renderbuffer_id = renderbuffer_link.renderbuffer_id().clone();
+            let webgl_renderbuffer = renderbuffer_link
+                .create_renderbuffer(gl, canvas)
+                .ok_or(CreateRenderbufferError::NoneWasReturned)?;
+            let renderbuffer = Renderbuffer::new(
+                renderbuffer_id.clone(),
+                webgl_renderbuffer,
+                renderbuffer_link.internal_format(),
+                renderbuffer_link.resolve_size(canvas),
+                renderbuffer_link.samples(),
+            );
+
+            self.renderbuffers.insert(renderbuffer_id, renderbuffer);
+        }
+
+        Ok(self)
+    }
+
+    /// Creates a WebGL Framebuffer for each FramebufferLink that was supplied using the callback,
+    /// then attaches the link's textures and renderbuffers and checks that the framebuffer is complete
+    /// The number of color attachments that can be both attached and drawn to.
+    ///
+    /// Falls back to 4, the minimum that every WebGL2 implementation provides, if either query fails.
+    fn max_color_attachments(gl: &WebGl2RenderingContext) -> usize {
+        const MIN_MAX_COLOR_ATTACHMENTS: usize = 4;
+
+        let get_parameter = |parameter| {
+            gl.get_parameter(parameter)
+                .ok()
+                .and_then(|value| value.as_f64())
+                .map(|value| value as usize)
+                .unwrap_or(MIN_MAX_COLOR_ATTACHMENTS)
+        };
+        get_parameter(WebGl2RenderingContext::MAX_COLOR_ATTACHMENTS)
+            .min(get_parameter(WebGl2RenderingContext::MAX_DRAW_BUFFERS))
+    }
+
+    fn create_framebuffers(&mut self) -> Result<&mut Self, CreateFramebufferError> {
+        let gl = self.gl.as_ref().ok_or(CreateFramebufferError::NoContext)?;
         let now = Self::now();
         let _user_ctx = self.user_ctx.clone();
 
         for framebuffer_link in &self.framebuffer_links {
             let framebuffer_id = framebuffer_link.framebuffer_id().clone();
+            for texture_id in framebuffer_link.attached_texture_ids() {
+                if !self.textures.contains_key(texture_id) {
+                    return Err(CreateFramebufferError::TextureNotFound {
+                        framebuffer_id: format!("{framebuffer_id:?}"),
+                        texture_id: format!("{texture_id:?}"),
+                    });
+                }
+            }
+            for renderbuffer_id in framebuffer_link.attached_renderbuffer_ids() {
+                if !self.renderbuffers.contains_key(renderbuffer_id) {
+                    return Err(CreateFramebufferError::RenderbufferNotFound {
+                        framebuffer_id: format!("{framebuffer_id:?}"),
+                        renderbuffer_id: format!("{renderbuffer_id:?}"),
+                    });
+                }
+            }
+            let color_attachments = framebuffer_link.color_attachments();
+            let max_color_attachments = Self::max_color_attachments(gl);
+            if color_attachments.len() > max_color_attachments {
+                return Err(CreateFramebufferError::TooManyColorAttachments {
+                    framebuffer_id: format!("{framebuffer_id:?}"),
+                    color_attachment_count: color_attachments.len(),
+                    max_color_attachments,
+                });
+            }
+            let depth_stencil_attachment = framebuffer_link.depth_stencil_attachment();
+
             let webgl_texture = framebuffer_link
                 .texture_id()
                 .and_then(|texture_id| self.textures.get(&texture_id))
-                .map(|texture| texture.webgl_texture())
-                .map(Clone::clone);
-
+                .map(|texture| texture.webgl_texture().clone());
             let webgl_framebuffer =
                 framebuffer_link.create_framebuffer(gl.clone(), now, webgl_texture);
-            let framebuffer = Framebuffer::new(framebuffer_id.clone(), webgl_framebuffer);
 
+            gl.bind_framebuffer(
+                WebGl2RenderingContext::FRAMEBUFFER,
+                Some(&webgl_framebuffer),
+            );
+            for (i, attachment) in color_attachments.iter().enumerate() {
+                self.attach(
+                    gl,
+                    WebGl2RenderingContext::COLOR_ATTACHMENT0 + i as u32,
+                    attachment,
+                );
+            }
+            if let Some((attachment_point, attachment)) = depth_stencil_attachment {
+                self.attach(gl, attachment_point.into(), attachment);
+            }
+            if !color_attachments.is_empty() {
+                let draw_buffers: Array = (0..color_attachments.len() as u32)
+                    .map(|i| JsValue::from(WebGl2RenderingContext::COLOR_ATTACHMENT0 + i))
+                    .collect();
+                gl.draw_buffers(&draw_buffers);
+            }
+            let status = gl.check_framebuffer_status(WebGl2RenderingContext::FRAMEBUFFER);
+            gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+
+            // framebuffers without any attachments yet are allowed, so that callbacks can attach them later
+            let is_unattached = color_attachments.is_empty()
+                && depth_stencil_attachment.is_none()
+                && status == WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT;
+            if !is_unattached {
+                if let Some(error) =
+                    CreateFramebufferError::from_status(format!("{framebuffer_id:?}"), status)
+                {
+                    gl.delete_framebuffer(Some(&webgl_framebuffer));
+                    return Err(error);
+                }
+            }
+
+            let framebuffer = Framebuffer::new(framebuffer_id.clone(), webgl_framebuffer);
             self.framebuffers.insert(framebuffer_id, framebuffer);
         }
 
         Ok(self)
     }
 
+    /// Attaches a texture or renderbuffer that has already been created to the framebuffer that is currently bound
+    fn attach(
+        &self,
+        gl: &WebGl2RenderingContext,
+        attachment_point: u32,
+        attachment: &FramebufferAttachment<T::TextureId, T::RenderbufferId>,
+    ) {
+        match attachment {
+            FramebufferAttachment::Texture(texture_id) => {
+                if let Some(texture) = self.textures.get(texture_id) {
+                    Self::attach_texture(gl, attachment_point, texture);
+                }
+            }
+            FramebufferAttachment::Renderbuffer(renderbuffer_id) => {
+                if let Some(renderbuffer) = self.renderbuffers.get(renderbuffer_id) {
+                    gl.framebuffer_renderbuffer(
+                        WebGl2RenderingContext::FRAMEBUFFER,
+                        attachment_point,
+                        WebGl2RenderingContext::RENDERBUFFER,
+                        Some(renderbuffer.webgl_renderbuffer()),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Attaches a texture to the framebuffer that is currently bound,
+    /// using the first layer of array and 3D textures
+    fn attach_texture(
+        gl: &WebGl2RenderingContext,
+        attachment: u32,
+        texture: &Texture<T::TextureId>,
+    ) {
+        let target = texture
+            .descriptor()
+            .map(|descriptor| descriptor.target)
+            .unwrap_or_default();
+        match target {
+            TextureTarget::Texture2D => gl.framebuffer_texture_2d(
+                WebGl2RenderingContext::FRAMEBUFFER,
+                attachment,
+                WebGl2RenderingContext::TEXTURE_2D,
+                Some(texture.webgl_texture()),
+                0,
+            ),
+            TextureTarget::Texture2DArray { .. } | TextureTarget::Texture3D { .. } => gl
+                .framebuffer_texture_layer(
+                    WebGl2RenderingContext::FRAMEBUFFER,
+                    attachment,
+                    Some(texture.webgl_texture()),
+                    0,
+                    0,
+                ),
+        }
+    }
+
     /// Finds every uniform's position in its corresponding program and builds a wrapper for it
     fn create_uniforms(&mut self) -> Result<&mut Self, CreateUniformError> {
         for uniform_link in self.uniform_links.iter() {
@@ -1150,62 +2915,63 @@ impl<
         Ok(self)
     }
 
-    fn link_program(
-        &self,
-        program_link: &ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>,
-    ) -> Result<WebGlProgram, LinkProgramError> {
-        let gl = self.gl.as_ref().ok_or(LinkProgramError::NoContext)?;
-
-        let vertex_shader_id = program_link.vertex_shader_id();
-        let vertex_shader = self
-            .vertex_shaders
-            .get(vertex_shader_id)
-            .ok_or(LinkProgramError::VertexShaderNotFound)?;
-
-        let fragment_shader_id = program_link.fragment_shader_id();
-        let fragment_shader = self
-            .fragment_shaders
-            .get(fragment_shader_id)
-            .ok_or(LinkProgramError::FragmentShaderNotFound)?;
-
-        // @todo - make this not have to clone the slice
-        let transform_feedback_varyings = program_link.transform_feedback_varyings().to_vec();
+    /// Creates a uniform buffer for each UniformBlockLink and binds the block to it in every associated program
+    fn create_uniform_blocks(&mut self) -> Result<&mut Self, CreateUniformBlockError> {
+        let gl = self.gl.as_ref().ok_or(CreateUniformBlockError::NoContext)?;
 
-        let webgl_program = gl.create_program().ok_or(LinkProgramError::NoProgram)?;
+        for uniform_block_link in self.uniform_block_links.iter() {
+            let uniform_block_id = uniform_block_link.uniform_block_id();
+            let binding_point = uniform_block_link.binding_point();
 
-        // assign attribute locations
-        for (attribute_id, attribute_location) in self.attribute_locations.iter() {
-            gl.bind_attrib_location(&webgl_program, *attribute_location, &attribute_id.name());
-        }
+            for program_id in uniform_block_link.program_ids() {
+                let program = self.programs.get(program_id).ok_or_else(|| {
+                    CreateUniformBlockError::ProgramNotFound {
+                        uniform_block_id: uniform_block_id.name(),
+                        program_id: format!("{program_id:?}"),
+                    }
+                })?;
+                let uniform_block_index =
+                    gl.get_uniform_block_index(program, &uniform_block_id.name());
+                if uniform_block_index == WebGl2RenderingContext::INVALID_INDEX {
+                    return Err(CreateUniformBlockError::UniformBlockIndexNotFound {
+                        uniform_block_id: uniform_block_id.name(),
+                        program_id: format!("{program_id:?}"),
+                    });
+                }
+                gl.uniform_block_binding(program, uniform_block_index, binding_point);
+            }
 
-        gl.attach_shader(&webgl_program, vertex_shader);
-        gl.attach_shader(&webgl_program, fragment_shader);
+            let webgl_buffer =
+                gl.create_buffer()
+                    .ok_or_else(|| CreateUniformBlockError::NoBuffer {
+                        uniform_block_id: uniform_block_id.name(),
+                    })?;
+            let initial_data = uniform_block_link.initial_data();
+            gl.bind_buffer(WebGl2RenderingContext::UNIFORM_BUFFER, Some(&webgl_buffer));
+            gl.buffer_data_with_u8_array(
+                WebGl2RenderingContext::UNIFORM_BUFFER,
+                initial_data,
+                WebGl2RenderingContext::DYNAMIC_DRAW,
+            );
+            gl.bind_buffer(WebGl2RenderingContext::UNIFORM_BUFFER, None);
+            gl.bind_buffer_base(
+                WebGl2RenderingContext::UNIFORM_BUFFER,
+                binding_point,
+                Some(&webgl_buffer),
+            );
 
-        if !transform_feedback_varyings.is_empty() {
-            let varyings_js_value = JsValue::from_serde(&transform_feedback_varyings)
-                .map_err(|_| LinkProgramError::CouldNotConvertVaryingsToArray)?;
-            gl.transform_feedback_varyings(
-                &webgl_program,
-                &varyings_js_value,
-                WebGl2RenderingContext::INTERLEAVED_ATTRIBS,
-            )
+            let uniform_block = UniformBlock::new(
+                uniform_block_link.program_ids().clone(),
+                uniform_block_id.clone(),
+                binding_point,
+                webgl_buffer,
+                initial_data.len(),
+            );
+            self.uniform_blocks
+                .insert(uniform_block_id.clone(), uniform_block);
         }
 
-        gl.link_program(&webgl_program);
-
-        if gl
-            .get_program_parameter(&webgl_program, WebGl2RenderingContext::LINK_STATUS)
-            .as_bool()
-            .unwrap_or(false)
-        {
-            Ok(webgl_program)
-        } else {
-            let inner_error = match gl.get_program_info_log(&webgl_program) {
-                Some(known_error) => LinkProgramError::KnownError(known_error),
-                None => LinkProgramError::UnknownError,
-            };
-            Err(inner_error)?
-        }
+        Ok(self)
     }
 
     /// Gets current DOMHighResTimeStamp from performance.now()
@@ -1214,95 +2980,41 @@ impl<
     fn now() -> f64 {
         window().unwrap().performance().unwrap().now()
     }
-
-    /// Takes the string source of a shader and compiles to using the current WebGL2RenderingContext
-    fn compile_shader<ShaderId: Id>(
-        &self,
-        shader_id: ShaderId,
-        shader_type: ShaderType,
-        source: &str,
-    ) -> Result<WebGlShader, CompileShaderError> {
-        let gl = self.gl.as_ref().ok_or(CompileShaderError::NoContext {
-            shader_id: format!("{shader_id:#?}"),
-        })?;
-
-        let shader =
-            gl.create_shader(shader_type.into())
-                .ok_or(CompileShaderError::NoShaderReturned {
-                    shader_id: format!("{shader_id:#?}"),
-                })?;
-
-        gl.shader_source(&shader, source);
-        gl.compile_shader(&shader);
-
-        if gl
-            .get_shader_parameter(&shader, WebGl2RenderingContext::COMPILE_STATUS)
-            .as_bool()
-            .unwrap_or(false)
-        {
-            Ok(shader)
-        } else {
-            let inner_error = match gl.get_shader_info_log(&shader) {
-                Some(known_error) => CompileShaderError::KnownError {
-                    shader_id: format!("{shader_id:#?}"),
-                    error: known_error,
-                },
-                None => CompileShaderError::UnknownError {
-                    shader_id: format!("{shader_id:#?}"),
-                },
-            };
-            Err(inner_error)?
-        }
-    }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    > Default
-    for RendererDataBuilder<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes> Default for RendererDataBuilder<T> {
     fn default() -> Self {
         Self {
             canvas: Default::default(),
             gl: Default::default(),
             vertex_shader_sources: Default::default(),
             fragment_shader_sources: Default::default(),
+            vertex_shader_urls: Default::default(),
+            fragment_shader_urls: Default::default(),
+            texture_urls: Default::default(),
+            texture_images: Default::default(),
+            load_progress_callback: Default::default(),
             vertex_shaders: Default::default(),
             fragment_shaders: Default::default(),
             program_links: Default::default(),
             programs: Default::default(),
+            program_reflections: Default::default(),
             render_callback: Default::default(),
             user_ctx: Default::default(),
             uniform_links: Default::default(),
             uniforms: Default::default(),
+            uniform_block_links: Default::default(),
+            uniform_blocks: Default::default(),
             buffer_links: Default::default(),
             buffers: Default::default(),
+            index_buffer_links: Default::default(),
+            index_buffers: Default::default(),
             texture_links: Default::default(),
             textures: Default::default(),
             framebuffer_links: Default::default(),
             framebuffers: Default::default(),
+            renderbuffer_links: Default::default(),
+            renderbuffers: Default::default(),
             attribute_links: Default::default(),
             attributes: Default::default(),
             vertex_array_object_links: Default::default(),
@@ -1311,6 +3023,710 @@ impl<
             transform_feedback_links: Default::default(),
             get_context_callback: Default::default(),
             attribute_locations: Default::default(),
+            duplicate_id_policy: Default::default(),
+            missing_uniform_policy: Default::default(),
+            duplicate_id_error: Default::default(),
+            lose_context_on_dispose: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ActiveUniform, AttributeCreateContext, AttributeDescriptor, BufferCreateContext,
+        BufferData, BufferDescriptor, DepthStencilAttachment, FramebufferCreateContext,
+        FramebufferDescriptor, ProgramDescriptor, RenderbufferDescriptor, ShaderDescriptor,
+        TextureDescriptor, TextureDescriptorError, TextureFilter, TextureSize,
+    };
+
+    #[derive(Clone, Debug)]
+    struct TestTypes;
+
+    impl RendererTypes for TestTypes {
+        type VertexShaderId = &'static str;
+        type FragmentShaderId = &'static str;
+        type ProgramId = &'static str;
+        type UniformId = &'static str;
+        type UniformBlockId = &'static str;
+        type BufferId = &'static str;
+        type AttributeId = &'static str;
+        type TextureId = &'static str;
+        type FramebufferId = &'static str;
+        type RenderbufferId = &'static str;
+        type TransformFeedbackId = &'static str;
+        type VertexArrayObjectId = &'static str;
+        type UserCtx = ();
+    }
+
+    type TestBuilder = RendererDataBuilder<TestTypes>;
+
+    #[test]
+    fn warns_about_undeclared_and_unlinked_uniforms() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_uniform_link(UniformLink::from_value("program", "u_time", 0.0))
+            .add_uniform_link(UniformLink::from_value("program", "u_tme", 0.0))
+            .add_uniform_link(UniformLink::array_of::<f32>("program", "u_weights", 8))
+            .add_uniform_link(UniformLink::from_value("other_program", "u_color", 0.0));
+        let program_reflection = ProgramReflection::new(
+            vec![
+                ActiveUniform::new("u_time", WebGl2RenderingContext::FLOAT, 1, None, false),
+                // the shader only declares `float u_weights[4]`, which is tolerated
+                ActiveUniform::new(
+                    "u_weights[0]",
+                    WebGl2RenderingContext::FLOAT,
+                    4,
+                    None,
+                    false,
+                ),
+                ActiveUniform::new(
+                    "u_resolution",
+                    WebGl2RenderingContext::FLOAT_VEC2,
+                    1,
+                    None,
+                    false,
+                ),
+                // members of uniform blocks are set through the block, not a UniformLink
+                ActiveUniform::new(
+                    "Lights.color",
+                    WebGl2RenderingContext::FLOAT_VEC3,
+                    1,
+                    None,
+                    true,
+                ),
+            ],
+            Vec::new(),
+            Vec::new(),
+        );
+
+        let warnings = builder.uniform_warnings(&"program", &program_reflection);
+
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("`u_tme`"));
+        assert!(warnings[1].contains("`u_resolution`"));
+        assert!(warnings[1].contains("no UniformLink"));
+    }
+
+    #[test]
+    fn uniforms_with_an_ignore_policy_are_not_warned_about() {
+        let mut ignored_uniform_link = UniformLink::from_value("program", "u_unused", 0.0);
+        ignored_uniform_link.set_missing_uniform_policy(MissingUniformPolicy::Ignore);
+        let mut builder = TestBuilder::default();
+        builder
+            .set_missing_uniform_policy(MissingUniformPolicy::Warn)
+            .add_uniform_link(ignored_uniform_link.clone())
+            .add_uniform_link(UniformLink::from_value("program", "u_time", 0.0));
+
+        let warnings = builder.uniform_warnings(&"program", &ProgramReflection::default());
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("`u_time`"));
+        assert_eq!(
+            builder.missing_uniform_policy(&ignored_uniform_link),
+            MissingUniformPolicy::Ignore
+        );
+    }
+
+    #[test]
+    fn validate_accepts_consistent_links() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_vertex_shader_src("vertex", "")
+            .add_fragment_shader_src("fragment", "")
+            .add_program_link(ProgramLink::new("program", "vertex", "fragment"))
+            .add_uniform_link(UniformLink::new(
+                "program",
+                "u_time",
+                |_: &UniformContext| {},
+            ))
+            .add_buffer_link(BufferLink::new(
+                "buffer",
+                |_: &BufferCreateContext| unreachable!(),
+            ))
+            .add_vao_link("vao")
+            .add_attribute_link(AttributeLink::new(
+                "vao",
+                "buffer",
+                "a_position",
+                |_: &AttributeCreateContext| {},
+            ));
+
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_every_broken_reference() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_program_link(ProgramLink::new("program", "vertex", "fragment"))
+            .add_uniform_link(UniformLink::new(
+                "missing_program",
+                "u_time",
+                |_: &UniformContext| {},
+            ))
+            .add_uniform_block_link(UniformBlockLink::new(
+                "missing_program",
+                "Camera",
+                0,
+                &[0.0f32; 4],
+            ))
+            .add_attribute_link(AttributeLink::new(
+                "missing_vao",
+                "missing_buffer",
+                "a_position",
+                |_: &AttributeCreateContext| {},
+            ))
+            .add_index_buffer_link(IndexBufferLink::new(
+                "missing_vao",
+                "indices",
+                vec![0u16, 1, 2],
+            ))
+            .add_framebuffer_link(FramebufferLink::new(
+                "framebuffer",
+                |_: &FramebufferCreateContext| unreachable!(),
+                Some("missing_texture"),
+            ));
+        let mut texture_descriptor = TextureDescriptor::new("texture");
+        texture_descriptor.mag_filter = TextureFilter::LinearMipmapLinear;
+        builder.add_texture_link(TextureLink::from_descriptor(texture_descriptor));
+
+        let error = builder.validate().unwrap_err();
+
+        assert_eq!(
+            error.problems(),
+            &[
+                ValidationProblem::VertexShaderNotFound {
+                    program_id: "\"program\"".to_string(),
+                    vertex_shader_id: "\"vertex\"".to_string(),
+                },
+                ValidationProblem::FragmentShaderNotFound {
+                    program_id: "\"program\"".to_string(),
+                    fragment_shader_id: "\"fragment\"".to_string(),
+                },
+                ValidationProblem::UniformProgramNotFound {
+                    uniform_id: "\"u_time\"".to_string(),
+                    program_id: "\"missing_program\"".to_string(),
+                },
+                ValidationProblem::UniformBlockProgramNotFound {
+                    uniform_block_id: "\"Camera\"".to_string(),
+                    program_id: "\"missing_program\"".to_string(),
+                },
+                ValidationProblem::AttributeBufferNotFound {
+                    attribute_id: "\"a_position\"".to_string(),
+                    buffer_id: "\"missing_buffer\"".to_string(),
+                },
+                ValidationProblem::AttributeVAONotFound {
+                    attribute_id: "\"a_position\"".to_string(),
+                    vao_id: "\"missing_vao\"".to_string(),
+                },
+                ValidationProblem::IndexBufferVAONotFound {
+                    buffer_id: "\"indices\"".to_string(),
+                    vao_id: "\"missing_vao\"".to_string(),
+                },
+                ValidationProblem::FramebufferTextureNotFound {
+                    framebuffer_id: "\"framebuffer\"".to_string(),
+                    texture_id: "\"missing_texture\"".to_string(),
+                },
+                ValidationProblem::InvalidTextureDescriptor {
+                    texture_id: "\"texture\"".to_string(),
+                    error: TextureDescriptorError::MipmapMagFilter,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_checks_every_framebuffer_attachment() {
+        let mut framebuffer_descriptor = FramebufferDescriptor::new("g_buffer", Some("albedo"));
+        framebuffer_descriptor
+            .color_attachments
+            .push(FramebufferAttachment::Texture("normals"));
+        framebuffer_descriptor.depth_stencil_attachment = Some((
+            DepthStencilAttachment::Depth,
+            FramebufferAttachment::Texture("depth"),
+        ));
+        let framebuffer_link = framebuffer_descriptor.framebuffer_link();
+
+        assert_eq!(
+            framebuffer_link.color_attachments(),
+            &[
+                FramebufferAttachment::Texture("albedo"),
+                FramebufferAttachment::Texture("normals")
+            ]
+        );
+        assert_eq!(
+            framebuffer_link.depth_stencil_attachment(),
+            Some((
+                DepthStencilAttachment::Depth,
+                &FramebufferAttachment::Texture("depth")
+            ))
+        );
+
+        let mut builder = TestBuilder::default();
+        builder
+            .add_texture_link(TextureDescriptor::new("albedo").texture_link())
+            .add_framebuffer_link(framebuffer_link);
+
+        let error = builder.validate().unwrap_err();
+        assert_eq!(
+            error.problems(),
+            &[
+                ValidationProblem::FramebufferTextureNotFound {
+                    framebuffer_id: "\"g_buffer\"".to_string(),
+                    texture_id: "\"depth\"".to_string(),
+                },
+                ValidationProblem::FramebufferTextureNotFound {
+                    framebuffer_id: "\"g_buffer\"".to_string(),
+                    texture_id: "\"normals\"".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn framebuffers_can_attach_renderbuffers() {
+        let mut framebuffer_link = FramebufferLink::from_attachments("msaa", "albedo");
+        framebuffer_link
+            .add_color_renderbuffer("color")
+            .set_depth_stencil_renderbuffer(DepthStencilAttachment::Depth, "depth");
+        let mut renderbuffer_link =
+            RenderbufferLink::new("color", WebGl2RenderingContext::RGBA8, TextureSize::Canvas);
+        renderbuffer_link.set_samples(4);
+
+        assert_eq!(
+            framebuffer_link.color_attachments(),
+            &[
+                FramebufferAttachment::Texture("albedo"),
+                FramebufferAttachment::Renderbuffer("color")
+            ]
+        );
+        assert_eq!(framebuffer_link.texture_id(), Some("albedo"));
+        assert_eq!(
+            framebuffer_link.attached_texture_ids().collect::<Vec<_>>(),
+            [&"albedo"]
+        );
+        assert_eq!(
+            framebuffer_link
+                .attached_renderbuffer_ids()
+                .collect::<Vec<_>>(),
+            [&"color", &"depth"]
+        );
+
+        let mut builder = TestBuilder::default();
+        builder
+            .add_texture_link(TextureDescriptor::new("albedo").texture_link())
+            .add_renderbuffer_link(renderbuffer_link)
+            .add_framebuffer_link(framebuffer_link);
+
+        let error = builder.validate().unwrap_err();
+        assert_eq!(
+            error.problems(),
+            &[ValidationProblem::FramebufferRenderbufferNotFound {
+                framebuffer_id: "\"msaa\"".to_string(),
+                renderbuffer_id: "\"depth\"".to_string(),
+            }]
+        );
+
+        let graph = builder.describe();
+        assert!(graph
+            .nodes()
+            .contains(&PipelineNode::new(IdKind::Renderbuffer, &"color")));
+        assert!(graph.edges().contains(&PipelineEdge::new(
+            PipelineNode::new(IdKind::Renderbuffer, &"color"),
+            PipelineNode::new(IdKind::Framebuffer, &"msaa"),
+            PipelineRelation::FramebufferAttachment,
+        )));
+
+        builder.add_renderbuffer_link(
+            RenderbufferDescriptor::new("depth", WebGl2RenderingContext::DEPTH_COMPONENT24)
+                .renderbuffer_link(),
+        );
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn duplicate_ids_are_rejected_by_default() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_program_link(ProgramLink::new("program", "vertex", "fragment"))
+            .add_program_link(ProgramLink::new("program", "other_vertex", "fragment"));
+
+        assert_eq!(
+            builder.build_renderer_data().unwrap_err(),
+            RendererBuilderError::DuplicateId {
+                kind: IdKind::Program,
+                id: "\"program\"".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rejects_vaos_with_more_than_one_index_buffer() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_vao_link("vao")
+            .add_index_buffer_link(IndexBufferLink::new("vao", "triangles", vec![0u32, 1, 2]))
+            .add_index_buffer_link(IndexBufferLink::new("vao", "lines", vec![0u16, 1]));
+
+        let error = builder.validate().unwrap_err();
+        assert_eq!(
+            error.problems(),
+            &[ValidationProblem::MultipleIndexBuffers {
+                vao_id: "\"vao\"".to_string(),
+                buffer_ids: vec!["\"lines\"".to_string(), "\"triangles\"".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_rejects_texture_urls_without_a_texture_link() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_texture_url("albedo", "albedo.png")
+            .add_texture_url("normal", "normal.png")
+            .add_texture_url("roughness", "roughness.png")
+            .add_texture_url("height", "height.png")
+            .add_texture_link(TextureDescriptor::new("albedo").texture_link());
+
+        let error = builder.validate().unwrap_err();
+        assert_eq!(
+            error.problems(),
+            &[
+                ValidationProblem::TextureImageLinkNotFound {
+                    texture_id: "\"height\"".to_string(),
+                },
+                ValidationProblem::TextureImageLinkNotFound {
+                    texture_id: "\"normal\"".to_string(),
+                },
+                ValidationProblem::TextureImageLinkNotFound {
+                    texture_id: "\"roughness\"".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn index_buffers_share_ids_with_buffers() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_buffer_link(BufferLink::new(
+                "mesh",
+                |_: &BufferCreateContext| unreachable!(),
+            ))
+            .add_index_buffer_link(IndexBufferLink::new("vao", "mesh", vec![0u32, 1, 2]));
+
+        assert_eq!(
+            builder.build_renderer_data().unwrap_err(),
+            RendererBuilderError::DuplicateId {
+                kind: IdKind::Buffer,
+                id: "\"mesh\"".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn duplicate_ids_replace_previous_value_when_allowed() {
+        let mut builder = TestBuilder::default();
+        builder
+            .set_duplicate_id_policy(DuplicateIdPolicy::Replace)
+            .add_vertex_shader_src("vertex", "first")
+            .add_vertex_shader_src("vertex", "second")
+            .add_program_link(ProgramLink::new("program", "vertex", "fragment"))
+            .add_program_link(ProgramLink::new("program", "other_vertex", "fragment"))
+            .add_attribute_link(AttributeLink::new(
+                "vao",
+                "buffer",
+                "a_position",
+                |_: &AttributeCreateContext| {},
+            ))
+            .add_attribute_link(AttributeLink::new(
+                "vao",
+                "buffer",
+                "a_normal",
+                |_: &AttributeCreateContext| {},
+            ))
+            .add_attribute_link(AttributeLink::new(
+                "other_vao",
+                "buffer",
+                "a_position",
+                |_: &AttributeCreateContext| {},
+            ));
+
+        assert_eq!(
+            builder.vertex_shader_sources.get("vertex").unwrap(),
+            "second"
+        );
+        assert_eq!(builder.program_links.len(), 1);
+        assert_eq!(
+            builder
+                .program_links
+                .iter()
+                .next()
+                .unwrap()
+                .vertex_shader_id(),
+            &"other_vertex"
+        );
+        assert_eq!(builder.attribute_links.len(), 2);
+        assert_eq!(builder.attribute_locations.get("a_position"), Some(&0));
+        assert_eq!(builder.attribute_locations.get("a_normal"), Some(&1));
+        assert!(builder.duplicate_id_error.is_none());
+    }
+
+    #[test]
+    fn pipeline_descriptor_adds_links() {
+        let mut pipeline_descriptor = PipelineDescriptor::<
+            &'static str,
+            &'static str,
+            &'static str,
+            &'static str,
+            &'static str,
+            &'static str,
+            &'static str,
+            &'static str,
+            &'static str,
+            &'static str,
+        >::new();
+        pipeline_descriptor
+            .vertex_shaders
+            .push(ShaderDescriptor::new("vertex", "vertex source"));
+        pipeline_descriptor
+            .fragment_shaders
+            .push(ShaderDescriptor::new("fragment", "fragment source"));
+        pipeline_descriptor
+            .programs
+            .push(ProgramDescriptor::new("program", "vertex", "fragment"));
+        pipeline_descriptor.vertex_array_objects.push("vao");
+        pipeline_descriptor
+            .buffers
+            .push(BufferDescriptor::new("buffer", BufferData::Size(64)));
+        pipeline_descriptor
+            .attributes
+            .push(AttributeDescriptor::new(
+                vec!["vao"],
+                "buffer",
+                "a_position",
+                2,
+            ));
+        pipeline_descriptor
+            .textures
+            .push(TextureDescriptor::new("texture"));
+        pipeline_descriptor
+            .renderbuffers
+            .push(RenderbufferDescriptor::new(
+                "depth",
+                WebGl2RenderingContext::DEPTH_COMPONENT24,
+            ));
+        let mut framebuffer_descriptor = FramebufferDescriptor::new("framebuffer", Some("texture"));
+        framebuffer_descriptor.depth_stencil_attachment = Some((
+            DepthStencilAttachment::Depth,
+            FramebufferAttachment::Renderbuffer("depth"),
+        ));
+        pipeline_descriptor
+            .framebuffers
+            .push(framebuffer_descriptor);
+
+        let mut builder = TestBuilder::default();
+        builder.add_pipeline_descriptor(&pipeline_descriptor);
+
+        assert_eq!(
+            builder.vertex_shader_sources.get("vertex").unwrap(),
+            "vertex source"
+        );
+        assert_eq!(builder.program_links.len(), 1);
+        assert_eq!(builder.buffer_links.len(), 1);
+        assert_eq!(builder.attribute_locations.get("a_position"), Some(&0));
+        assert_eq!(builder.texture_links.len(), 1);
+        assert_eq!(builder.renderbuffer_links.len(), 1);
+        assert_eq!(builder.framebuffer_links.len(), 1);
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn describe_includes_every_link() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_vertex_shader_src("vertex", "")
+            .add_fragment_shader_src("fragment", "")
+            .add_program_link(ProgramLink::new("program", "vertex", "fragment"))
+            .add_uniform_link(UniformLink::new(
+                "program",
+                "u_time",
+                |_: &UniformContext| {},
+            ))
+            .add_buffer_link(BufferLink::new("buffer", |ctx: &BufferCreateContext| {
+                ctx.gl().create_buffer().unwrap()
+            }))
+            .add_attribute_link(AttributeLink::new(
+                "vao",
+                "buffer",
+                "a_position",
+                |_: &AttributeCreateContext| {},
+            ))
+            .add_vao_link("vao")
+            .add_framebuffer_link(FramebufferLink::new(
+                "framebuffer",
+                |ctx: &FramebufferCreateContext| ctx.gl().create_framebuffer().unwrap(),
+                Some("texture"),
+            ));
+
+        let graph = builder.describe();
+        let relations: Vec<_> = graph.edges().iter().map(|edge| edge.relation).collect();
+        assert_eq!(
+            relations,
+            vec![
+                PipelineRelation::VertexShader,
+                PipelineRelation::FragmentShader,
+                PipelineRelation::Uniform,
+                PipelineRelation::AttributeBuffer,
+                PipelineRelation::VertexArrayObject,
+                PipelineRelation::FramebufferAttachment,
+            ]
+        );
+        assert_eq!(graph.nodes().len(), 9);
+    }
+
+    #[test]
+    fn describe_includes_shaders_added_by_url() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_vertex_shader_url("vertex", "shader.vert")
+            .add_fragment_shader_url("fragment", "shader.frag")
+            .add_fragment_shader_url("unused_fragment", "unused.frag")
+            .add_program_link(ProgramLink::new("program", "vertex", "fragment"));
+
+        let graph = builder.describe();
+        assert_eq!(
+            graph.nodes(),
+            &[
+                PipelineNode::new(IdKind::VertexShader, &"vertex"),
+                PipelineNode::new(IdKind::FragmentShader, &"fragment"),
+                PipelineNode::new(IdKind::FragmentShader, &"unused_fragment"),
+                PipelineNode::new(IdKind::Program, &"program"),
+            ]
+        );
+    }
+
+    /// Serves text assets from memory instead of fetching them
+    struct LocalAssetLoader(HashMap<&'static str, &'static str>);
+
+    impl AssetLoader for LocalAssetLoader {
+        fn load_text(&self, url: &str) -> crate::AssetFuture<String> {
+            let result = self.0.get(url).map(|text| text.to_string()).ok_or_else(|| {
+                LoadAssetError::Status {
+                    url: url.to_string(),
+                    status: 404,
+                }
+            });
+            Box::pin(std::future::ready(result))
+        }
+
+        fn load_image(&self, url: &str) -> crate::AssetFuture<HtmlImageElement> {
+            Box::pin(std::future::ready(Err(LoadAssetError::Image {
+                url: url.to_string(),
+                message: String::from("images are not available outside the browser"),
+            })))
+        }
+    }
+
+    /// Polls a future that never needs to wait, such as one produced by [LocalAssetLoader]
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        fn noop_raw_waker() -> std::task::RawWaker {
+            fn clone(_: *const ()) -> std::task::RawWaker {
+                noop_raw_waker()
+            }
+            fn noop(_: *const ()) {}
+            static VTABLE: std::task::RawWakerVTable =
+                std::task::RawWakerVTable::new(clone, noop, noop, noop);
+            std::task::RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+
+        // SAFETY: every function in the vtable ignores the data pointer, so a null pointer is valid
+        let waker = unsafe { std::task::Waker::from_raw(noop_raw_waker()) };
+        let mut cx = std::task::Context::from_waker(&waker);
+        match future.as_mut().poll(&mut cx) {
+            std::task::Poll::Ready(output) => output,
+            std::task::Poll::Pending => panic!("future was not ready"),
         }
     }
+
+    #[test]
+    fn load_assets_saves_shader_sources() {
+        let asset_loader = LocalAssetLoader(HashMap::from([
+            ("shader.vert", "vertex source"),
+            ("shader.frag", "fragment source"),
+        ]));
+        let progress = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let progress_clone = progress.clone();
+
+        let mut builder = TestBuilder::default();
+        builder
+            .add_vertex_shader_url("vertex", "shader.vert")
+            .add_fragment_shader_url("fragment", "shader.frag")
+            .set_load_progress_callback(move |load_progress: LoadProgress| {
+                progress_clone
+                    .borrow_mut()
+                    .push((load_progress.loaded(), load_progress.total()));
+            });
+
+        block_on(builder.load_assets(&asset_loader)).unwrap();
+
+        assert_eq!(
+            builder
+                .vertex_shader_sources
+                .get("vertex")
+                .map(String::as_str),
+            Some("vertex source")
+        );
+        assert_eq!(
+            builder
+                .fragment_shader_sources
+                .get("fragment")
+                .map(String::as_str),
+            Some("fragment source")
+        );
+        assert!(!builder.has_unloaded_assets());
+        assert_eq!(*progress.borrow(), vec![(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn load_assets_reports_failed_loads() {
+        let asset_loader = LocalAssetLoader(HashMap::new());
+        let mut builder = TestBuilder::default();
+        builder.add_vertex_shader_url("vertex", "missing.vert");
+
+        let error = block_on(builder.load_assets(&asset_loader)).unwrap_err();
+
+        assert_eq!(
+            error,
+            LoadAssetError::Status {
+                url: String::from("missing.vert"),
+                status: 404
+            }
+        );
+    }
+
+    #[test]
+    fn build_requires_assets_to_be_loaded() {
+        let mut builder = TestBuilder::default();
+        builder.add_vertex_shader_url("vertex", "shader.vert");
+
+        assert_eq!(
+            builder.build_renderer_data().unwrap_err(),
+            RendererBuilderError::RendererBuildError(BuildRendererError::UnloadedAssets)
+        );
+    }
+
+    #[test]
+    fn validate_accepts_shaders_added_by_url() {
+        let mut builder = TestBuilder::default();
+        builder
+            .add_vertex_shader_url("vertex", "shader.vert")
+            .add_fragment_shader_url("fragment", "shader.frag")
+            .add_program_link(ProgramLink::new("program", "vertex", "fragment"));
+
+        assert_eq!(builder.validate(), Ok(()));
+    }
 }
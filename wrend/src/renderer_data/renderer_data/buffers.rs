@@ -0,0 +1,151 @@
+use super::{RendererData, RendererDataBuilder};
+use crate::{
+    Buffer, CreateBufferError, CreateVAOError, IndexBuffer, InstanceBuffer, RendererTypes,
+    UpdateBufferError,
+};
+use bytemuck::Pod;
+use std::collections::HashMap;
+use web_sys::WebGl2RenderingContext;
+
+/// Buffer access and updates
+impl<T: RendererTypes> RendererData<T> {
+    /// Gets a typed handle for replacing the contents of a buffer created with [crate::BufferLink::from_instances].
+    ///
+    /// See [InstanceBuffer].
+    pub fn instance_buffer<V: Pod>(&self, buffer_id: &T::BufferId) -> Option<InstanceBuffer<V>> {
+        self.buffers.get(buffer_id).map(|buffer| {
+            InstanceBuffer::new(
+                self.gl.clone(),
+                buffer.webgl_buffer().clone(),
+                buffer.byte_length_cell(),
+            )
+        })
+    }
+
+    /// Overwrites part of a buffer with `data`, starting `offset` bytes into the buffer (using `bufferSubData`).
+    ///
+    /// Returns an error rather than writing anything if `data` does not fit within the buffer--use
+    /// [RendererData::resize_buffer] to make room for it first.
+    ///
+    /// Index buffers (see [crate::IndexBufferLink]) are not supported, since their index type and count are
+    /// fixed when they are created: passing an index buffer's id returns [UpdateBufferError::BufferNotFound].
+    pub fn update_buffer<V: Pod>(
+        &self,
+        buffer_id: &T::BufferId,
+        offset: usize,
+        data: &[V],
+    ) -> Result<&Self, UpdateBufferError> {
+        self.buffers
+            .get(buffer_id)
+            .ok_or_else(|| UpdateBufferError::BufferNotFound {
+                buffer_id: format!("{buffer_id:?}"),
+            })?
+            .update(&self.gl, offset, data)?;
+
+        Ok(self)
+    }
+
+    /// Reallocates a buffer to hold `byte_length` bytes, keeping as much of its current contents as fits.
+    ///
+    /// See [Buffer::resize]. As with [RendererData::update_buffer], index buffers are not supported.
+    pub fn resize_buffer(
+        &self,
+        buffer_id: &T::BufferId,
+        byte_length: usize,
+    ) -> Result<&Self, UpdateBufferError> {
+        self.buffers
+            .get(buffer_id)
+            .ok_or_else(|| UpdateBufferError::BufferNotFound {
+                buffer_id: format!("{buffer_id:?}"),
+            })?
+            .resize(&self.gl, byte_length);
+
+        Ok(self)
+    }
+
+    /// The index buffer created from the [crate::IndexBufferLink] with the given id
+    pub fn index_buffer(
+        &self,
+        buffer_id: &T::BufferId,
+    ) -> Option<&IndexBuffer<T::VertexArrayObjectId, T::BufferId>> {
+        self.index_buffers.get(buffer_id)
+    }
+
+    /// Every index buffer, by id
+    pub fn index_buffers(
+        &self,
+    ) -> &HashMap<T::BufferId, IndexBuffer<T::VertexArrayObjectId, T::BufferId>> {
+        &self.index_buffers
+    }
+}
+
+/// Buffer creation
+impl<T: RendererTypes> RendererDataBuilder<T> {
+    /// Creates all WebGL buffers, using the passed in BufferLinks
+    pub(super) fn create_buffers(&mut self) -> Result<&mut Self, CreateBufferError> {
+        let gl = self.gl.as_ref().ok_or(CreateBufferError::NoContext)?;
+        let now = Self::now();
+
+        for buffer_link in &self.buffer_links {
+            let buffer_id = buffer_link.buffer_id().clone();
+            let webgl_buffer = buffer_link.create_buffer(gl.clone(), now);
+            let buffer = Buffer::from_webgl_buffer(gl, buffer_id.clone(), webgl_buffer);
+            self.buffers.insert(buffer_id, buffer);
+        }
+
+        Ok(self)
+    }
+
+    /// Creates every VAO, along with the index buffers attached to them
+    pub(super) fn create_vaos(&mut self) -> Result<&mut Self, CreateVAOError> {
+        let gl = self.gl.as_ref().ok_or(CreateVAOError::NoContext)?;
+
+        for vao_id in self.vertex_array_object_links.iter() {
+            let vao = gl
+                .create_vertex_array()
+                .ok_or(CreateVAOError::NoneWasReturned)?;
+            self.vertex_array_objects.insert(vao_id.to_owned(), vao);
+        }
+
+        // the ELEMENT_ARRAY_BUFFER binding is saved in whichever VAO is bound at the time
+        for index_buffer_link in self.index_buffer_links.iter() {
+            let buffer_id = index_buffer_link.buffer_id();
+            let indices = index_buffer_link.indices();
+            let webgl_buffer = gl.create_buffer().ok_or(CreateVAOError::NoIndexBuffer)?;
+
+            for vao_id in index_buffer_link.vao_ids() {
+                let vao = self.vertex_array_objects.get(vao_id).ok_or_else(|| {
+                    CreateVAOError::VAONotFound {
+                        buffer_id: format!("{buffer_id:?}"),
+                        vao_id: format!("{vao_id:?}"),
+                    }
+                })?;
+                gl.bind_vertex_array(Some(vao));
+                gl.bind_buffer(
+                    WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER,
+                    Some(&webgl_buffer),
+                );
+            }
+            gl.bind_vertex_array(None);
+
+            // with no VAO bound, this binding only serves to upload the data
+            gl.bind_buffer(
+                WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER,
+                Some(&webgl_buffer),
+            );
+            indices.upload(gl);
+            gl.bind_buffer(WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER, None);
+
+            let index_buffer = IndexBuffer::new(
+                index_buffer_link.vao_ids().to_vec(),
+                buffer_id.clone(),
+                webgl_buffer,
+                indices.index_type(),
+                indices.len() as i32,
+            );
+            self.index_buffers.insert(buffer_id.clone(), index_buffer);
+        }
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,82 @@
+use super::RendererData;
+use crate::{DrawError, IndexBuffer, RendererTypes};
+use web_sys::WebGlVertexArrayObject;
+
+/// Draw calls
+impl<T: RendererTypes> RendererData<T> {
+    /// Draws every index of the index buffer attached to the VAO (see [crate::IndexBufferLink]),
+    /// using the program that is currently in use. `mode` is the primitive type, such as `TRIANGLES`.
+    pub fn draw_elements(
+        &self,
+        vao_id: &T::VertexArrayObjectId,
+        mode: u32,
+    ) -> Result<&Self, DrawError> {
+        let vao = self.vao_to_draw(vao_id)?;
+        let index_buffer =
+            self.vao_index_buffer(vao_id)
+                .ok_or_else(|| DrawError::IndexBufferNotFound {
+                    vao_id: format!("{vao_id:?}"),
+                })?;
+
+        self.gl.bind_vertex_array(Some(vao));
+        index_buffer.draw(&self.gl, mode);
+        self.gl.bind_vertex_array(None);
+
+        Ok(self)
+    }
+
+    /// Draws `instances` copies of the VAO's first `count` vertices (or, if an index buffer is attached to the VAO,
+    /// its first `count` indices) in a single draw call, using the program that is currently in use.
+    ///
+    /// Attributes with a divisor (see [crate::AttributeLink::set_divisor]) advance once per instance
+    /// rather than once per vertex.
+    pub fn draw_instanced(
+        &self,
+        vao_id: &T::VertexArrayObjectId,
+        mode: u32,
+        count: i32,
+        instances: i32,
+    ) -> Result<&Self, DrawError> {
+        let vao = self.vao_to_draw(vao_id)?;
+        let index_buffer = self.vao_index_buffer(vao_id);
+
+        self.gl.bind_vertex_array(Some(vao));
+        match index_buffer {
+            Some(index_buffer) => self.gl.draw_elements_instanced_with_i32(
+                mode,
+                count,
+                index_buffer.index_type(),
+                0,
+                instances,
+            ),
+            None => self.gl.draw_arrays_instanced(mode, 0, count, instances),
+        }
+        self.gl.bind_vertex_array(None);
+
+        Ok(self)
+    }
+}
+
+/// Private API
+impl<T: RendererTypes> RendererData<T> {
+    fn vao_to_draw(
+        &self,
+        vao_id: &T::VertexArrayObjectId,
+    ) -> Result<&WebGlVertexArrayObject, DrawError> {
+        self.vertex_array_objects
+            .get(vao_id)
+            .ok_or_else(|| DrawError::VAONotFound {
+                vao_id: format!("{vao_id:?}"),
+            })
+    }
+
+    /// The index buffer attached to the VAO, of which there is at most one once the builder has been validated
+    fn vao_index_buffer(
+        &self,
+        vao_id: &T::VertexArrayObjectId,
+    ) -> Option<&IndexBuffer<T::VertexArrayObjectId, T::BufferId>> {
+        self.index_buffers
+            .values()
+            .find(|index_buffer| index_buffer.vao_ids().contains(vao_id))
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
-    AttributeLinkJs, BufferLinkJs, FramebufferLinkJs, ProgramLinkJs, RenderCallbackJs,
-    RendererDataBuilder, RendererDataJs, RendererJs, TextureJs, TextureLinkJs,
-    TransformFeedbackLinkJs, UniformLinkJs,
+    AttributeLinkJs, BufferLinkJs, DuplicateIdPolicy, FramebufferLinkJs, JsTypes,
+    LoadProgressCallbackJs, MissingUniformPolicy, PipelineDescriptor, ProgramLinkJs,
+    RenderCallbackJs, RenderbufferLinkJs, RendererDataBuilder, RendererDataJs, RendererJs,
+    TextureJs, TextureLinkJs, TransformFeedbackLinkJs, UniformBlockLinkJs, UniformLinkJs,
 };
 use js_sys::{Function, Object};
 
@@ -12,19 +13,7 @@ use web_sys::HtmlCanvasElement;
 /// Wrapper around `RendererData` to make it callable from JavaScript.
 ///
 /// Types are adjusted to only use JavaScript-compatible types and no generics.
-type RendererDataBuilderJsInner = RendererDataBuilder<
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    Object,
->;
+type RendererDataBuilderJsInner = RendererDataBuilder<JsTypes>;
 
 /// See [RendererDataBuilder](crate::RendererDataBuilder)
 #[wasm_bindgen(inspectable, js_name = RendererDataBuilder)]
@@ -41,6 +30,18 @@ impl RendererDataBuilderJs {
         self.deref_mut().set_canvas(canvas);
     }
 
+    #[wasm_bindgen(js_name = setDuplicateIdPolicy)]
+    pub fn set_duplicate_id_policy(&mut self, duplicate_id_policy: DuplicateIdPolicy) {
+        self.deref_mut()
+            .set_duplicate_id_policy(duplicate_id_policy);
+    }
+
+    #[wasm_bindgen(js_name = setMissingUniformPolicy)]
+    pub fn set_missing_uniform_policy(&mut self, missing_uniform_policy: MissingUniformPolicy) {
+        self.deref_mut()
+            .set_missing_uniform_policy(missing_uniform_policy);
+    }
+
     #[wasm_bindgen(js_name = addFragmentShaderSrc)]
     pub fn add_fragment_shader_src(&mut self, id: String, fragment_shader_src: String) {
         self.deref_mut()
@@ -53,6 +54,29 @@ impl RendererDataBuilderJs {
             .add_vertex_shader_src(id, vertex_shader_src);
     }
 
+    #[wasm_bindgen(js_name = addFragmentShaderUrl)]
+    pub fn add_fragment_shader_url(&mut self, id: String, fragment_shader_url: String) {
+        self.deref_mut()
+            .add_fragment_shader_url(id, fragment_shader_url);
+    }
+
+    #[wasm_bindgen(js_name = addVertexShaderUrl)]
+    pub fn add_vertex_shader_url(&mut self, id: String, vertex_shader_url: String) {
+        self.deref_mut()
+            .add_vertex_shader_url(id, vertex_shader_url);
+    }
+
+    #[wasm_bindgen(js_name = addTextureUrl)]
+    pub fn add_texture_url(&mut self, id: String, texture_url: String) {
+        self.deref_mut().add_texture_url(id, texture_url);
+    }
+
+    #[wasm_bindgen(js_name = setLoadProgressCallback)]
+    pub fn set_load_progress_callback(&mut self, load_progress_callback: LoadProgressCallbackJs) {
+        self.deref_mut()
+            .set_load_progress_callback(load_progress_callback);
+    }
+
     #[wasm_bindgen(js_name = addProgramLink)]
     pub fn add_program_link(&mut self, program_link: ProgramLinkJs) {
         self.deref_mut().add_program_link(program_link);
@@ -73,6 +97,11 @@ impl RendererDataBuilderJs {
         self.deref_mut().add_uniform_link(uniform_link);
     }
 
+    #[wasm_bindgen(js_name = addUniformBlockLink)]
+    pub fn add_uniform_block_link(&mut self, uniform_block_link: UniformBlockLinkJs) {
+        self.deref_mut().add_uniform_block_link(uniform_block_link);
+    }
+
     #[wasm_bindgen(js_name = addBufferLink)]
     pub fn add_buffer_link(&mut self, buffer_link: BufferLinkJs) {
         self.deref_mut().add_buffer_link(buffer_link);
@@ -93,6 +122,12 @@ impl RendererDataBuilderJs {
         self.deref_mut().add_framebuffer_link(framebuffer_link);
     }
 
+    /// Saves a link that will be used to build a renderbuffer at build time
+    #[wasm_bindgen(js_name = addRenderbufferLink)]
+    pub fn add_renderbuffer_link(&mut self, renderbuffer_link: RenderbufferLinkJs) {
+        self.deref_mut().add_renderbuffer_link(renderbuffer_link);
+    }
+
     #[wasm_bindgen(js_name = addTransformFeedbackLink)]
     pub fn add_transform_feedback_link(mut self, transform_feedback_link: TransformFeedbackLinkJs) {
         self.deref_mut()
@@ -104,12 +139,42 @@ impl RendererDataBuilderJs {
         self.deref_mut().add_vao_link(vertex_array_object_id);
     }
 
+    /// Parses a JSON `PipelineDescriptor` and adds every resource it describes to the builder
+    #[wasm_bindgen(js_name = addPipelineDescriptorJson)]
+    pub fn add_pipeline_descriptor_json(&mut self, json: String) -> Result<(), String> {
+        let pipeline_descriptor =
+            PipelineDescriptor::from_json(&json).map_err(|err| err.to_string())?;
+        self.deref_mut()
+            .add_pipeline_descriptor(&pipeline_descriptor);
+        Ok(())
+    }
+
+    /// Parses a RON `PipelineDescriptor` and adds every resource it describes to the builder
+    #[wasm_bindgen(js_name = addPipelineDescriptorRon)]
+    pub fn add_pipeline_descriptor_ron(&mut self, ron: String) -> Result<(), String> {
+        let pipeline_descriptor =
+            PipelineDescriptor::from_ron(&ron).map_err(|err| err.to_string())?;
+        self.deref_mut()
+            .add_pipeline_descriptor(&pipeline_descriptor);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setLoseContextOnDispose)]
+    pub fn set_lose_context_on_dispose(&mut self, lose_context_on_dispose: bool) {
+        self.deref_mut()
+            .set_lose_context_on_dispose(lose_context_on_dispose);
+    }
+
     #[wasm_bindgen(js_name = setGetContextCallback)]
     pub fn set_get_context_callback(&mut self, get_context_callback: Function) {
         self.deref_mut()
             .set_get_context_callback(get_context_callback);
     }
 
+    pub fn validate(&self) -> Result<(), String> {
+        self.deref().validate().map_err(|err| err.to_string())
+    }
+
     #[wasm_bindgen(js_name = buildRendererData)]
     pub fn build_renderer_data(self) -> Result<RendererDataJs, String> {
         self.0
@@ -126,6 +191,25 @@ impl RendererDataBuilderJs {
             .map::<RendererJs, _>(Into::into)
             .map_err::<String, _>(|err| err.to_string())
     }
+
+    #[wasm_bindgen(js_name = buildRendererDataAsync)]
+    pub async fn build_renderer_data_async(self) -> Result<RendererDataJs, String> {
+        self.0
+            .build_renderer_data_async()
+            .await
+            .map(Into::into)
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = buildRendererAsync)]
+    pub async fn build_renderer_async(self) -> Result<RendererJs, String> {
+        self.0
+            .build_renderer_data_async()
+            .await
+            .map::<RendererDataJs, _>(Into::into)
+            .map::<RendererJs, _>(Into::into)
+            .map_err::<String, _>(|err| err.to_string())
+    }
 }
 
 impl Default for RendererDataBuilderJs {
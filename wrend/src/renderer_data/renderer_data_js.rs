@@ -1,7 +1,8 @@
 use crate::{
-    utils, AttributeJs, AttributeMap, BufferJs, BufferMap, FramebufferJs, RenderCallback,
-    RendererData, RendererDataBuilderJs, RendererJs, RendererJsInner, StringArray, TextureJs,
-    TextureJsArray, TextureMap, UniformJs, UniformMap, WebGlProgramMap, WebGlShaderMap,
+    utils, AttributeJs, AttributeMap, BindTexturesError, BufferJs, BufferMap, FramebufferJs,
+    JsTypes, RenderCallback, RendererData, RendererDataBuilderJs, RendererJs, RendererJsInner,
+    StringArray, TextureJs, TextureJsArray, TextureMap, UniformHandleJs, UniformJs, UniformMap,
+    UniformValue, UpdateUniformBlockError, WebGlProgramMap, WebGlShaderMap,
 };
 use js_sys::{Array, Map, Object};
 use log::error;
@@ -12,32 +13,15 @@ use std::{
 };
 use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
 use web_sys::{
-    HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram, WebGlShader, WebGlTransformFeedback,
-    WebGlVertexArrayObject,
+    HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram, WebGlRenderbuffer, WebGlShader,
+    WebGlTransformFeedback, WebGlVertexArrayObject,
 };
 
-/// Wrapper around `RendererData` to make it callable from JavaScript.
-///
-/// Types are adjusted to only use JavaScript-compatible types and no generics.
-pub type RendererDataJsInner = RendererData<
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    Object,
->;
-
 #[wasm_bindgen(inspectable, js_name = RendererData)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 // Reference counting the internals here is necessary to be able to
 // convert this value into a `JsValue` without cloning its internal data.
-pub struct RendererDataJs(Rc<RefCell<RendererDataJsInner>>);
+pub struct RendererDataJs(Rc<RefCell<RendererData<JsTypes>>>);
 
 #[wasm_bindgen(js_class = RendererData)]
 impl RendererDataJs {
@@ -202,6 +186,14 @@ impl RendererDataJs {
             .map(Into::into)
     }
 
+    /// The WebGL renderbuffer created from the `RenderbufferLink` with the given id
+    pub fn renderbuffer(&self, renderbuffer_id: String) -> Option<WebGlRenderbuffer> {
+        self.deref()
+            .borrow()
+            .renderbuffer(&renderbuffer_id)
+            .map(|renderbuffer| renderbuffer.webgl_renderbuffer().clone())
+    }
+
     #[wasm_bindgen(js_name = transformFeedback)]
     pub fn transform_feedback(
         &self,
@@ -233,6 +225,92 @@ impl RendererDataJs {
         self.deref().borrow().use_vao(&vao_id);
     }
 
+    /// Binds each texture to the texture unit of the sampler uniform with the same index, and points that sampler at it.
+    ///
+    /// Returns an error if `sampler_uniform_ids` and `texture_ids` have different lengths.
+    #[wasm_bindgen(js_name = bindTextures)]
+    pub fn bind_textures(
+        &self,
+        program_id: String,
+        sampler_uniform_ids: StringArray,
+        texture_ids: StringArray,
+    ) -> Result<(), String> {
+        let sampler_uniform_ids = utils::js_array_to_vec_strings(&sampler_uniform_ids);
+        let texture_ids = utils::js_array_to_vec_strings(&texture_ids);
+        if sampler_uniform_ids.len() != texture_ids.len() {
+            return Err(BindTexturesError::LengthMismatch {
+                sampler_count: sampler_uniform_ids.len(),
+                texture_count: texture_ids.len(),
+            }
+            .to_string());
+        }
+
+        let samplers: Vec<_> = sampler_uniform_ids.into_iter().zip(texture_ids).collect();
+        self.deref()
+            .borrow()
+            .bind_textures(&program_id, &samplers)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = drawElements)]
+    pub fn draw_elements(&self, vao_id: String, mode: u32) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .draw_elements(&vao_id, mode)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = drawInstanced)]
+    pub fn draw_instanced(
+        &self,
+        vao_id: String,
+        mode: u32,
+        count: i32,
+        instances: i32,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .draw_instanced(&vao_id, mode, count, instances)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    /// Overwrites part of a buffer with `data`, starting `offset` bytes into the buffer
+    #[wasm_bindgen(js_name = updateBuffer)]
+    pub fn update_buffer(
+        &self,
+        buffer_id: String,
+        offset: usize,
+        data: Vec<u8>,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .update_buffer(&buffer_id, offset, &data)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = resizeBuffer)]
+    pub fn resize_buffer(&self, buffer_id: String, byte_length: usize) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .resize_buffer(&buffer_id, byte_length)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    /// Replaces the contents of a per-instance buffer with tightly packed `f32` data
+    #[wasm_bindgen(js_name = updateInstanceBuffer)]
+    pub fn update_instance_buffer(&self, buffer_id: String, data: Vec<f32>) {
+        let renderer_data = self.deref().borrow();
+        match renderer_data.instance_buffer::<f32>(&buffer_id) {
+            Some(instance_buffer) => instance_buffer.update(&data),
+            None => error!("No buffer was found with the id: {buffer_id}"),
+        }
+    }
+
     #[wasm_bindgen(js_name = updateUniform)]
     pub fn update_uniform(&self, uniform_id: String) {
         self.deref().borrow().update_uniform(&uniform_id);
@@ -243,6 +321,35 @@ impl RendererDataJs {
         self.deref().borrow().update_uniforms();
     }
 
+    #[wasm_bindgen(js_name = uniformHandle)]
+    pub fn uniform_handle(&self, uniform_id: String) -> Option<UniformHandleJs> {
+        self.deref()
+            .borrow()
+            .uniform_handle::<UniformValue>(&uniform_id)
+            .map(Into::into)
+    }
+
+    /// Uploads data that has already been packed in `std140` layout to a uniform block's buffer
+    #[wasm_bindgen(js_name = updateUniformBlock)]
+    pub fn update_uniform_block(
+        &self,
+        uniform_block_id: String,
+        data: Vec<u8>,
+    ) -> Result<(), String> {
+        let renderer_data = self.deref().borrow();
+        let uniform_block = renderer_data
+            .uniform_block(&uniform_block_id)
+            .ok_or_else(|| {
+                UpdateUniformBlockError::UniformBlockNotFound {
+                    uniform_block_id: format!("{uniform_block_id:?}"),
+                }
+                .to_string()
+            })?;
+        uniform_block
+            .update_bytes(renderer_data.gl(), &data)
+            .map_err(|err| err.to_string())
+    }
+
     // `render` does not deref to the internal `RendererData` here, because its much less complex (and much faster) to
     // pass `RendererDataJs` as an argument to the `render` function here at this level , rather than converting
     // back into a `RendererDataJs` from within the `RendererData` struct.
@@ -259,6 +366,71 @@ impl RendererDataJs {
         }
     }
 
+    #[wasm_bindgen(js_name = replaceFragmentShaderSrc)]
+    pub fn replace_fragment_shader_src(
+        &self,
+        fragment_shader_id: String,
+        fragment_shader_src: String,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow_mut()
+            .replace_fragment_shader_src(&fragment_shader_id, fragment_shader_src)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = replaceVertexShaderSrc)]
+    pub fn replace_vertex_shader_src(
+        &self,
+        vertex_shader_id: String,
+        vertex_shader_src: String,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow_mut()
+            .replace_vertex_shader_src(&vertex_shader_id, vertex_shader_src)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn dispose(&self) {
+        self.deref().borrow_mut().dispose();
+    }
+
+    #[wasm_bindgen(js_name = isDisposed)]
+    pub fn is_disposed(&self) -> bool {
+        self.deref().borrow().is_disposed()
+    }
+
+    /// Returns a plain object describing how the resources in this `RendererData` relate to one another
+    pub fn describe(&self) -> Result<JsValue, String> {
+        let graph = self.deref().borrow().describe();
+        let json = serde_json::to_string(&graph).map_err(|err| err.to_string())?;
+        js_sys::JSON::parse(&json).map_err(|err| format!("{err:?}"))
+    }
+
+    #[wasm_bindgen(js_name = describeDot)]
+    pub fn describe_dot(&self) -> String {
+        self.deref().borrow().describe().to_dot()
+    }
+
+    #[wasm_bindgen(js_name = describeMermaid)]
+    pub fn describe_mermaid(&self) -> String {
+        self.deref().borrow().describe().to_mermaid()
+    }
+
+    #[wasm_bindgen(js_name = isContextLost)]
+    pub fn is_context_lost(&self) -> bool {
+        self.deref().borrow().is_context_lost()
+    }
+
+    pub fn rebuild(&self) -> Result<(), String> {
+        self.deref()
+            .borrow_mut()
+            .rebuild()
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
     #[wasm_bindgen(js_name = saveImage)]
     pub fn save_image(&self) {
         self.deref().borrow().save_image();
@@ -271,31 +443,17 @@ impl RendererDataJs {
 }
 
 impl RendererDataJs {
-    pub fn into_inner(self) -> Rc<RefCell<RendererDataJsInner>> {
+    pub fn into_inner(self) -> Rc<RefCell<RendererData<JsTypes>>> {
         self.0
     }
 
-    pub fn render_callback(
-        &self,
-    ) -> RenderCallback<
-        String,
-        String,
-        String,
-        String,
-        String,
-        String,
-        String,
-        String,
-        String,
-        String,
-        Object,
-    > {
+    pub fn render_callback(&self) -> RenderCallback<JsTypes> {
         self.deref().borrow().render_callback()
     }
 }
 
 impl Deref for RendererDataJs {
-    type Target = Rc<RefCell<RendererDataJsInner>>;
+    type Target = Rc<RefCell<RendererData<JsTypes>>>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -303,19 +461,19 @@ impl Deref for RendererDataJs {
 }
 
 impl DerefMut for RendererDataJs {
-    fn deref_mut(&mut self) -> &mut Rc<RefCell<RendererDataJsInner>> {
+    fn deref_mut(&mut self) -> &mut Rc<RefCell<RendererData<JsTypes>>> {
         &mut self.0
     }
 }
 
-impl From<RendererDataJsInner> for RendererDataJs {
-    fn from(renderer_data_js_inner: RendererDataJsInner) -> Self {
+impl From<RendererData<JsTypes>> for RendererDataJs {
+    fn from(renderer_data_js_inner: RendererData<JsTypes>) -> Self {
         Self(Rc::new(RefCell::new(renderer_data_js_inner)))
     }
 }
 
-impl From<Rc<RefCell<RendererDataJsInner>>> for RendererDataJs {
-    fn from(renderer_data_js_inner: Rc<RefCell<RendererDataJsInner>>) -> Self {
+impl From<Rc<RefCell<RendererData<JsTypes>>>> for RendererDataJs {
+    fn from(renderer_data_js_inner: Rc<RefCell<RendererData<JsTypes>>>) -> Self {
         Self(renderer_data_js_inner)
     }
 }
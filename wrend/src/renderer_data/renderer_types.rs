@@ -0,0 +1,63 @@
+use crate::{Id, IdName};
+use std::fmt::Debug;
+
+/// Bundles together all of the id types (and the user context type) used by a single rendering pipeline.
+///
+/// Rather than spelling out every id type each time a [`RendererData`](crate::RendererData),
+/// [`Renderer`](crate::Renderer), or callback is named, implement this trait once for a (usually empty)
+/// marker type and use that instead (the marker type itself only needs to be `Clone` and `Debug`):
+///
+/// ```
+/// use wrend::{IdDefault, RendererData, RendererTypes};
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// struct AppTypes;
+///
+/// impl RendererTypes for AppTypes {
+///     type VertexShaderId = &'static str;
+///     type FragmentShaderId = &'static str;
+///     type ProgramId = &'static str;
+///     type UniformId = String;
+///     type UniformBlockId = IdDefault;
+///     type BufferId = &'static str;
+///     type AttributeId = String;
+///     type TextureId = IdDefault;
+///     type FramebufferId = IdDefault;
+///     type RenderbufferId = IdDefault;
+///     type TransformFeedbackId = IdDefault;
+///     type VertexArrayObjectId = &'static str;
+///     type UserCtx = ();
+/// }
+///
+/// fn render(renderer_data: &RendererData<AppTypes>) {
+///     renderer_data.use_program(&"program");
+/// }
+/// ```
+pub trait RendererTypes: 'static + Clone + Debug {
+    /// Identifies vertex shaders
+    type VertexShaderId: Id;
+    /// Identifies fragment shaders
+    type FragmentShaderId: Id;
+    /// Identifies programs
+    type ProgramId: Id;
+    /// Identifies uniforms; its name is the uniform's name in the shader source
+    type UniformId: Id + IdName;
+    /// Identifies uniform blocks; its name is the block's name in the shader source
+    type UniformBlockId: Id + IdName;
+    /// Identifies buffers, including index buffers
+    type BufferId: Id;
+    /// Identifies attributes; its name is the attribute's name in the shader source
+    type AttributeId: Id + IdName;
+    /// Identifies textures
+    type TextureId: Id;
+    /// Identifies framebuffers
+    type FramebufferId: Id;
+    /// Identifies renderbuffers
+    type RenderbufferId: Id;
+    /// Identifies transform feedback objects
+    type TransformFeedbackId: Id;
+    /// Identifies vertex array objects
+    type VertexArrayObjectId: Id;
+    /// Arbitrary user state that is passed to every callback
+    type UserCtx: Clone + 'static;
+}
@@ -1,3 +1,6 @@
+mod context_lifecycle_callback;
+mod context_lifecycle_callback_js;
+mod context_lifecycle_event;
 mod errors;
 mod get_context_callback;
 mod get_context_callback_js;
@@ -6,6 +9,9 @@ mod render_callback_js;
 mod renderer;
 mod renderer_js;
 
+pub use context_lifecycle_callback::*;
+pub use context_lifecycle_callback_js::*;
+pub use context_lifecycle_event::*;
 pub use errors::*;
 pub use get_context_callback::*;
 pub use get_context_callback_js::*;
@@ -0,0 +1,50 @@
+use std::{fmt::Debug, ops::Deref, rc::Rc};
+
+use crate::{Callback, ContextLifecycleCallbackJs, ContextLifecycleEvent};
+
+/// This is the inner type that [`ContextLifecycleCallback`] stores
+pub type ContextLifecycleCallbackInner =
+    Callback<dyn Fn(ContextLifecycleEvent), ContextLifecycleCallbackJs>;
+
+/// This callback is called by a [`crate::Renderer`] whenever its WebGL2 context is lost or restored.
+/// Can be created by converting from either a Rust or a JavaScript callback.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct ContextLifecycleCallback(ContextLifecycleCallbackInner);
+
+impl Deref for ContextLifecycleCallback {
+    type Target = ContextLifecycleCallbackInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for ContextLifecycleCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ContextLifecycleCallback")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<F: Fn(ContextLifecycleEvent) + 'static> From<F> for ContextLifecycleCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(ContextLifecycleEvent)>
+        ))
+    }
+}
+
+impl<F: Fn(ContextLifecycleEvent) + 'static> From<Rc<F>> for ContextLifecycleCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(
+            callback as Rc<dyn Fn(ContextLifecycleEvent)>,
+        ))
+    }
+}
+
+impl From<ContextLifecycleCallbackJs> for ContextLifecycleCallback {
+    fn from(callback: ContextLifecycleCallbackJs) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
@@ -0,0 +1,14 @@
+use js_sys::Function;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(typescript_custom_section)]
+const CONTEXT_LIFECYCLE_CALLBACK_JS: &'static str = r#"
+type ContextLifecycleCallbackJs = (event: ContextLifecycleEvent) => void;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Function, is_type_of = JsValue::is_function, typescript_type = "ContextLifecycleCallbackJs")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type ContextLifecycleCallbackJs;
+}
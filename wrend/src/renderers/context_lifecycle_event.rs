@@ -0,0 +1,13 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Lifecycle events emitted by a [crate::Renderer] when its WebGL2 context is lost or restored
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ContextLifecycleEvent {
+    /// The WebGL2 context was lost. Animation is paused until the context is restored.
+    Lost,
+    /// The WebGL2 context was restored, every resource was rebuilt, and animation has resumed.
+    Restored,
+    /// The WebGL2 context was restored, but the pipeline could not be rebuilt. Animation stays paused.
+    RestoreFailed,
+}
@@ -1,27 +1,49 @@
+mod bind_textures_error;
 mod build_renderer_error;
 mod compile_shader_error;
 mod create_attribute_error;
 mod create_buffer_error;
 mod create_framebuffer_error;
+mod create_renderbuffer_error;
 mod create_texture_error;
 mod create_transform_feedback_error;
+mod create_uniform_block_error;
 mod create_uniform_error;
 mod create_vao_error;
+mod draw_error;
 mod link_program_error;
+mod load_asset_error;
+mod pipeline_descriptor_error;
 mod renderer_builder_error;
+mod replace_shader_error;
 mod save_context_error;
+mod texture_descriptor_error;
+mod update_buffer_error;
+mod update_uniform_block_error;
+mod validation_error;
 mod webgl_context_error;
 
+pub use bind_textures_error::*;
 pub use build_renderer_error::*;
 pub use compile_shader_error::*;
 pub use create_attribute_error::*;
 pub use create_buffer_error::*;
 pub use create_framebuffer_error::*;
+pub use create_renderbuffer_error::*;
 pub use create_texture_error::*;
 pub use create_transform_feedback_error::*;
+pub use create_uniform_block_error::*;
 pub use create_uniform_error::*;
 pub use create_vao_error::*;
+pub use draw_error::*;
 pub use link_program_error::*;
+pub use load_asset_error::*;
+pub use pipeline_descriptor_error::*;
 pub use renderer_builder_error::*;
+pub use replace_shader_error::*;
 pub use save_context_error::*;
+pub use texture_descriptor_error::*;
+pub use update_buffer_error::*;
+pub use update_uniform_block_error::*;
+pub use validation_error::*;
 pub use webgl_context_error::*;
@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum BindTexturesError {
+    #[error("No program has been saved with the id: {program_id}")]
+    ProgramNotFound { program_id: String },
+    #[error("No sampler uniform has been saved with the id: {uniform_id}")]
+    UniformNotFound { uniform_id: String },
+    #[error("No texture has been saved with the id: {texture_id}")]
+    TextureNotFound { texture_id: String },
+    /// The program's samplers need more texture units than the context provides
+    #[error("Program {program_id} has {sampler_count} sampler uniforms bound, but only {max_texture_image_units} texture units are available (MAX_TEXTURE_IMAGE_UNITS)")]
+    TooManyTextures {
+        /// The id of the program
+        program_id: String,
+        /// The number of sampler uniforms that would have a texture unit assigned
+        sampler_count: usize,
+        /// The number of texture units available to a fragment shader
+        max_texture_image_units: usize,
+    },
+    /// Sampler uniforms and textures were given as separate lists of different lengths
+    #[error("{sampler_count} sampler uniforms were given for {texture_count} textures")]
+    LengthMismatch {
+        /// The number of sampler uniform ids
+        sampler_count: usize,
+        /// The number of texture ids
+        texture_count: usize,
+    },
+}
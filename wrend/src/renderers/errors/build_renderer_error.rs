@@ -8,4 +8,6 @@ pub enum BuildRendererError {
     NoContext,
     #[error("No RenderCallback was supplied")]
     NoRenderCallback,
+    #[error("Some shaders or textures were added by URL but have not been loaded yet")]
+    UnloadedAssets,
 }
@@ -1,7 +1,131 @@
 use thiserror::Error;
+use web_sys::WebGl2RenderingContext;
 
+/// An error returned when creating a framebuffer, including when it is incomplete once its attachments are attached
 #[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
 pub enum CreateFramebufferError {
+    /// No WebGL2 context was available to create the framebuffer with
     #[error("No WebGL2RenderingContext was provided")]
     NoContext,
+    /// A texture attached to the framebuffer was not created
+    #[error(
+        "FramebufferLink {framebuffer_id} references TextureId {texture_id}, which has no texture"
+    )]
+    TextureNotFound {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+        /// The id of the missing texture
+        texture_id: String,
+    },
+    /// A renderbuffer attached to the framebuffer was not created
+    #[error(
+        "FramebufferLink {framebuffer_id} references RenderbufferId {renderbuffer_id}, which has no renderbuffer"
+    )]
+    RenderbufferNotFound {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+        /// The id of the missing renderbuffer
+        renderbuffer_id: String,
+    },
+    /// More color attachments were given than the context supports
+    #[error("FramebufferLink {framebuffer_id} has {color_attachment_count} color attachments, but only {max_color_attachments} are supported (MAX_COLOR_ATTACHMENTS / MAX_DRAW_BUFFERS)")]
+    TooManyColorAttachments {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+        /// The number of color attachments given
+        color_attachment_count: usize,
+        /// The smaller of `MAX_COLOR_ATTACHMENTS` and `MAX_DRAW_BUFFERS`
+        max_color_attachments: usize,
+    },
+    /// An attachment cannot be rendered to
+    #[error("Framebuffer {framebuffer_id} has an attachment that cannot be rendered to (FRAMEBUFFER_INCOMPLETE_ATTACHMENT)")]
+    IncompleteAttachment {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+    },
+    /// Nothing is attached to the framebuffer
+    #[error("Framebuffer {framebuffer_id} has no attachments (FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT)")]
+    MissingAttachment {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+    },
+    /// The attachments have different sizes
+    #[error("Framebuffer {framebuffer_id} has attachments of different sizes (FRAMEBUFFER_INCOMPLETE_DIMENSIONS)")]
+    IncompleteDimensions {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+    },
+    /// The combination of attachment formats is not supported
+    #[error("Framebuffer {framebuffer_id} combines attachment formats that are not supported together (FRAMEBUFFER_UNSUPPORTED)")]
+    Unsupported {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+    },
+    /// The attachments have different sample counts
+    #[error("Framebuffer {framebuffer_id} has attachments with different sample counts (FRAMEBUFFER_INCOMPLETE_MULTISAMPLE)")]
+    IncompleteMultisample {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+    },
+    /// The framebuffer is incomplete for any other reason
+    #[error("Framebuffer {framebuffer_id} is incomplete, with status {status:#06x}")]
+    Incomplete {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+        /// The status returned by `checkFramebufferStatus`
+        status: u32,
+    },
+}
+
+impl CreateFramebufferError {
+    /// Maps the result of `checkFramebufferStatus` to an error, or `None` if the framebuffer is complete
+    pub fn from_status(framebuffer_id: impl Into<String>, status: u32) -> Option<Self> {
+        let framebuffer_id = framebuffer_id.into();
+        let error = match status {
+            WebGl2RenderingContext::FRAMEBUFFER_COMPLETE => return None,
+            WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => {
+                Self::IncompleteAttachment { framebuffer_id }
+            }
+            WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => {
+                Self::MissingAttachment { framebuffer_id }
+            }
+            WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_DIMENSIONS => {
+                Self::IncompleteDimensions { framebuffer_id }
+            }
+            WebGl2RenderingContext::FRAMEBUFFER_UNSUPPORTED => Self::Unsupported { framebuffer_id },
+            WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => {
+                Self::IncompleteMultisample { framebuffer_id }
+            }
+            status => Self::Incomplete {
+                framebuffer_id,
+                status,
+            },
+        };
+        Some(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_framebuffer_status_to_errors() {
+        assert_eq!(
+            CreateFramebufferError::from_status(
+                "framebuffer",
+                WebGl2RenderingContext::FRAMEBUFFER_COMPLETE
+            ),
+            None
+        );
+        assert_eq!(
+            CreateFramebufferError::from_status(
+                "framebuffer",
+                WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_DIMENSIONS
+            ),
+            Some(CreateFramebufferError::IncompleteDimensions {
+                framebuffer_id: "framebuffer".to_string()
+            })
+        );
+    }
 }
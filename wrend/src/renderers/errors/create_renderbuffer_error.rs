@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+/// An error that occurred while creating the renderbuffer for a [crate::RenderbufferLink]
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum CreateRenderbufferError {
+    /// The builder has no WebGL2 context to create renderbuffers with
+    #[error("No WebGL2RenderingContext was provided")]
+    NoContext,
+    /// The builder has no canvas to size renderbuffers from
+    #[error("No canvas was provided")]
+    NoCanvas,
+    /// `createRenderbuffer` returned `null`, which happens when the context has been lost
+    #[error("The renderbuffer returned from the WebGL2 context was None")]
+    NoneWasReturned,
+    /// The sample count was negative
+    #[error("Renderbuffer {renderbuffer_id} cannot have a negative number of samples ({samples})")]
+    NegativeSamples {
+        /// The id of the renderbuffer's link
+        renderbuffer_id: String,
+        /// The sample count given to the link
+        samples: i32,
+    },
+    /// The sample count was greater than the context's `MAX_SAMPLES`
+    #[error("Renderbuffer {renderbuffer_id} has {samples} samples, but at most {max_samples} are supported (MAX_SAMPLES)")]
+    TooManySamples {
+        /// The id of the renderbuffer's link
+        renderbuffer_id: String,
+        /// The sample count given to the link
+        samples: i32,
+        /// The context's `MAX_SAMPLES`
+        max_samples: i32,
+    },
+    /// A renderbuffer with an integer format was given a nonzero sample count
+    #[error("Renderbuffer {renderbuffer_id} has an integer format ({internal_format:#06x}), which cannot be multisampled")]
+    IntegerMultisample {
+        /// The id of the renderbuffer's link
+        renderbuffer_id: String,
+        /// The renderbuffer's internal format
+        internal_format: u32,
+    },
+}
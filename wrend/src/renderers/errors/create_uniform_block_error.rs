@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum CreateUniformBlockError {
+    #[error("No WebGL2RenderingContext was provided")]
+    NoContext,
+    #[error("No WebGlBuffer was returned when creating the buffer for uniform block: {uniform_block_id}")]
+    NoBuffer { uniform_block_id: String },
+    #[error("The associated program could not be found for uniform block {uniform_block_id}: {program_id}")]
+    ProgramNotFound {
+        uniform_block_id: String,
+        program_id: String,
+    },
+    #[error("The uniform block {uniform_block_id} was not found in the program: {program_id}")]
+    UniformBlockIndexNotFound {
+        uniform_block_id: String,
+        program_id: String,
+    },
+}
@@ -1,9 +1,23 @@
 use thiserror::Error;
 
+/// An error returned when creating a VAO or attaching an index buffer to one
 #[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
 pub enum CreateVAOError {
+    /// No WebGL2 context was available to create the VAO with
     #[error("No WebGL2RenderingContext was provided")]
     NoContext,
+    /// WebGL2 failed to create the VAO
     #[error("The VAO returned from the WebGL2 context was None")]
     NoneWasReturned,
+    /// WebGL2 failed to create an index buffer
+    #[error("The index buffer returned from the WebGL2 context was None")]
+    NoIndexBuffer,
+    /// An index buffer is attached to a VAO that was never declared
+    #[error("IndexBufferLink {buffer_id} references VertexArrayObjectId {vao_id}, which has no VAO link")]
+    VAONotFound {
+        /// The id of the index buffer's link
+        buffer_id: String,
+        /// The id of the missing VAO
+        vao_id: String,
+    },
 }
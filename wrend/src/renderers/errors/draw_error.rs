@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+/// An error returned by [crate::RendererData::draw_elements] and [crate::RendererData::draw_instanced]
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum DrawError {
+    /// No VAO was created for the given id
+    #[error("No VAO has been saved with the id: {vao_id}")]
+    VAONotFound {
+        /// The id of the missing VAO
+        vao_id: String,
+    },
+    /// `draw_elements` was called for a VAO that has no [crate::IndexBufferLink] attached to it
+    #[error("VAO {vao_id} has no index buffer attached to it")]
+    IndexBufferNotFound {
+        /// The id of the VAO
+        vao_id: String,
+    },
+}
@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+/// An error returned when a shader or texture could not be loaded from its URL
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LoadAssetError {
+    /// Assets can only be fetched from a browser window
+    #[error("No window was available to load assets with")]
+    NoWindow,
+    /// The request could not be made, or its body could not be read
+    #[error("Could not fetch {url}: {message}")]
+    Fetch {
+        /// The URL of the asset
+        url: String,
+        /// What went wrong
+        message: String,
+    },
+    /// The server responded with an unsuccessful status
+    #[error("Fetching {url} failed with status {status}")]
+    Status {
+        /// The URL of the asset
+        url: String,
+        /// The HTTP status code
+        status: u16,
+    },
+    /// The image failed to load or decode
+    #[error("Could not load image {url}: {message}")]
+    Image {
+        /// The URL of the image
+        url: String,
+        /// What went wrong
+        message: String,
+    },
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+/// An error returned when reading or writing a [crate::PipelineDescriptor]
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum PipelineDescriptorError {
+    /// The descriptor could not be parsed from or written as JSON
+    #[error("Error occurred while converting pipeline descriptor to or from JSON: {0}")]
+    JsonError(String),
+    /// The descriptor could not be parsed from or written as RON
+    #[error("Error occurred while converting pipeline descriptor to or from RON: {0}")]
+    RonError(String),
+}
@@ -1,7 +1,8 @@
 use crate::{
     BuildRendererError, CompileShaderError, CreateAttributeError, CreateBufferError,
-    CreateFramebufferError, CreateTextureError, CreateTransformFeedbackError, CreateUniformError,
-    CreateVAOError, LinkProgramError, SaveContextError, WebGlContextError,
+    CreateFramebufferError, CreateRenderbufferError, CreateTextureError,
+    CreateTransformFeedbackError, CreateUniformBlockError, CreateUniformError, CreateVAOError,
+    IdKind, LinkProgramError, LoadAssetError, SaveContextError, ValidationError, WebGlContextError,
 };
 use thiserror::Error;
 
@@ -17,6 +18,8 @@ pub enum RendererBuilderError {
     LinkProgramError(#[from] LinkProgramError),
     #[error("Error occurred while initializing uniforms: {0:?}")]
     UniformError(#[from] CreateUniformError),
+    #[error("Error occurred while creating uniform block: {0:?}")]
+    CreateUniformBlockError(#[from] CreateUniformBlockError),
     #[error("Error occurred while trying to retrieve WebGL context from canvas: {0:?}")]
     SaveContextError(#[from] SaveContextError),
     #[error("Error occurred while trying to create Vertex Array Object: {0:?}")]
@@ -29,6 +32,14 @@ pub enum RendererBuilderError {
     CreateTextureError(#[from] CreateTextureError),
     #[error("Error occurred while trying to create framebuffer: {0:?}")]
     CreateFramebufferError(#[from] CreateFramebufferError),
+    #[error("Error occurred while trying to create renderbuffer: {0:?}")]
+    CreateRenderbufferError(#[from] CreateRenderbufferError),
     #[error("Error occurred while trying to create transform feedback: {0:?}")]
     CreateTransformFeedbackError(#[from] CreateTransformFeedbackError),
+    #[error("Error occurred while validating links: {0}")]
+    ValidationError(#[from] ValidationError),
+    #[error("Error occurred while loading assets: {0}")]
+    LoadAssetError(#[from] LoadAssetError),
+    #[error("A {kind:?} was added more than once with the id: {id}")]
+    DuplicateId { kind: IdKind, id: String },
 }
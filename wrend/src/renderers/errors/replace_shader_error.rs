@@ -0,0 +1,25 @@
+use crate::{CompileShaderError, CreateUniformBlockError, CreateUniformError, LinkProgramError};
+use thiserror::Error;
+
+/// An error returned when replacing a shader's source at runtime, in which case nothing is replaced
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ReplaceShaderError {
+    /// No shader was created for the given id
+    #[error("No shader has been saved with the id: {shader_id}")]
+    ShaderNotFound {
+        /// The id of the missing shader
+        shader_id: String,
+    },
+    /// The new source failed to compile
+    #[error("Error occurred while compiling shader: {0:?}")]
+    CompileShaderError(#[from] CompileShaderError),
+    /// A program that uses the shader failed to link with the new source
+    #[error("Error occurred while relinking program: {0:?}")]
+    LinkProgramError(#[from] LinkProgramError),
+    /// A uniform could not be found in a relinked program
+    #[error("Error occurred while resolving uniforms for relinked program: {0:?}")]
+    UniformError(#[from] CreateUniformError),
+    /// A uniform block could not be bound in a relinked program
+    #[error("Error occurred while binding uniform blocks for relinked program: {0:?}")]
+    UniformBlockError(#[from] CreateUniformBlockError),
+}
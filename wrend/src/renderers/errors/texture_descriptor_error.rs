@@ -0,0 +1,57 @@
+use thiserror::Error;
+
+/// A problem with a [crate::TextureDescriptor] that WebGL2 would otherwise report
+/// (or silently ignore) when the texture is created.
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub enum TextureDescriptorError {
+    /// The internal format, format and type are not a combination that WebGL2 accepts
+    #[error("Internal format {internal_format:#06x} cannot be created from format {format:#06x} and type {data_type:#06x}")]
+    InvalidFormat {
+        /// The sized internal format
+        internal_format: u32,
+        /// The format of the data
+        format: u32,
+        /// The type of the data
+        data_type: u32,
+    },
+    /// The magnification filter uses mipmaps
+    #[error("Mipmap filters can only be used as a minification filter")]
+    MipmapMagFilter,
+    /// The minification filter uses mipmaps, but `generate_mipmaps` is `false`
+    #[error("The minification filter samples from mipmaps, but mipmaps are not generated")]
+    MipmapsNotGenerated,
+    /// Mipmaps were requested for an integer format
+    #[error("Mipmaps cannot be generated for textures with an integer format")]
+    IntegerMipmaps,
+    /// Mipmaps were requested for a format that is not both color-renderable and filterable
+    #[error("Mipmaps cannot be generated for internal format {internal_format:#06x}, which is not both color-renderable and filterable in WebGL2")]
+    MipmapsUnsupported {
+        /// The sized internal format
+        internal_format: u32,
+    },
+    /// A filter that interpolates was used with a format that cannot be filtered
+    #[error("Internal format {internal_format:#06x} cannot be linearly filtered in WebGL2, so only the Nearest and NearestMipmapNearest filters can be used")]
+    UnfilterableFormat {
+        /// The sized internal format
+        internal_format: u32,
+    },
+    /// Initial data was given for a canvas-sized texture
+    #[error("Initial data can only be uploaded to textures with a fixed size")]
+    DataWithoutFixedSize,
+    /// The initial data's element type does not match the format's type
+    #[error("Initial data of type {data_type} cannot be uploaded as type {gl_type:#06x}")]
+    DataTypeMismatch {
+        /// The element type of the initial data
+        data_type: String,
+        /// The WebGL2 type of the format
+        gl_type: u32,
+    },
+    /// The initial data does not have one value per texel component
+    #[error("Expected {expected} values of initial data, but found {actual}")]
+    DataLengthMismatch {
+        /// The number of values that the texture holds
+        expected: usize,
+        /// The number of values that were given
+        actual: usize,
+    },
+}
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// An error returned when writing to a buffer that has already been created
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum UpdateBufferError {
+    /// No buffer was created for the given id
+    #[error("No buffer has been saved with the id: {buffer_id}")]
+    BufferNotFound {
+        /// The id of the missing buffer
+        buffer_id: String,
+    },
+    /// The write would extend past the end of the buffer
+    #[error("Cannot write {byte_length} bytes at offset {offset} into buffer {buffer_id}, which only holds {buffer_byte_length} bytes")]
+    OutOfBounds {
+        /// The id of the buffer
+        buffer_id: String,
+        /// The byte offset that the write starts at
+        offset: usize,
+        /// The number of bytes being written
+        byte_length: usize,
+        /// The size of the buffer in bytes
+        buffer_byte_length: usize,
+    },
+}
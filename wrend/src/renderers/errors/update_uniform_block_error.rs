@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+/// An error returned when uploading new data to a uniform block's buffer
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum UpdateUniformBlockError {
+    /// No uniform block was created for the given id
+    #[error("No uniform block has been saved with the id: {uniform_block_id}")]
+    UniformBlockNotFound {
+        /// The id that was looked up
+        uniform_block_id: String,
+    },
+    /// The data is larger than the buffer that was allocated for the block when it was created
+    #[error("Could not update uniform block {uniform_block_id}: {byte_length} bytes were supplied, but its buffer only holds {size}")]
+    SizeMismatch {
+        /// The id of the uniform block
+        uniform_block_id: String,
+        /// The number of bytes that were supplied
+        byte_length: usize,
+        /// The size of the block's buffer, in bytes
+        size: usize,
+    },
+}
@@ -0,0 +1,144 @@
+use crate::TextureDescriptorError;
+use std::fmt::Display;
+use thiserror::Error;
+
+/// A single broken cross-reference found while validating the links of a `RendererDataBuilder`.
+///
+/// All ids are stored as their `Debug` representation so that problems can be reported
+/// regardless of the concrete id types used by the pipeline.
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub enum ValidationProblem {
+    /// A program references a vertex shader that has no source
+    #[error("ProgramLink {program_id} references VertexShaderId {vertex_shader_id}, which has no shader source")]
+    VertexShaderNotFound {
+        /// The id of the program's link
+        program_id: String,
+        /// The id of the missing vertex shader
+        vertex_shader_id: String,
+    },
+    /// A program references a fragment shader that has no source
+    #[error("ProgramLink {program_id} references FragmentShaderId {fragment_shader_id}, which has no shader source")]
+    FragmentShaderNotFound {
+        /// The id of the program's link
+        program_id: String,
+        /// The id of the missing fragment shader
+        fragment_shader_id: String,
+    },
+    /// A uniform references a program that has no link
+    #[error(
+        "UniformLink {uniform_id} references ProgramId {program_id}, which has no ProgramLink"
+    )]
+    UniformProgramNotFound {
+        /// The id of the uniform's link
+        uniform_id: String,
+        /// The id of the missing program
+        program_id: String,
+    },
+    /// A uniform block references a program that has no link
+    #[error("UniformBlockLink {uniform_block_id} references ProgramId {program_id}, which has no ProgramLink")]
+    UniformBlockProgramNotFound {
+        /// The id of the uniform block's link
+        uniform_block_id: String,
+        /// The id of the missing program
+        program_id: String,
+    },
+    /// An attribute references a buffer that has no link
+    #[error(
+        "AttributeLink {attribute_id} references BufferId {buffer_id}, which has no BufferLink"
+    )]
+    AttributeBufferNotFound {
+        /// The id of the attribute's link
+        attribute_id: String,
+        /// The id of the missing buffer
+        buffer_id: String,
+    },
+    /// An attribute references a VAO that was never declared
+    #[error("AttributeLink {attribute_id} references VertexArrayObjectId {vao_id}, which has no VAO link")]
+    AttributeVAONotFound {
+        /// The id of the attribute's link
+        attribute_id: String,
+        /// The id of the missing VAO
+        vao_id: String,
+    },
+    /// An index buffer references a VAO that was never declared
+    #[error("IndexBufferLink {buffer_id} references VertexArrayObjectId {vao_id}, which has no VAO link")]
+    IndexBufferVAONotFound {
+        /// The id of the index buffer's link
+        buffer_id: String,
+        /// The id of the missing VAO
+        vao_id: String,
+    },
+    /// Only one index buffer can be bound to a VAO, so drawing it would use whichever one was bound last
+    #[error("VertexArrayObjectId {vao_id} is referenced by more than one IndexBufferLink: {buffer_ids:?}")]
+    MultipleIndexBuffers {
+        /// The id of the VAO
+        vao_id: String,
+        /// The ids of every index buffer that references the VAO, sorted
+        buffer_ids: Vec<String>,
+    },
+    /// A framebuffer attaches a texture that has no link
+    #[error("FramebufferLink {framebuffer_id} references TextureId {texture_id}, which has no TextureLink")]
+    FramebufferTextureNotFound {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+        /// The id of the missing texture
+        texture_id: String,
+    },
+    /// A framebuffer attaches a renderbuffer that has no link
+    #[error("FramebufferLink {framebuffer_id} references RenderbufferId {renderbuffer_id}, which has no RenderbufferLink")]
+    FramebufferRenderbufferNotFound {
+        /// The id of the framebuffer's link
+        framebuffer_id: String,
+        /// The id of the missing renderbuffer
+        renderbuffer_id: String,
+    },
+    /// A texture's descriptor would be rejected by WebGL2
+    #[error("TextureLink {texture_id} was created from an invalid TextureDescriptor: {error}")]
+    InvalidTextureDescriptor {
+        /// The id of the texture's link
+        texture_id: String,
+        /// What is wrong with the descriptor
+        error: TextureDescriptorError,
+    },
+    /// An image was added for a texture that has no link, so it would never be used
+    #[error("An image was added for TextureId {texture_id}, which has no TextureLink")]
+    TextureImageLinkNotFound {
+        /// The id that the image was added under
+        texture_id: String,
+    },
+}
+
+/// Report of every problem found while validating the links of a `RendererDataBuilder`.
+///
+/// Problems are sorted so that the report is stable between builds.
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash, Default)]
+pub struct ValidationError {
+    problems: Vec<ValidationProblem>,
+}
+
+impl ValidationError {
+    /// Expects `problems` to already be sorted
+    pub(crate) fn new(problems: Vec<ValidationProblem>) -> Self {
+        Self { problems }
+    }
+
+    /// Gets every problem that was found during validation
+    pub fn problems(&self) -> &[ValidationProblem] {
+        &self.problems
+    }
+
+    /// Returns `true` if no problems were found
+    pub fn is_empty(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} problem(s) found in link graph", self.problems.len())?;
+        for problem in &self.problems {
+            write!(f, "\n  - {problem}")?;
+        }
+        Ok(())
+    }
+}
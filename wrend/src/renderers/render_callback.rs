@@ -1,282 +1,40 @@
-use crate::{Callback, RendererData};
-use crate::{Id, IdDefault, IdName, RenderCallbackJs};
+use crate::RenderCallbackJs;
+use crate::{Callback, RendererData, RendererTypes};
 use std::ops::Deref;
 use std::rc::Rc;
 
 #[derive(Clone, Hash, Eq, PartialOrd, Debug)]
-pub struct RenderCallback<
-    VertexShaderId: Id = IdDefault,
-    FragmentShaderId: Id = IdDefault,
-    ProgramId: Id = IdDefault,
-    UniformId: Id + IdName = IdDefault,
-    BufferId: Id = IdDefault,
-    AttributeId: Id + IdName = IdDefault,
-    TextureId: Id = IdDefault,
-    FramebufferId: Id = IdDefault,
-    TransformFeedbackId: Id = IdDefault,
-    VertexArrayObjectId: Id = IdDefault,
-    UserCtx: Clone + 'static = (),
->(
-    Callback<
-        dyn Fn(
-            &RendererData<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        ),
-        RenderCallbackJs,
-    >,
-);
+pub struct RenderCallback<T: RendererTypes>(Callback<dyn Fn(&RendererData<T>), RenderCallbackJs>);
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    > PartialEq
-    for RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes> PartialEq for RenderCallback<T> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    > Deref
-    for RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
-    type Target = Callback<
-        dyn Fn(
-            &RendererData<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        ),
-        RenderCallbackJs,
-    >;
+impl<T: RendererTypes> Deref for RenderCallback<T> {
+    type Target = Callback<dyn Fn(&RendererData<T>), RenderCallbackJs>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-        F: Fn(
-                &RendererData<
-                    VertexShaderId,
-                    FragmentShaderId,
-                    ProgramId,
-                    UniformId,
-                    BufferId,
-                    AttributeId,
-                    TextureId,
-                    FramebufferId,
-                    TransformFeedbackId,
-                    VertexArrayObjectId,
-                    UserCtx,
-                >,
-            ) + 'static,
-    > From<F>
-    for RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes, F: Fn(&RendererData<T>) + 'static> From<F> for RenderCallback<T> {
     fn from(callback: F) -> Self {
-        Self(Callback::new_rs(Rc::new(callback)
-            as Rc<
-                dyn Fn(
-                    &RendererData<
-                        VertexShaderId,
-                        FragmentShaderId,
-                        ProgramId,
-                        UniformId,
-                        BufferId,
-                        AttributeId,
-                        TextureId,
-                        FramebufferId,
-                        TransformFeedbackId,
-                        VertexArrayObjectId,
-                        UserCtx,
-                    >,
-                ),
-            >))
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(&RendererData<T>)>
+        ))
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-        F: Fn(
-                &RendererData<
-                    VertexShaderId,
-                    FragmentShaderId,
-                    ProgramId,
-                    UniformId,
-                    BufferId,
-                    AttributeId,
-                    TextureId,
-                    FramebufferId,
-                    TransformFeedbackId,
-                    VertexArrayObjectId,
-                    UserCtx,
-                >,
-            ) + 'static,
-    > From<Rc<F>>
-    for RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes, F: Fn(&RendererData<T>) + 'static> From<Rc<F>> for RenderCallback<T> {
     fn from(callback: Rc<F>) -> Self {
-        Self(Callback::new_rs(
-            callback
-                as Rc<
-                    dyn Fn(
-                        &RendererData<
-                            VertexShaderId,
-                            FragmentShaderId,
-                            ProgramId,
-                            UniformId,
-                            BufferId,
-                            AttributeId,
-                            TextureId,
-                            FramebufferId,
-                            TransformFeedbackId,
-                            VertexArrayObjectId,
-                            UserCtx,
-                        >,
-                    ),
-                >,
-        ))
+        Self(Callback::new_rs(callback as Rc<dyn Fn(&RendererData<T>)>))
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    > From<RenderCallbackJs>
-    for RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes> From<RenderCallbackJs> for RenderCallback<T> {
     fn from(callback: RenderCallbackJs) -> Self {
         Self(Callback::new_js(callback))
     }
@@ -1,144 +1,95 @@
 use crate::{
-    recording_handlers, AnimationCallback, AnimationData, Attribute, Buffer, Framebuffer, Id,
-    IdName, RecordingData, RenderCallback, RendererData, RendererDataBuilder, RendererJs,
-    RendererJsInner, Texture, Uniform,
+    recording_handlers, AnimationCallback, AnimationData, Attribute, Buffer,
+    ContextLifecycleCallback, ContextLifecycleEvent, Framebuffer, Listener, RecordingData,
+    RenderCallback, RendererData, RendererDataBuilder, RendererJs, RendererJsInner, RendererTypes,
+    ReplaceShaderError, Texture, Uniform,
 };
 
 use log::{error, info};
 
 use std::cell::RefCell;
+use std::fmt::Debug;
 use std::ops::Deref;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{
-    window, HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram, WebGlShader,
+    window, Event, HtmlCanvasElement, Node, WebGl2RenderingContext, WebGlProgram, WebGlShader,
     WebGlTransformFeedback, WebGlVertexArrayObject,
 };
 
 /// The `Renderer` struct takes ownership of the `RendererData`, enabling it to
 /// perform more complex operations than would otherwise be possible, such as
 /// animating renders over time or recording canvas output.
-#[derive(Debug)]
-pub struct Renderer<
-    VertexShaderId: Id,
-    FragmentShaderId: Id,
-    ProgramId: Id,
-    UniformId: Id + IdName,
-    BufferId: Id,
-    AttributeId: Id + IdName,
-    TextureId: Id,
-    FramebufferId: Id,
-    TransformFeedbackId: Id,
-    VertexArrayObjectId: Id,
-    UserCtx: Clone + 'static,
-> {
-    renderer_data: Rc<
-        RefCell<
-            RendererData<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        >,
-    >,
-    animation_data: Rc<
-        RefCell<
-            AnimationData<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        >,
-    >,
+pub struct Renderer<T: RendererTypes> {
+    renderer_data: Rc<RefCell<RendererData<T>>>,
+    animation_data: Rc<RefCell<AnimationData<T>>>,
+    /// The `requestAnimationFrame` callback, which is only ever referenced weakly by itself,
+    /// so that taking it out of here is enough to free it (along with anything it captures)
+    animation_frame_callback: Rc<RefCell<Option<Closure<dyn Fn()>>>>,
     recording_data: Option<Rc<RefCell<RecordingData>>>,
+    context_lifecycle_callback: Rc<RefCell<Option<ContextLifecycleCallback>>>,
+    /// Listens for `webglcontextlost` and `webglcontextrestored` events on the canvas
+    context_listeners: Vec<Listener<Node, Event>>,
 }
 
-impl<
-        VertexShaderId: 'static + Id,
-        FragmentShaderId: 'static + Id,
-        ProgramId: 'static + Id,
-        UniformId: 'static + Id + IdName,
-        BufferId: 'static + Id,
-        AttributeId: 'static + Id + IdName,
-        TextureId: 'static + Id,
-        FramebufferId: 'static + Id,
-        TransformFeedbackId: 'static + Id,
-        VertexArrayObjectId: 'static + Id,
-        UserCtx: Clone + 'static,
-    >
-    Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
-    pub(crate) fn new(
-        renderer_data: RendererData<
-            VertexShaderId,
-            FragmentShaderId,
-            ProgramId,
-            UniformId,
-            BufferId,
-            AttributeId,
-            TextureId,
-            FramebufferId,
-            TransformFeedbackId,
-            VertexArrayObjectId,
-            UserCtx,
-        >,
-    ) -> Self {
+impl<T: RendererTypes> Renderer<T> {
+    pub(crate) fn new(renderer_data: RendererData<T>) -> Self {
         Self::new_with_rc_renderer(Rc::new(RefCell::new(renderer_data)))
     }
 
     /// Allows providing an already-wrapped `RendererData` as an argument.
-    pub(crate) fn new_with_rc_renderer(
-        renderer_data: Rc<
-            RefCell<
-                RendererData<
-                    VertexShaderId,
-                    FragmentShaderId,
-                    ProgramId,
-                    UniformId,
-                    BufferId,
-                    AttributeId,
-                    TextureId,
-                    FramebufferId,
-                    TransformFeedbackId,
-                    VertexArrayObjectId,
-                    UserCtx,
-                >,
-            >,
-        >,
-    ) -> Self {
+    pub(crate) fn new_with_rc_renderer(renderer_data: Rc<RefCell<RendererData<T>>>) -> Self {
+        let animation_data = Rc::new(RefCell::new(AnimationData::new()));
+        let context_lifecycle_callback = Rc::new(RefCell::new(None));
+        let canvas: Node = renderer_data.borrow().canvas().clone().into();
+
+        let context_lost_listener = {
+            let animation_data = Rc::clone(&animation_data);
+            let context_lifecycle_callback = Rc::clone(&context_lifecycle_callback);
+            Listener::new(canvas.clone(), "webglcontextlost", move |event: Event| {
+                // the context can only be restored if the default behavior is prevented
+                event.prevent_default();
+                animation_data.borrow_mut().set_is_paused(true);
+                info!("WebGL2 context was lost. Animation is paused until the context is restored");
+                Self::call_context_lifecycle_callback(
+                    &context_lifecycle_callback,
+                    ContextLifecycleEvent::Lost,
+                );
+            })
+        };
+
+        let context_restored_listener = {
+            let animation_data = Rc::clone(&animation_data);
+            let renderer_data = Rc::clone(&renderer_data);
+            let context_lifecycle_callback = Rc::clone(&context_lifecycle_callback);
+            Listener::new(canvas, "webglcontextrestored", move |_: Event| {
+                let rebuild_result = renderer_data.borrow_mut().rebuild().map(|_| ());
+                let context_lifecycle_event = match rebuild_result {
+                    Ok(_) => {
+                        animation_data.borrow_mut().set_is_paused(false);
+                        info!("WebGL2 context was restored. Resuming animation");
+                        ContextLifecycleEvent::Restored
+                    }
+                    Err(err) => {
+                        error!("Error rebuilding renderer after the WebGL2 context was restored: {err}");
+                        ContextLifecycleEvent::RestoreFailed
+                    }
+                };
+                Self::call_context_lifecycle_callback(
+                    &context_lifecycle_callback,
+                    context_lifecycle_event,
+                );
+            })
+        };
+
         Self {
             recording_data: None,
             renderer_data,
-            animation_data: Rc::new(RefCell::new(AnimationData::new())),
+            animation_data,
+            animation_frame_callback: Rc::new(RefCell::new(None)),
+            context_lifecycle_callback,
+            context_listeners: vec![context_lost_listener, context_restored_listener],
         }
     }
 
@@ -206,31 +157,43 @@ impl<
         }
 
         self.animation_data.borrow_mut().set_is_animating(true);
-        let f = Rc::new(RefCell::new(None));
-        let g = Rc::clone(&f);
-        let animation_data = Rc::clone(&self.animation_data);
-        let renderer_data = Rc::clone(&self.renderer_data);
+        // only weak references are captured, so that the `Renderer` (and its `RendererData`)
+        // can still be dropped while animating
+        let f: Weak<RefCell<Option<Closure<dyn Fn()>>>> =
+            Rc::downgrade(&self.animation_frame_callback);
+        let renderer_data = Rc::downgrade(&self.renderer_data);
         {
             let animation_data = Rc::clone(&self.animation_data);
-            *g.borrow_mut() = Some(Closure::wrap(Box::new(move || {
+            *self.animation_frame_callback.borrow_mut() = Some(Closure::wrap(Box::new(move || {
+                let (Some(f), Some(renderer_data)) = (f.upgrade(), renderer_data.upgrade()) else {
+                    return;
+                };
+
                 // do not run callback if not animating
                 if !animation_data.borrow().is_animating() {
                     return;
                 }
 
-                // run animation callback
-                animation_data
-                    .borrow_mut()
-                    .call_animation_callback(Rc::clone(&renderer_data));
+                // run animation callback, unless paused while the WebGL2 context is lost
+                if !animation_data.borrow().is_paused() {
+                    animation_data
+                        .borrow_mut()
+                        .call_animation_callback(renderer_data);
+                }
 
                 // schedule another requestAnimationFrame callback
-                let animation_id = Self::request_animation_frame(f.borrow().as_ref().unwrap());
-                animation_data.borrow_mut().set_request_id(animation_id);
-            }) as Box<dyn Fn()>));
+                let f = f.borrow();
+                if let Some(f) = f.as_ref() {
+                    let animation_id = Self::request_animation_frame(f);
+                    animation_data.borrow_mut().set_request_id(animation_id);
+                }
+            })
+                as Box<dyn Fn()>));
         }
 
-        let id = Self::request_animation_frame(g.borrow().as_ref().unwrap());
-        animation_data.borrow_mut().set_request_id(id);
+        let id =
+            Self::request_animation_frame(self.animation_frame_callback.borrow().as_ref().unwrap());
+        self.animation_data.borrow_mut().set_request_id(id);
     }
 
     pub fn stop_animating(&self) {
@@ -243,28 +206,13 @@ impl<
         window()
             .unwrap()
             .cancel_animation_frame(self.animation_data.borrow().request_id())
-            .expect("Should be able to cancel animation frame")
+            .expect("Should be able to cancel animation frame");
+        self.animation_frame_callback.borrow_mut().take();
     }
 
     pub fn set_animation_callback(
         &mut self,
-        animation_callback: Option<
-            impl Into<
-                AnimationCallback<
-                    VertexShaderId,
-                    FragmentShaderId,
-                    ProgramId,
-                    UniformId,
-                    BufferId,
-                    AttributeId,
-                    TextureId,
-                    FramebufferId,
-                    TransformFeedbackId,
-                    VertexArrayObjectId,
-                    UserCtx,
-                >,
-            >,
-        >,
+        animation_callback: Option<impl Into<AnimationCallback<T>>>,
     ) {
         self.animation_data
             .borrow_mut()
@@ -318,6 +266,37 @@ impl<
         }
     }
 
+    /// Sets a callback that is called whenever the WebGL2 context is lost or restored.
+    ///
+    /// The `Renderer` handles context loss on its own: animation is paused when the context is lost,
+    /// and every resource is rebuilt (see [RendererData::rebuild]) before animation resumes.
+    pub fn set_context_lifecycle_callback(
+        &mut self,
+        context_lifecycle_callback: Option<impl Into<ContextLifecycleCallback>>,
+    ) {
+        self.context_lifecycle_callback
+            .replace(context_lifecycle_callback.map(|cb| cb.into()));
+    }
+
+    /// Returns `true` while animation is paused because the WebGL2 context has been lost
+    pub fn is_paused(&self) -> bool {
+        self.animation_data.borrow().is_paused()
+    }
+
+    /// Stops any animation or recording in progress and deletes every WebGL resource
+    /// owned by the underlying `RendererData`. See [RendererData::dispose].
+    pub fn dispose(&self) {
+        if self.is_recording() {
+            self.stop_recording();
+        }
+
+        if self.is_animating() {
+            self.stop_animating();
+        }
+
+        self.renderer_data.borrow_mut().dispose();
+    }
+
     pub fn recorder_initialized(&self) -> bool {
         self.recording_data.is_some()
     }
@@ -334,28 +313,21 @@ impl<
             })
     }
 
-    pub(crate) fn renderer_data(
-        &self,
-    ) -> Rc<
-        RefCell<
-            RendererData<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        >,
-    > {
+    pub(crate) fn renderer_data(&self) -> Rc<RefCell<RendererData<T>>> {
         Rc::clone(&self.renderer_data)
     }
 
+    fn call_context_lifecycle_callback(
+        context_lifecycle_callback: &RefCell<Option<ContextLifecycleCallback>>,
+        context_lifecycle_event: ContextLifecycleEvent,
+    ) {
+        // cloned so that the callback is free to replace itself
+        let context_lifecycle_callback = context_lifecycle_callback.borrow().clone();
+        if let Some(context_lifecycle_callback) = context_lifecycle_callback {
+            context_lifecycle_callback.call_with_js_arg(context_lifecycle_event);
+        }
+    }
+
     pub(crate) fn request_animation_frame(f: &Closure<dyn Fn()>) -> i32 {
         window()
             .unwrap()
@@ -364,33 +336,7 @@ impl<
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    > Drop
-    for Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
+impl<T: RendererTypes> Drop for Renderer<T> {
     fn drop(&mut self) {
         // this would get dropped even if we didn't do it manually,
         // but dropping the listeners here before the rest of the data gets dropped
@@ -398,6 +344,7 @@ impl<
         if let Some(recording_data) = &self.recording_data {
             recording_data.borrow_mut().remove_all_event_listeners();
         }
+        self.context_listeners.clear();
 
         if self.is_recording() {
             self.stop_recording();
@@ -406,111 +353,43 @@ impl<
         if self.is_animating() {
             self.stop_animating();
         }
+
+        // other handles (such as a `RendererJs`) may still share the `RendererData`,
+        // but its resources belong to this `Renderer`
+        if let Ok(mut renderer_data) = self.renderer_data.try_borrow_mut() {
+            renderer_data.dispose();
+        }
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    >
-    From<
-        RendererData<
-            VertexShaderId,
-            FragmentShaderId,
-            ProgramId,
-            UniformId,
-            BufferId,
-            AttributeId,
-            TextureId,
-            FramebufferId,
-            TransformFeedbackId,
-            VertexArrayObjectId,
-            UserCtx,
-        >,
-    >
-    for Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
-    fn from(
-        renderer_data: RendererData<
-            VertexShaderId,
-            FragmentShaderId,
-            ProgramId,
-            UniformId,
-            BufferId,
-            AttributeId,
-            TextureId,
-            FramebufferId,
-            TransformFeedbackId,
-            VertexArrayObjectId,
-            UserCtx,
-        >,
-    ) -> Self {
+impl<T: RendererTypes> From<RendererData<T>> for Renderer<T> {
+    fn from(renderer_data: RendererData<T>) -> Self {
         Renderer::new(renderer_data)
     }
 }
 
 // Re-export of the (inexpensive) functionality from `RendererData`
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    >
-    Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
+impl<T: RendererTypes> Debug for Renderer<T>
+where
+    T::UserCtx: Debug,
 {
-    pub fn builder() -> RendererDataBuilder<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    > {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Renderer")
+            .field("renderer_data", &self.renderer_data)
+            .field("animation_data", &self.animation_data)
+            .field("animation_frame_callback", &self.animation_frame_callback)
+            .field("recording_data", &self.recording_data)
+            .field(
+                "context_lifecycle_callback",
+                &self.context_lifecycle_callback,
+            )
+            .field("context_listeners", &self.context_listeners)
+            .finish()
+    }
+}
+
+impl<T: RendererTypes> Renderer<T> {
+    pub fn builder() -> RendererDataBuilder<T> {
         RendererDataBuilder::default()
     }
 
@@ -522,50 +401,53 @@ impl<
         self.deref().borrow().deref().gl().to_owned()
     }
 
-    pub fn fragment_shader(&self, fragment_shader_id: &FragmentShaderId) -> Option<WebGlShader> {
+    pub fn fragment_shader(&self, fragment_shader_id: &T::FragmentShaderId) -> Option<WebGlShader> {
         self.deref()
             .borrow()
             .fragment_shader(fragment_shader_id)
             .map(Clone::clone)
     }
 
-    pub fn vertex_shader(&self, vertex_shader_id: &VertexShaderId) -> Option<WebGlShader> {
+    pub fn vertex_shader(&self, vertex_shader_id: &T::VertexShaderId) -> Option<WebGlShader> {
         self.deref()
             .borrow()
             .vertex_shader(vertex_shader_id)
             .map(Clone::clone)
     }
 
-    pub fn program(&self, program_id: &ProgramId) -> Option<WebGlProgram> {
+    pub fn program(&self, program_id: &T::ProgramId) -> Option<WebGlProgram> {
         self.deref().borrow().program(program_id).map(Clone::clone)
     }
 
-    pub fn uniform(&self, uniform_id: &UniformId) -> Option<Uniform<ProgramId, UniformId>> {
+    pub fn uniform(
+        &self,
+        uniform_id: &T::UniformId,
+    ) -> Option<Uniform<T::ProgramId, T::UniformId>> {
         self.deref().borrow().uniform(uniform_id).map(Clone::clone)
     }
 
-    pub fn buffer(&self, buffer_id: &BufferId) -> Option<Buffer<BufferId>> {
+    pub fn buffer(&self, buffer_id: &T::BufferId) -> Option<Buffer<T::BufferId>> {
         self.deref().borrow().buffer(buffer_id).map(Clone::clone)
     }
 
     pub fn attribute(
         &self,
-        attribute_id: &AttributeId,
-    ) -> Option<Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
+        attribute_id: &T::AttributeId,
+    ) -> Option<Attribute<T::VertexArrayObjectId, T::BufferId, T::AttributeId>> {
         self.deref()
             .borrow()
             .attribute(attribute_id)
             .map(Clone::clone)
     }
 
-    pub fn texture(&self, texture_id: &TextureId) -> Option<Texture<TextureId>> {
+    pub fn texture(&self, texture_id: &T::TextureId) -> Option<Texture<T::TextureId>> {
         self.deref().borrow().texture(texture_id).map(Clone::clone)
     }
 
     pub fn framebuffer(
         &self,
-        framebuffer_id: &FramebufferId,
-    ) -> Option<Framebuffer<FramebufferId>> {
+        framebuffer_id: &T::FramebufferId,
+    ) -> Option<Framebuffer<T::FramebufferId>> {
         self.deref()
             .borrow()
             .framebuffer(framebuffer_id)
@@ -574,7 +456,7 @@ impl<
 
     pub fn transform_feedback(
         &self,
-        transform_feedback_id: &TransformFeedbackId,
+        transform_feedback_id: &T::TransformFeedbackId,
     ) -> Option<WebGlTransformFeedback> {
         self.deref()
             .borrow()
@@ -582,24 +464,24 @@ impl<
             .map(Clone::clone)
     }
 
-    pub fn vao(&self, vao_id: &VertexArrayObjectId) -> Option<WebGlVertexArrayObject> {
+    pub fn vao(&self, vao_id: &T::VertexArrayObjectId) -> Option<WebGlVertexArrayObject> {
         self.deref().borrow().vao(vao_id).map(Clone::clone)
     }
 
-    pub fn user_ctx(&self) -> Option<UserCtx> {
+    pub fn user_ctx(&self) -> Option<T::UserCtx> {
         self.deref().borrow().user_ctx().map(Clone::clone)
     }
 
-    pub fn use_program(&self, program_id: &ProgramId) -> &Self {
+    pub fn use_program(&self, program_id: &T::ProgramId) -> &Self {
         self.deref().borrow().use_program(program_id);
         self
     }
 
-    pub fn use_vao(&self, vao_id: &VertexArrayObjectId) -> &Self {
+    pub fn use_vao(&self, vao_id: &T::VertexArrayObjectId) -> &Self {
         self.deref().borrow().use_vao(vao_id);
         self
     }
-    pub fn update_uniform(&self, uniform_id: &UniformId) -> &Self {
+    pub fn update_uniform(&self, uniform_id: &T::UniformId) -> &Self {
         self.deref().borrow().update_uniform(uniform_id);
         self
     }
@@ -618,138 +500,43 @@ impl<
         self.deref().borrow().save_image()
     }
 
-    pub fn render_callback(
+    /// See [RendererData::replace_fragment_shader_src]
+    pub fn replace_fragment_shader_src(
+        &self,
+        fragment_shader_id: &T::FragmentShaderId,
+        fragment_shader_src: impl Into<String>,
+    ) -> Result<&Self, ReplaceShaderError> {
+        self.deref()
+            .borrow_mut()
+            .replace_fragment_shader_src(fragment_shader_id, fragment_shader_src)?;
+        Ok(self)
+    }
+
+    /// See [RendererData::replace_vertex_shader_src]
+    pub fn replace_vertex_shader_src(
         &self,
-    ) -> RenderCallback<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    > {
+        vertex_shader_id: &T::VertexShaderId,
+        vertex_shader_src: impl Into<String>,
+    ) -> Result<&Self, ReplaceShaderError> {
+        self.deref()
+            .borrow_mut()
+            .replace_vertex_shader_src(vertex_shader_id, vertex_shader_src)?;
+        Ok(self)
+    }
+
+    pub fn render_callback(&self) -> RenderCallback<T> {
         self.deref().borrow().render_callback()
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    >
-    From<
-        Rc<
-            RefCell<
-                RendererData<
-                    VertexShaderId,
-                    FragmentShaderId,
-                    ProgramId,
-                    UniformId,
-                    BufferId,
-                    AttributeId,
-                    TextureId,
-                    FramebufferId,
-                    TransformFeedbackId,
-                    VertexArrayObjectId,
-                    UserCtx,
-                >,
-            >,
-        >,
-    >
-    for Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
-    fn from(
-        renderer_data: Rc<
-            RefCell<
-                RendererData<
-                    VertexShaderId,
-                    FragmentShaderId,
-                    ProgramId,
-                    UniformId,
-                    BufferId,
-                    AttributeId,
-                    TextureId,
-                    FramebufferId,
-                    TransformFeedbackId,
-                    VertexArrayObjectId,
-                    UserCtx,
-                >,
-            >,
-        >,
-    ) -> Self {
+impl<T: RendererTypes> From<Rc<RefCell<RendererData<T>>>> for Renderer<T> {
+    fn from(renderer_data: Rc<RefCell<RendererData<T>>>) -> Self {
         Renderer::new_with_rc_renderer(renderer_data)
     }
 }
 
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    > Deref
-    for Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
-    type Target = Rc<
-        RefCell<
-            RendererData<
-                VertexShaderId,
-                FragmentShaderId,
-                ProgramId,
-                UniformId,
-                BufferId,
-                AttributeId,
-                TextureId,
-                FramebufferId,
-                TransformFeedbackId,
-                VertexArrayObjectId,
-                UserCtx,
-            >,
-        >,
-    >;
+impl<T: RendererTypes> Deref for Renderer<T> {
+    type Target = Rc<RefCell<RendererData<T>>>;
 
     fn deref(&self) -> &Self::Target {
         &self.renderer_data
@@ -1,6 +1,7 @@
 use crate::{
-    AnimationCallbackJs, AttributeJs, BufferJs, Callback, FramebufferJs, RenderCallbackJs,
-    Renderer, RendererDataBuilderJs, RendererDataJs, TextureJs, UniformJs,
+    AnimationCallbackJs, AttributeJs, BufferJs, Callback, ContextLifecycleCallbackJs,
+    FramebufferJs, JsTypes, RenderCallbackJs, Renderer, RendererDataBuilderJs, RendererDataJs,
+    TextureJs, UniformJs,
 };
 use js_sys::Object;
 use log::error;
@@ -11,19 +12,7 @@ use web_sys::{
     WebGlVertexArrayObject,
 };
 
-pub type RendererJsInner = Renderer<
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    Object,
->;
+pub type RendererJsInner = Renderer<JsTypes>;
 
 #[wasm_bindgen(inspectable, js_name = Renderer)]
 pub struct RendererJs(RendererJsInner);
@@ -65,6 +54,29 @@ impl RendererJs {
         self.deref().clear_recorded_data();
     }
 
+    #[wasm_bindgen(js_name = setContextLifecycleCallback)]
+    pub fn set_context_lifecycle_callback(
+        &mut self,
+        context_lifecycle_callback: Option<ContextLifecycleCallbackJs>,
+    ) {
+        self.deref_mut()
+            .set_context_lifecycle_callback(context_lifecycle_callback);
+    }
+
+    #[wasm_bindgen(js_name = isPaused)]
+    pub fn is_paused(&self) -> bool {
+        self.deref().is_paused()
+    }
+
+    #[wasm_bindgen(js_name = isContextLost)]
+    pub fn is_context_lost(&self) -> bool {
+        self.deref().borrow().is_context_lost()
+    }
+
+    pub fn dispose(&self) {
+        self.deref().dispose();
+    }
+
     #[wasm_bindgen(js_name = recorderInitialized)]
     pub fn recorder_initialized(&self) -> bool {
         self.deref().recorder_initialized()
@@ -218,6 +230,32 @@ impl RendererJs {
         }
     }
 
+    #[wasm_bindgen(js_name = replaceFragmentShaderSrc)]
+    pub fn replace_fragment_shader_src(
+        &self,
+        fragment_shader_id: String,
+        fragment_shader_src: String,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow_mut()
+            .replace_fragment_shader_src(&fragment_shader_id, fragment_shader_src)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = replaceVertexShaderSrc)]
+    pub fn replace_vertex_shader_src(
+        &self,
+        vertex_shader_id: String,
+        vertex_shader_src: String,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow_mut()
+            .replace_vertex_shader_src(&vertex_shader_id, vertex_shader_src)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
     #[wasm_bindgen(js_name = saveImage)]
     pub fn save_image(&self) {
         self.deref().borrow().save_image()
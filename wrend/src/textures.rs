@@ -6,6 +6,7 @@ mod texture_create_context_js;
 mod texture_js;
 mod texture_link;
 mod texture_link_js;
+mod texture_units;
 
 pub use texture::*;
 pub use texture_create_callback::*;
@@ -15,3 +16,4 @@ pub use texture_create_context_js::*;
 pub use texture_js::*;
 pub use texture_link::*;
 pub use texture_link_js::*;
+pub use texture_units::*;
@@ -1,4 +1,4 @@
-use crate::{Id, TextureJs, TextureJsInner};
+use crate::{Id, TextureDescriptor, TextureJs, TextureJsInner};
 use std::fmt::Debug;
 use std::hash::Hash;
 use wasm_bindgen::JsValue;
@@ -8,6 +8,8 @@ use web_sys::WebGlTexture;
 pub struct Texture<TextureId: Id> {
     texture_id: TextureId,
     webgl_texture: WebGlTexture,
+    descriptor: Option<TextureDescriptor<TextureId>>,
+    size: Option<(i32, i32)>,
 }
 
 impl<TextureId: Id> Texture<TextureId> {
@@ -16,6 +18,8 @@ impl<TextureId: Id> Texture<TextureId> {
         Self {
             texture_id,
             webgl_texture,
+            descriptor: None,
+            size: None,
         }
     }
 
@@ -26,6 +30,31 @@ impl<TextureId: Id> Texture<TextureId> {
     pub fn webgl_texture(&self) -> &WebGlTexture {
         &self.webgl_texture
     }
+
+    /// The descriptor that the texture was created from, if it was created with [crate::TextureLink::from_descriptor]
+    pub fn descriptor(&self) -> Option<&TextureDescriptor<TextureId>> {
+        self.descriptor.as_ref()
+    }
+
+    /// The width of the texture, if it was created from a descriptor
+    pub fn width(&self) -> Option<i32> {
+        self.size.map(|(width, _)| width)
+    }
+
+    /// The height of the texture, if it was created from a descriptor
+    pub fn height(&self) -> Option<i32> {
+        self.size.map(|(_, height)| height)
+    }
+
+    pub(crate) fn set_descriptor(
+        &mut self,
+        descriptor: TextureDescriptor<TextureId>,
+        width: i32,
+        height: i32,
+    ) {
+        self.descriptor = Some(descriptor);
+        self.size = Some((width, height));
+    }
 }
 
 impl<TextureId: Id> Debug for Texture<TextureId> {
@@ -33,6 +62,8 @@ impl<TextureId: Id> Debug for Texture<TextureId> {
         f.debug_struct("Texture")
             .field("texture_id", &self.texture_id)
             .field("webgl_texture", &self.webgl_texture)
+            .field("descriptor", &self.descriptor)
+            .field("size", &self.size)
             .finish()
     }
 }
@@ -1,4 +1,4 @@
-use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
+use web_sys::{HtmlCanvasElement, HtmlImageElement, WebGl2RenderingContext};
 
 /// This is the context object that is passed to the create_texture callback function
 #[derive(Debug, Clone)]
@@ -6,11 +6,22 @@ pub struct TextureCreateContext {
     gl: WebGl2RenderingContext,
     now: f64,
     canvas: HtmlCanvasElement,
+    image: Option<HtmlImageElement>,
 }
 
 impl TextureCreateContext {
-    pub fn new(gl: WebGl2RenderingContext, now: f64, canvas: HtmlCanvasElement) -> Self {
-        Self { gl, now, canvas }
+    pub fn new(
+        gl: WebGl2RenderingContext,
+        now: f64,
+        canvas: HtmlCanvasElement,
+        image: Option<HtmlImageElement>,
+    ) -> Self {
+        Self {
+            gl,
+            now,
+            canvas,
+            image,
+        }
     }
 
     pub fn gl(&self) -> &WebGl2RenderingContext {
@@ -24,4 +35,9 @@ impl TextureCreateContext {
     pub fn canvas(&self) -> &HtmlCanvasElement {
         &self.canvas
     }
+
+    /// The image loaded for this texture, if one was added with [crate::RendererDataBuilder::add_texture_url]
+    pub fn image(&self) -> Option<&HtmlImageElement> {
+        self.image.as_ref()
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{IntoJsWrapper, TextureCreateContext};
 use std::ops::{Deref, DerefMut};
 use wasm_bindgen::prelude::wasm_bindgen;
-use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
+use web_sys::{HtmlCanvasElement, HtmlImageElement, WebGl2RenderingContext};
 
 pub type TextureCreateContextJsInner = TextureCreateContext;
 
@@ -21,6 +21,10 @@ impl TextureCreateContextJs {
     pub fn canvas(&self) -> HtmlCanvasElement {
         self.deref().canvas().clone()
     }
+
+    pub fn image(&self) -> Option<HtmlImageElement> {
+        self.deref().image().cloned()
+    }
 }
 
 impl TextureCreateContextJs {
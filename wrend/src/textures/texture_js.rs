@@ -20,6 +20,14 @@ impl TextureJs {
     pub fn webgl_texture(&self) -> WebGlTexture {
         self.deref().webgl_texture().to_owned()
     }
+
+    pub fn width(&self) -> Option<i32> {
+        self.deref().width()
+    }
+
+    pub fn height(&self) -> Option<i32> {
+        self.deref().height()
+    }
 }
 
 impl Deref for TextureJs {
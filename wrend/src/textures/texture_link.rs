@@ -1,13 +1,14 @@
 use super::texture_create_context::TextureCreateContext;
-use crate::{Id, TextureCreateCallback};
+use crate::{Id, TextureCreateCallback, TextureDescriptor};
 use std::fmt::Debug;
 use std::hash::Hash;
-use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlTexture};
+use web_sys::{HtmlCanvasElement, HtmlImageElement, WebGl2RenderingContext, WebGlTexture};
 
 #[derive(Clone)]
 pub struct TextureLink<TextureId: Id> {
     texture_id: TextureId,
     create_texture_callback: TextureCreateCallback,
+    descriptor: Option<TextureDescriptor<TextureId>>,
 }
 
 impl<TextureId: Id> TextureLink<TextureId> {
@@ -18,20 +19,40 @@ impl<TextureId: Id> TextureLink<TextureId> {
         Self {
             texture_id,
             create_texture_callback: create_texture_callback.into(),
+            descriptor: None,
         }
     }
 
+    /// Creates the texture from a [TextureDescriptor] rather than from a callback.
+    ///
+    /// The descriptor is kept, so that the created [crate::Texture]'s size and format can be queried later.
+    pub fn from_descriptor(descriptor: TextureDescriptor<TextureId>) -> Self {
+        let texture_id = descriptor.texture_id.clone();
+        let texture_descriptor = descriptor.clone();
+        let mut texture_link = Self::new(texture_id, move |ctx: &TextureCreateContext| {
+            texture_descriptor.create_texture(ctx)
+        });
+        texture_link.descriptor = Some(descriptor);
+        texture_link
+    }
+
     pub fn texture_id(&self) -> &TextureId {
         &self.texture_id
     }
 
+    /// The descriptor that the texture is created from, if it was created with [TextureLink::from_descriptor]
+    pub fn descriptor(&self) -> Option<&TextureDescriptor<TextureId>> {
+        self.descriptor.as_ref()
+    }
+
     pub fn create_texture(
         &self,
         gl: WebGl2RenderingContext,
         now: f64,
         canvas: HtmlCanvasElement,
+        image: Option<HtmlImageElement>,
     ) -> WebGlTexture {
-        let texture_create_context = TextureCreateContext::new(gl, now, canvas);
+        let texture_create_context = TextureCreateContext::new(gl, now, canvas, image);
         self.create_texture_callback
             .call_with_into_js_arg_and_return(&texture_create_context)
     }
@@ -42,6 +63,7 @@ impl<TextureId: Id> Debug for TextureLink<TextureId> {
         f.debug_struct("TextureLink")
             .field("texture_id", &self.texture_id)
             .field("create_texture_callback", &self.create_texture_callback)
+            .field("descriptor", &self.descriptor)
             .finish()
     }
 }
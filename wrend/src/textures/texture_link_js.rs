@@ -1,7 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
 use wasm_bindgen::prelude::wasm_bindgen;
-use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlTexture};
+use web_sys::{HtmlCanvasElement, HtmlImageElement, WebGl2RenderingContext, WebGlTexture};
 
 use crate::{TextureCreateCallbackJs, TextureLink};
 
@@ -28,8 +28,9 @@ impl TextureLinkJs {
         gl: WebGl2RenderingContext,
         now: f64,
         canvas: HtmlCanvasElement,
+        image: Option<HtmlImageElement>,
     ) -> WebGlTexture {
-        self.deref().create_texture(gl, now, canvas)
+        self.deref().create_texture(gl, now, canvas, image)
     }
 }
 
@@ -0,0 +1,62 @@
+/// The texture unit assigned to each sampler uniform of a program.
+///
+/// Units are handed out in order from `0` the first time a sampler is bound, and stay assigned
+/// to that sampler afterwards, so binding a program's textures over several calls never reuses a unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextureUnits<UniformId> {
+    sampler_uniform_ids: Vec<UniformId>,
+}
+
+impl<UniformId> Default for TextureUnits<UniformId> {
+    fn default() -> Self {
+        Self {
+            sampler_uniform_ids: Vec::new(),
+        }
+    }
+}
+
+impl<UniformId: PartialEq + Clone> TextureUnits<UniformId> {
+    /// The unit assigned to the sampler, assigning the next free unit if it has none yet
+    pub fn assign(&mut self, sampler_uniform_id: &UniformId) -> u32 {
+        self.unit(sampler_uniform_id).unwrap_or_else(|| {
+            self.sampler_uniform_ids.push(sampler_uniform_id.clone());
+            (self.sampler_uniform_ids.len() - 1) as u32
+        })
+    }
+
+    /// The unit assigned to the sampler, if any
+    pub fn unit(&self, sampler_uniform_id: &UniformId) -> Option<u32> {
+        self.sampler_uniform_ids
+            .iter()
+            .position(|assigned_uniform_id| assigned_uniform_id == sampler_uniform_id)
+            .map(|unit| unit as u32)
+    }
+
+    /// The number of units that have been assigned
+    pub fn len(&self) -> usize {
+        self.sampler_uniform_ids.len()
+    }
+
+    /// Whether no units have been assigned
+    pub fn is_empty(&self) -> bool {
+        self.sampler_uniform_ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_units_assigned_across_calls() {
+        let mut texture_units = TextureUnits::default();
+
+        assert_eq!(texture_units.assign(&"u_albedo"), 0);
+        assert_eq!(texture_units.assign(&"u_normals"), 1);
+        assert_eq!(texture_units.assign(&"u_depth"), 2);
+        assert_eq!(texture_units.assign(&"u_albedo"), 0);
+        assert_eq!(texture_units.unit(&"u_normals"), Some(1));
+        assert_eq!(texture_units.unit(&"u_shadow"), None);
+        assert_eq!(texture_units.len(), 3);
+    }
+}
@@ -0,0 +1,9 @@
+mod std140;
+mod uniform_block;
+mod uniform_block_link;
+mod uniform_block_link_js;
+
+pub use self::std140::*;
+pub use uniform_block::*;
+pub use uniform_block_link::*;
+pub use uniform_block_link_js::*;
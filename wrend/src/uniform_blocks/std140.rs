@@ -0,0 +1,269 @@
+/// A type that can be packed into the `std140` memory layout used by GLSL uniform blocks.
+///
+/// Implemented for `f32`, `i32`, `u32` and `bool` (as scalars), for `[f32; N]`, `[i32; N]` and `[u32; N]`
+/// where `N` is 2, 3 or 4 (as `vec`, `ivec` and `uvec` types), and for `[[f32; R]; C]` (as column-major
+/// `mat` types). Structs can implement it with `#[derive(Std140)]` when the `derive` feature is enabled,
+/// in which case fields marked with `#[std140(array)]` are packed as GLSL arrays (other array fields are
+/// packed as the vector or matrix type above).
+pub trait Std140 {
+    /// The base alignment of this type in bytes
+    const ALIGNMENT: usize;
+
+    /// The number of bytes that this type occupies, not including any padding that follows it
+    const SIZE: usize;
+
+    /// Appends this value to `writer`, which has already been aligned to [Std140::ALIGNMENT]
+    fn write_std140(&self, writer: &mut Std140Writer);
+
+    /// Packs this value into a new buffer, ready to be uploaded to a uniform buffer
+    fn to_std140_bytes(&self) -> Vec<u8> {
+        let mut writer = Std140Writer::default();
+        writer.write(self);
+        writer.finish(Self::ALIGNMENT)
+    }
+}
+
+/// Rounds `offset` up to the next multiple of `alignment`
+pub const fn std140_align(offset: usize, alignment: usize) -> usize {
+    offset.div_ceil(alignment) * alignment
+}
+
+/// The base alignment of a struct or array whose largest member alignment is `alignment`.
+///
+/// Structs and arrays are always aligned to at least 16 bytes (the size of a `vec4`).
+pub const fn std140_aggregate_alignment(alignment: usize) -> usize {
+    if alignment > 16 {
+        std140_align(alignment, 16)
+    } else {
+        16
+    }
+}
+
+/// The number of bytes between consecutive elements of an array
+pub const fn std140_array_stride(element_size: usize, element_alignment: usize) -> usize {
+    std140_align(element_size, std140_aggregate_alignment(element_alignment))
+}
+
+/// Accumulates bytes in `std140` layout, inserting padding as values are written.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Std140Writer {
+    bytes: Vec<u8>,
+}
+
+impl Std140Writer {
+    /// The number of bytes written so far
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether nothing has been written yet
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Pads the buffer with zeros until its length is a multiple of `alignment`
+    pub fn align(&mut self, alignment: usize) -> &mut Self {
+        self.bytes
+            .resize(std140_align(self.bytes.len(), alignment), 0);
+        self
+    }
+
+    /// Appends raw bytes without any alignment
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.bytes.extend_from_slice(bytes);
+        self
+    }
+
+    /// Aligns the buffer for `value` and appends it
+    pub fn write<S: Std140 + ?Sized>(&mut self, value: &S) -> &mut Self {
+        self.align(S::ALIGNMENT);
+        value.write_std140(self);
+        self
+    }
+
+    /// Appends `values` as a GLSL array, in which every element is padded out to the array stride
+    pub fn write_array<S: Std140>(&mut self, values: &[S]) -> &mut Self {
+        let stride = std140_array_stride(S::SIZE, S::ALIGNMENT);
+        self.align(std140_aggregate_alignment(S::ALIGNMENT));
+        for value in values {
+            let start = self.bytes.len();
+            value.write_std140(self);
+            self.bytes.resize(start + stride, 0);
+        }
+        self
+    }
+
+    /// Pads the buffer to a multiple of `alignment` and returns the packed bytes
+    pub fn finish(mut self, alignment: usize) -> Vec<u8> {
+        self.align(alignment);
+        self.bytes
+    }
+}
+
+macro_rules! impl_std140_scalar {
+    ($($scalar:ty),*) => {
+        $(
+            impl Std140 for $scalar {
+                const ALIGNMENT: usize = 4;
+                const SIZE: usize = 4;
+
+                fn write_std140(&self, writer: &mut Std140Writer) {
+                    writer.write_bytes(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_std140_scalar!(f32, i32, u32);
+
+impl Std140 for bool {
+    const ALIGNMENT: usize = 4;
+    const SIZE: usize = 4;
+
+    fn write_std140(&self, writer: &mut Std140Writer) {
+        u32::from(*self).write_std140(writer);
+    }
+}
+
+macro_rules! impl_std140_vector {
+    ($($scalar:ty),*) => {
+        $(
+            impl Std140 for [$scalar; 2] {
+                const ALIGNMENT: usize = 8;
+                const SIZE: usize = 8;
+
+                fn write_std140(&self, writer: &mut Std140Writer) {
+                    self.iter().for_each(|x| x.write_std140(writer));
+                }
+            }
+
+            impl Std140 for [$scalar; 3] {
+                const ALIGNMENT: usize = 16;
+                const SIZE: usize = 12;
+
+                fn write_std140(&self, writer: &mut Std140Writer) {
+                    self.iter().for_each(|x| x.write_std140(writer));
+                }
+            }
+
+            impl Std140 for [$scalar; 4] {
+                const ALIGNMENT: usize = 16;
+                const SIZE: usize = 16;
+
+                fn write_std140(&self, writer: &mut Std140Writer) {
+                    self.iter().for_each(|x| x.write_std140(writer));
+                }
+            }
+        )*
+    };
+}
+
+impl_std140_vector!(f32, i32, u32);
+
+/// Matrices are laid out as an array of column vectors, so each column is padded to 16 bytes
+impl<const C: usize, const R: usize> Std140 for [[f32; R]; C]
+where
+    [f32; R]: Std140,
+{
+    const ALIGNMENT: usize = std140_aggregate_alignment(<[f32; R]>::ALIGNMENT);
+    const SIZE: usize = C * std140_array_stride(<[f32; R]>::SIZE, <[f32; R]>::ALIGNMENT);
+
+    fn write_std140(&self, writer: &mut Std140Writer) {
+        writer.write_array(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirrors the GLSL block:
+    ///
+    /// ```glsl
+    /// uniform Light {
+    ///     float intensity;
+    ///     vec3 position;
+    ///     vec2 size;
+    ///     bool enabled;
+    ///     mat3 rotation;
+    /// };
+    /// ```
+    struct Light {
+        intensity: f32,
+        position: [f32; 3],
+        size: [f32; 2],
+        enabled: bool,
+        rotation: [[f32; 3]; 3],
+    }
+
+    impl Std140 for Light {
+        const ALIGNMENT: usize = 16;
+        const SIZE: usize = 96;
+
+        fn write_std140(&self, writer: &mut Std140Writer) {
+            writer
+                .write(&self.intensity)
+                .write(&self.position)
+                .write(&self.size)
+                .write(&self.enabled)
+                .write(&self.rotation)
+                .align(Self::ALIGNMENT);
+        }
+    }
+
+    fn floats(bytes: &[u8]) -> Vec<f32> {
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn aligns_vectors_and_matrices() {
+        let light = Light {
+            intensity: 1.0,
+            position: [2.0, 3.0, 4.0],
+            size: [5.0, 6.0],
+            enabled: true,
+            rotation: [[7.0, 8.0, 9.0], [10.0, 11.0, 12.0], [13.0, 14.0, 15.0]],
+        };
+
+        let bytes = light.to_std140_bytes();
+
+        assert_eq!(bytes.len(), Light::SIZE);
+        let floats = floats(&bytes);
+        // intensity, then padding up to the vec3's 16 byte alignment
+        assert_eq!(floats[0], 1.0);
+        assert_eq!(&floats[4..7], &[2.0, 3.0, 4.0]);
+        // the vec2 is 8 byte aligned, so it starts right after the vec3's 12 bytes + 4 bytes of padding
+        assert_eq!(&floats[8..10], &[5.0, 6.0]);
+        assert_eq!(&bytes[40..44], &1u32.to_le_bytes());
+        // every column of the mat3 is padded to 16 bytes
+        assert_eq!(&floats[12..15], &[7.0, 8.0, 9.0]);
+        assert_eq!(&floats[16..19], &[10.0, 11.0, 12.0]);
+        assert_eq!(&floats[20..23], &[13.0, 14.0, 15.0]);
+    }
+
+    #[test]
+    fn pads_array_elements_to_the_array_stride() {
+        let mut writer = Std140Writer::default();
+        writer.write(&1.0f32).write_array(&[2.0f32, 3.0]);
+
+        let bytes = writer.finish(16);
+
+        assert_eq!(bytes.len(), 48);
+        assert_eq!(
+            floats(&bytes),
+            vec![1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn computes_matrix_layout() {
+        assert_eq!(<[[f32; 4]; 4]>::SIZE, 64);
+        assert_eq!(<[[f32; 3]; 3]>::SIZE, 48);
+        assert_eq!(<[[f32; 2]; 2]>::SIZE, 32);
+        assert_eq!(<[[f32; 2]; 2]>::ALIGNMENT, 16);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{Id, Std140, UpdateUniformBlockError};
+use std::fmt::Debug;
+use std::hash::Hash;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer};
+
+/// Contains the build information for a uniform block: the binding point that it is bound to in each of
+/// its programs, and the `WebGlBuffer` that backs it.
+///
+/// Can be updated with [crate::RendererData::update_uniform_block].
+#[derive(Clone, Debug)]
+pub struct UniformBlock<ProgramId: Id, UniformBlockId: Id> {
+    program_ids: Vec<ProgramId>,
+    uniform_block_id: UniformBlockId,
+    binding_point: u32,
+    webgl_buffer: WebGlBuffer,
+    size: usize,
+}
+
+impl<ProgramId: Id, UniformBlockId: Id> UniformBlock<ProgramId, UniformBlockId> {
+    pub(crate) fn new(
+        program_ids: Vec<ProgramId>,
+        uniform_block_id: UniformBlockId,
+        binding_point: u32,
+        webgl_buffer: WebGlBuffer,
+        size: usize,
+    ) -> Self {
+        Self {
+            program_ids,
+            uniform_block_id,
+            binding_point,
+            webgl_buffer,
+            size,
+        }
+    }
+
+    /// Gets all program ids associated with this uniform block
+    pub fn program_ids(&self) -> &Vec<ProgramId> {
+        &self.program_ids
+    }
+
+    pub fn uniform_block_id(&self) -> &UniformBlockId {
+        &self.uniform_block_id
+    }
+
+    pub fn binding_point(&self) -> u32 {
+        self.binding_point
+    }
+
+    pub fn webgl_buffer(&self) -> &WebGlBuffer {
+        &self.webgl_buffer
+    }
+
+    /// Gets the size of the backing buffer in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Packs `value` in `std140` layout and uploads it to the backing buffer.
+    ///
+    /// Since the buffer is shared, the new value is visible to every program that uses this block.
+    pub fn update(
+        &self,
+        gl: &WebGl2RenderingContext,
+        value: &impl Std140,
+    ) -> Result<(), UpdateUniformBlockError> {
+        self.update_bytes(gl, &value.to_std140_bytes())
+    }
+
+    /// Uploads data that has already been packed in `std140` layout to the backing buffer.
+    ///
+    /// Returns [UpdateUniformBlockError::SizeMismatch] if the data is larger than the buffer that was
+    /// allocated when the block was created.
+    pub fn update_bytes(
+        &self,
+        gl: &WebGl2RenderingContext,
+        data: &[u8],
+    ) -> Result<(), UpdateUniformBlockError> {
+        if data.len() > self.size {
+            return Err(UpdateUniformBlockError::SizeMismatch {
+                uniform_block_id: format!("{:?}", self.uniform_block_id),
+                byte_length: data.len(),
+                size: self.size,
+            });
+        }
+
+        gl.bind_buffer(
+            WebGl2RenderingContext::UNIFORM_BUFFER,
+            Some(&self.webgl_buffer),
+        );
+        gl.buffer_sub_data_with_i32_and_u8_array(WebGl2RenderingContext::UNIFORM_BUFFER, 0, data);
+        gl.bind_buffer(WebGl2RenderingContext::UNIFORM_BUFFER, None);
+
+        Ok(())
+    }
+}
+
+impl<ProgramId: Id, UniformBlockId: Id> Hash for UniformBlock<ProgramId, UniformBlockId> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.uniform_block_id.hash(state);
+    }
+}
+
+impl<ProgramId: Id, UniformBlockId: Id> PartialEq for UniformBlock<ProgramId, UniformBlockId> {
+    fn eq(&self, other: &Self) -> bool {
+        self.uniform_block_id == other.uniform_block_id && self.webgl_buffer == other.webgl_buffer
+    }
+}
+
+impl<ProgramId: Id, UniformBlockId: Id> Eq for UniformBlock<ProgramId, UniformBlockId> {}
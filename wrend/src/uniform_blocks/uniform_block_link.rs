@@ -0,0 +1,90 @@
+use crate::{Bridge, Id, IdName, Std140};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Associates a named uniform block (e.g. `uniform Camera { ... };`) in one or more programs with a
+/// shared binding point, so that every program reads from the same uniform buffer.
+///
+/// The uniform block's name in GLSL is given by its id's [IdName] implementation. The size of the
+/// backing buffer is taken from the initial value supplied here.
+#[derive(Clone, Debug)]
+pub struct UniformBlockLink<ProgramId: Id, UniformBlockId: Id + IdName> {
+    program_ids: Vec<ProgramId>,
+    uniform_block_id: UniformBlockId,
+    binding_point: u32,
+    initial_data: Vec<u8>,
+}
+
+impl<ProgramId: Id, UniformBlockId: Id + IdName> UniformBlockLink<ProgramId, UniformBlockId> {
+    /// Creates a new uniform block link, whose buffer is initialized with `initial_value` packed in `std140` layout
+    pub fn new(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_block_id: UniformBlockId,
+        binding_point: u32,
+        initial_value: &impl Std140,
+    ) -> Self {
+        Self::from_bytes(
+            program_ids,
+            uniform_block_id,
+            binding_point,
+            initial_value.to_std140_bytes(),
+        )
+    }
+
+    /// Creates a new uniform block link from data that has already been packed in `std140` layout
+    pub fn from_bytes(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_block_id: UniformBlockId,
+        binding_point: u32,
+        initial_data: Vec<u8>,
+    ) -> Self {
+        let program_id_bridge: Bridge<ProgramId> = program_ids.into();
+        Self {
+            program_ids: program_id_bridge.into(),
+            uniform_block_id,
+            binding_point,
+            initial_data,
+        }
+    }
+
+    /// Gets all program ids that this link is associated with
+    pub fn program_ids(&self) -> &Vec<ProgramId> {
+        &self.program_ids
+    }
+
+    pub fn uniform_block_id(&self) -> &UniformBlockId {
+        &self.uniform_block_id
+    }
+
+    /// Gets the index of the `UNIFORM_BUFFER` binding point that the block's buffer is bound to
+    pub fn binding_point(&self) -> u32 {
+        self.binding_point
+    }
+
+    /// Gets the data that the block's buffer is initialized with
+    pub fn initial_data(&self) -> &[u8] {
+        &self.initial_data
+    }
+}
+
+impl<ProgramId: Id, UniformBlockId: Id + IdName> Hash
+    for UniformBlockLink<ProgramId, UniformBlockId>
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.program_ids.hash(state);
+        self.uniform_block_id.hash(state);
+    }
+}
+
+impl<ProgramId: Id, UniformBlockId: Id + IdName> PartialEq
+    for UniformBlockLink<ProgramId, UniformBlockId>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.program_ids == other.program_ids && self.uniform_block_id == other.uniform_block_id
+    }
+}
+
+impl<ProgramId: Id, UniformBlockId: Id + IdName> Eq
+    for UniformBlockLink<ProgramId, UniformBlockId>
+{
+}
@@ -0,0 +1,71 @@
+use crate::{utils, StringArray, UniformBlockLink};
+use std::ops::Deref;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+pub type UniformBlockLinkJsInner = UniformBlockLink<String, String>;
+
+/// See [UniformBlockLink](crate::UniformBlockLink).
+///
+/// From JavaScript, the initial data must already be packed in `std140` layout.
+#[wasm_bindgen(inspectable, js_name = UniformBlockLink)]
+pub struct UniformBlockLinkJs(UniformBlockLinkJsInner);
+
+#[wasm_bindgen(js_class = UniformBlockLink)]
+impl UniformBlockLinkJs {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        program_ids: StringArray,
+        uniform_block_id: String,
+        binding_point: u32,
+        initial_data: Vec<u8>,
+    ) -> Self {
+        let program_ids = utils::js_array_to_vec_strings(&program_ids);
+        Self(UniformBlockLinkJsInner::from_bytes(
+            program_ids,
+            uniform_block_id,
+            binding_point,
+            initial_data,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = programIds)]
+    pub fn program_ids(&self) -> StringArray {
+        utils::strings_to_js_array(self.deref().program_ids())
+    }
+
+    #[wasm_bindgen(js_name = uniformBlockId)]
+    pub fn uniform_block_id(&self) -> String {
+        self.deref().uniform_block_id().to_owned()
+    }
+
+    #[wasm_bindgen(js_name = bindingPoint)]
+    pub fn binding_point(&self) -> u32 {
+        self.deref().binding_point()
+    }
+}
+
+impl UniformBlockLinkJs {
+    pub fn into_inner(self) -> UniformBlockLinkJsInner {
+        self.0
+    }
+}
+
+impl From<UniformBlockLinkJs> for UniformBlockLinkJsInner {
+    fn from(uniform_block_link_js: UniformBlockLinkJs) -> Self {
+        uniform_block_link_js.into_inner()
+    }
+}
+
+impl From<UniformBlockLinkJsInner> for UniformBlockLinkJs {
+    fn from(uniform_block_link_js_inner: UniformBlockLinkJsInner) -> Self {
+        Self(uniform_block_link_js_inner)
+    }
+}
+
+impl Deref for UniformBlockLinkJs {
+    type Target = UniformBlockLinkJsInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
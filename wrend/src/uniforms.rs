@@ -1,21 +1,33 @@
+mod missing_uniform_policy;
 mod uniform;
 mod uniform_context;
 mod uniform_context_js;
 mod uniform_create_update_callback;
 mod uniform_create_update_callback_js;
+mod uniform_fields;
+mod uniform_handle;
+mod uniform_handle_js;
 mod uniform_js;
 mod uniform_link;
 mod uniform_link_js;
 mod uniform_should_update_callback;
 mod uniform_should_update_callback_js;
+mod uniform_value;
+mod uniform_value_js;
 
+pub use missing_uniform_policy::*;
 pub use uniform::*;
 pub use uniform_context::*;
 pub use uniform_context_js::*;
 pub use uniform_create_update_callback::*;
 pub use uniform_create_update_callback_js::*;
+pub use uniform_fields::*;
+pub use uniform_handle::*;
+pub use uniform_handle_js::*;
 pub use uniform_js::*;
 pub use uniform_link::*;
 pub use uniform_link_js::*;
 pub use uniform_should_update_callback::*;
 pub use uniform_should_update_callback_js::*;
+pub use uniform_value::*;
+pub use uniform_value_js::*;
@@ -0,0 +1,18 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Determines what happens when a uniform's location cannot be found in one of its programs,
+/// which is usually because the GLSL compiler optimized it away (or because its name is misspelled).
+///
+/// Set for every uniform with [crate::RendererDataBuilder::set_missing_uniform_policy],
+/// or for a single uniform with [crate::UniformLink::set_missing_uniform_policy].
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum MissingUniformPolicy {
+    /// Building returns a [crate::CreateUniformError::UniformLocationNotFound]
+    #[default]
+    Fail,
+    /// The uniform is skipped for that program, and a warning is logged
+    Warn,
+    /// The uniform is silently skipped for that program
+    Ignore,
+}
@@ -1,13 +1,20 @@
 use crate::Callback;
 use crate::Id;
+use crate::MissingUniformPolicy;
 use crate::UniformContext;
 use crate::UniformCreateUpdateCallback;
+use crate::UniformHandle;
+use crate::UniformHandleState;
 use crate::UniformJs;
 use crate::UniformJsInner;
+use crate::UniformLink;
 use crate::UniformShouldUpdateCallback;
+use crate::UniformValue;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::rc::Rc;
 use wasm_bindgen::JsValue;
 use web_sys::{WebGl2RenderingContext, WebGlProgram, WebGlUniformLocation};
 
@@ -25,28 +32,35 @@ pub struct Uniform<ProgramId: Id, UniformId: Id> {
     update_callback: Option<UniformCreateUpdateCallback>,
     should_update_callback: Option<UniformShouldUpdateCallback>,
     use_init_callback_for_update: bool,
+    missing_uniform_policy: Option<MissingUniformPolicy>,
+    handle_state: Rc<RefCell<UniformHandleState>>,
+    fields: Vec<String>,
+    field_locations: HashMap<ProgramId, HashMap<String, WebGlUniformLocation>>,
+    field_values: Rc<RefCell<Vec<(String, UniformValue)>>>,
 }
 
 impl<ProgramId: Id, UniformId: Id> Uniform<ProgramId, UniformId> {
-    // @todo move into builder pattern
+    /// Creates a uniform from the link that it was built from
     pub(crate) fn new(
-        program_ids: Vec<ProgramId>,
-        uniform_id: UniformId,
+        uniform_link: &UniformLink<ProgramId, UniformId>,
         // a single "conceptual" uniform can be shared across multiple programs and updated in tandem
         uniform_locations: HashMap<ProgramId, WebGlUniformLocation>,
-        initialize_callback: UniformCreateUpdateCallback,
-        update_callback: Option<UniformCreateUpdateCallback>,
-        should_update_callback: Option<UniformShouldUpdateCallback>,
-        use_init_callback_for_update: bool,
+        // struct and array uniforms have a location for each of their members instead
+        field_locations: HashMap<ProgramId, HashMap<String, WebGlUniformLocation>>,
     ) -> Self {
         Self {
-            program_ids,
-            uniform_id,
+            program_ids: uniform_link.program_ids().clone(),
+            uniform_id: uniform_link.uniform_id().clone(),
             uniform_locations,
-            uniform_create_callback: initialize_callback,
-            update_callback,
-            should_update_callback,
-            use_init_callback_for_update,
+            uniform_create_callback: uniform_link.initialize_callback(),
+            update_callback: uniform_link.update_callback(),
+            should_update_callback: uniform_link.should_update_callback(),
+            use_init_callback_for_update: uniform_link.use_init_callback_for_update(),
+            missing_uniform_policy: uniform_link.missing_uniform_policy(),
+            handle_state: uniform_link.handle_state(),
+            fields: uniform_link.fields().clone(),
+            field_locations,
+            field_values: uniform_link.field_values(),
         }
     }
 
@@ -65,6 +79,83 @@ impl<ProgramId: Id, UniformId: Id> Uniform<ProgramId, UniformId> {
         &self.uniform_locations
     }
 
+    /// Saves a new location for this uniform in the given program (used when a program gets relinked)
+    pub(crate) fn set_uniform_location(
+        &mut self,
+        program_id: ProgramId,
+        uniform_location: WebGlUniformLocation,
+    ) {
+        self.uniform_locations.insert(program_id, uniform_location);
+    }
+
+    /// See [UniformLink::fields]
+    pub fn fields(&self) -> &Vec<String> {
+        &self.fields
+    }
+
+    /// Gets the location of every member of a struct or array uniform that could be found,
+    /// keyed by the member's name relative to the uniform (see [UniformLink::fields])
+    pub fn field_locations(&self) -> &HashMap<ProgramId, HashMap<String, WebGlUniformLocation>> {
+        &self.field_locations
+    }
+
+    /// Forgets this uniform's location in the given program, which no longer declares it
+    /// (used when a program gets relinked)
+    pub(crate) fn remove_uniform_location(&mut self, program_id: &ProgramId) {
+        self.uniform_locations.remove(program_id);
+        self.field_locations.remove(program_id);
+    }
+
+    /// Saves new member locations for this uniform in the given program (used when a program gets relinked)
+    pub(crate) fn set_field_locations(
+        &mut self,
+        program_id: ProgramId,
+        field_locations: HashMap<String, WebGlUniformLocation>,
+    ) {
+        self.field_locations.insert(program_id, field_locations);
+    }
+
+    /// Uploads the values most recently given to [Uniform::update_fields], if any,
+    /// to the given member locations of the program that is currently in use
+    pub(crate) fn upload_field_values(
+        gl: &WebGl2RenderingContext,
+        field_values: &[(String, UniformValue)],
+        field_locations: &HashMap<String, WebGlUniformLocation>,
+    ) {
+        for (name, value) in field_values {
+            // members that the shader doesn't use have no location and are skipped
+            if let Some(field_location) = field_locations.get(name) {
+                value.upload(gl, field_location);
+            }
+        }
+    }
+
+    /// The values most recently uploaded to the members of this struct or array uniform
+    pub(crate) fn field_values(&self) -> Rc<RefCell<Vec<(String, UniformValue)>>> {
+        Rc::clone(&self.field_values)
+    }
+
+    /// Uploads a value to each member of a struct or array uniform in every program where it is used.
+    ///
+    /// Values are given as a member's name relative to the uniform, along with its value.
+    pub(crate) fn update_fields(
+        &self,
+        gl: &WebGl2RenderingContext,
+        programs: &HashMap<ProgramId, WebGlProgram>,
+        field_values: Vec<(String, UniformValue)>,
+    ) {
+        for (program_id, field_locations) in self.field_locations.iter() {
+            let program = programs
+                .get(program_id)
+                .expect("Program id should correspond to a saved WebGlProgram");
+            gl.use_program(Some(program));
+            Self::upload_field_values(gl, &field_values, field_locations);
+        }
+        gl.use_program(None);
+
+        *self.field_values.borrow_mut() = field_values;
+    }
+
     /// Gets the callback that is used to initialize this uniform
     pub fn initialize_callback(&self) -> UniformCreateUpdateCallback {
         self.uniform_create_callback.clone()
@@ -89,10 +180,35 @@ impl<ProgramId: Id, UniformId: Id> Uniform<ProgramId, UniformId> {
         self.use_init_callback_for_update
     }
 
+    /// See [UniformLink::missing_uniform_policy]
+    pub fn missing_uniform_policy(&self) -> Option<MissingUniformPolicy> {
+        self.missing_uniform_policy
+    }
+
+    /// Gets a handle for setting this uniform's value directly (see [UniformHandle])
+    pub fn handle<V>(&self) -> UniformHandle<V> {
+        UniformHandle::new(Rc::clone(&self.handle_state))
+    }
+
+    /// Uploads the value most recently set through a [UniformHandle], if there is one,
+    /// to the given location of the program that is currently in use
+    pub(crate) fn upload_handle_value(
+        &self,
+        gl: &WebGl2RenderingContext,
+        uniform_location: &WebGlUniformLocation,
+    ) {
+        if let Some(value) = self.handle_state.borrow().value() {
+            value.upload(gl, uniform_location);
+        }
+    }
+
     /// Updates the value of this uniform in WebGl for every Program where this uniform is used,
     /// using the update callback that was passed in at creation time.
     ///
-    /// @todo: calling this function for anything more than the current program is useless without a UBO
+    /// If a value has been set through a [UniformHandle], that value is uploaded instead (once per program),
+    /// and only if it has changed since the last update. No callbacks are called in that case.
+    ///
+    /// To share a value between programs without uploading it to each one, use a [crate::UniformBlockLink] instead.
     pub fn update(
         &self,
         gl: &WebGl2RenderingContext,
@@ -101,6 +217,21 @@ impl<ProgramId: Id, UniformId: Id> Uniform<ProgramId, UniformId> {
     ) {
         let uniform_locations = self.uniform_locations();
 
+        if self.handle_state.borrow().value().is_some() {
+            let dirty_value = self.handle_state.borrow_mut().take_dirty_value();
+            if let Some(value) = dirty_value {
+                for (program_id, uniform_location) in uniform_locations.iter() {
+                    let program = programs
+                        .get(program_id)
+                        .expect("Program id should correspond to a saved WebGlProgram");
+                    gl.use_program(Some(program));
+                    value.upload(gl, uniform_location);
+                }
+                gl.use_program(None);
+            }
+            return;
+        }
+
         for (program_id, uniform_location) in uniform_locations.iter() {
             let program = programs
                 .get(program_id)
@@ -0,0 +1,161 @@
+use crate::UniformValue;
+
+/// A Rust type that mirrors a GLSL uniform: either a single value (`float`, `vec3`, etc.) or a struct
+/// whose members are themselves uniforms.
+///
+/// Structs can implement this with `#[derive(Uniforms)]` when the `derive` feature is enabled, and are then
+/// used with [crate::UniformLink::struct_of] or [crate::UniformLink::array_of].
+///
+/// Member names are built up from a `prefix`, so that the same type can be used as a struct member,
+/// an array element (`prefix` = `"[2]"`), or a top-level struct (`prefix` = `""`).
+pub trait Uniforms {
+    /// Appends the name of every single-valued member of this type, relative to the uniform's own name
+    fn uniform_names(prefix: &str, names: &mut Vec<String>);
+
+    /// Calls `visit` with the relative name and the value of every single-valued member of this value
+    fn visit_uniform_values(&self, prefix: &str, visit: &mut dyn FnMut(&str, UniformValue));
+}
+
+/// The relative names of every member of a GLSL array of `len` elements of type `V`
+pub(crate) fn uniform_array_names<V: Uniforms>(len: usize) -> Vec<String> {
+    let mut names = Vec::new();
+    for i in 0..len {
+        V::uniform_names(&format!("[{i}]"), &mut names);
+    }
+    names
+}
+
+/// The relative name and value of every member of a GLSL array holding `values`
+pub(crate) fn uniform_array_values<V: Uniforms>(values: &[V]) -> Vec<(String, UniformValue)> {
+    let mut uniform_values = Vec::new();
+    for (i, value) in values.iter().enumerate() {
+        value.visit_uniform_values(&format!("[{i}]"), &mut |name, value| {
+            uniform_values.push((name.to_string(), value))
+        });
+    }
+    uniform_values
+}
+
+/// The relative name and value of every member of a GLSL struct holding `value`
+pub(crate) fn uniform_struct_values<V: Uniforms>(value: &V) -> Vec<(String, UniformValue)> {
+    let mut uniform_values = Vec::new();
+    value.visit_uniform_values("", &mut |name, value| {
+        uniform_values.push((name.to_string(), value))
+    });
+    uniform_values
+}
+
+macro_rules! impl_uniforms_for_value {
+    ($($value:ty),*) => {
+        $(
+            impl Uniforms for $value {
+                fn uniform_names(prefix: &str, names: &mut Vec<String>) {
+                    names.push(prefix.to_string());
+                }
+
+                fn visit_uniform_values(
+                    &self,
+                    prefix: &str,
+                    visit: &mut dyn FnMut(&str, UniformValue),
+                ) {
+                    visit(prefix, UniformValue::from(*self));
+                }
+            }
+        )*
+    };
+}
+
+impl_uniforms_for_value!(
+    f32,
+    [f32; 2],
+    [f32; 3],
+    [f32; 4],
+    i32,
+    [i32; 2],
+    [i32; 3],
+    [i32; 4],
+    u32,
+    bool,
+    UniformValue
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Material {
+        kind: i32,
+        albedo: [f32; 3],
+    }
+
+    impl Uniforms for Material {
+        fn uniform_names(prefix: &str, names: &mut Vec<String>) {
+            i32::uniform_names(&format!("{prefix}.type"), names);
+            <[f32; 3]>::uniform_names(&format!("{prefix}.albedo"), names);
+        }
+
+        fn visit_uniform_values(&self, prefix: &str, visit: &mut dyn FnMut(&str, UniformValue)) {
+            self.kind
+                .visit_uniform_values(&format!("{prefix}.type"), visit);
+            self.albedo
+                .visit_uniform_values(&format!("{prefix}.albedo"), visit);
+        }
+    }
+
+    #[test]
+    fn names_nested_members() {
+        let mut names = Vec::new();
+        Material::uniform_names("[1].material", &mut names);
+
+        assert_eq!(names, vec!["[1].material.type", "[1].material.albedo"]);
+    }
+
+    #[test]
+    fn names_every_array_element() {
+        assert_eq!(
+            uniform_array_names::<Material>(2),
+            vec!["[0].type", "[0].albedo", "[1].type", "[1].albedo"]
+        );
+        assert_eq!(uniform_array_names::<f32>(2), vec!["[0]", "[1]"]);
+    }
+
+    #[test]
+    fn visits_nested_values() {
+        let material = Material {
+            kind: 2,
+            albedo: [0.5, 0.5, 0.5],
+        };
+        let mut values = Vec::new();
+        material.visit_uniform_values("", &mut |name, value| {
+            values.push((name.to_string(), value))
+        });
+
+        assert_eq!(
+            values,
+            vec![
+                (".type".to_string(), UniformValue::Int(2)),
+                (".albedo".to_string(), UniformValue::Vec3([0.5, 0.5, 0.5])),
+            ]
+        );
+    }
+
+    #[test]
+    fn array_values_match_array_names() {
+        let materials = [
+            Material {
+                kind: 0,
+                albedo: [1.0, 0.0, 0.0],
+            },
+            Material {
+                kind: 1,
+                albedo: [0.0, 1.0, 0.0],
+            },
+        ];
+
+        let values = uniform_array_values(&materials);
+        let names: Vec<_> = values.iter().map(|(name, _)| name.clone()).collect();
+
+        assert_eq!(names, uniform_array_names::<Material>(2));
+        assert_eq!(values[2].1, UniformValue::Int(1));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::UniformValue;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// The most recent value set through a [UniformHandle], shared between the handle,
+/// the [crate::UniformLink] and the [crate::Uniform] that it belongs to.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct UniformHandleState {
+    value: Option<UniformValue>,
+    is_dirty: bool,
+}
+
+impl UniformHandleState {
+    pub(crate) fn value(&self) -> Option<UniformValue> {
+        self.value
+    }
+
+    /// Returns the value if it has changed since it was last taken, marking it as clean
+    pub(crate) fn take_dirty_value(&mut self) -> Option<UniformValue> {
+        if !self.is_dirty {
+            return None;
+        }
+        self.is_dirty = false;
+        self.value
+    }
+}
+
+/// A typed handle for setting the value of a [crate::Uniform] directly, rather than through its callbacks.
+///
+/// Once a value has been set, [crate::RendererData::update_uniforms] only uploads the uniform when its
+/// value has changed (and skips its update callbacks entirely), so there is no need for a
+/// [crate::UniformShouldUpdateCallback].
+///
+/// Handles stay valid if the pipeline is rebuilt or a program is relinked: the most recent value is
+/// uploaded again as soon as the uniform is re-initialized.
+pub struct UniformHandle<V> {
+    state: Rc<RefCell<UniformHandleState>>,
+    value_type: PhantomData<fn(V)>,
+}
+
+impl<V> UniformHandle<V> {
+    pub(crate) fn new(state: Rc<RefCell<UniformHandleState>>) -> Self {
+        Self {
+            state,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Gets the most recently set value, if any
+    pub fn value(&self) -> Option<UniformValue> {
+        self.state.borrow().value
+    }
+
+    /// Returns `true` if the value has changed since the uniform was last uploaded
+    pub fn is_dirty(&self) -> bool {
+        self.state.borrow().is_dirty
+    }
+}
+
+impl<V: Into<UniformValue>> UniformHandle<V> {
+    /// Caches a new value, which is uploaded the next time the uniform is updated.
+    ///
+    /// Setting the same value that is already cached does not mark the uniform as dirty.
+    pub fn set(&self, value: V) {
+        let value = value.into();
+        let mut state = self.state.borrow_mut();
+        if state.value != Some(value) {
+            state.value = Some(value);
+            state.is_dirty = true;
+        }
+    }
+}
+
+impl<V> Clone for UniformHandle<V> {
+    fn clone(&self) -> Self {
+        Self::new(Rc::clone(&self.state))
+    }
+}
+
+impl<V> Debug for UniformHandle<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UniformHandle")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_changed_values_are_dirty() {
+        let state = Rc::new(RefCell::new(UniformHandleState::default()));
+        let handle = UniformHandle::<f32>::new(Rc::clone(&state));
+
+        handle.set(1.0);
+        assert!(handle.is_dirty());
+        assert_eq!(
+            state.borrow_mut().take_dirty_value(),
+            Some(UniformValue::Float(1.0))
+        );
+        assert!(!handle.is_dirty());
+
+        handle.set(1.0);
+        assert!(!handle.is_dirty());
+        assert_eq!(state.borrow_mut().take_dirty_value(), None);
+
+        handle.set(2.0);
+        assert!(handle.is_dirty());
+        assert_eq!(handle.value(), Some(UniformValue::Float(2.0)));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{UniformHandle, UniformValue, UniformValueJs};
+use std::ops::Deref;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+pub type UniformHandleJsInner = UniformHandle<UniformValue>;
+
+/// See [UniformHandle](crate::UniformHandle)
+#[wasm_bindgen(inspectable, js_name = UniformHandle)]
+#[derive(Debug, Clone)]
+pub struct UniformHandleJs(UniformHandleJsInner);
+
+#[wasm_bindgen(js_class = UniformHandle)]
+impl UniformHandleJs {
+    pub fn set(&self, value: UniformValueJs) {
+        self.deref().set(value.into());
+    }
+
+    pub fn value(&self) -> Option<UniformValueJs> {
+        self.deref().value().map(Into::into)
+    }
+
+    #[wasm_bindgen(js_name = isDirty)]
+    pub fn is_dirty(&self) -> bool {
+        self.deref().is_dirty()
+    }
+}
+
+impl UniformHandleJs {
+    pub fn into_inner(self) -> UniformHandleJsInner {
+        self.0
+    }
+}
+
+impl From<UniformHandleJsInner> for UniformHandleJs {
+    fn from(uniform_handle: UniformHandleJsInner) -> Self {
+        Self(uniform_handle)
+    }
+}
+
+impl Deref for UniformHandleJs {
+    type Target = UniformHandleJsInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -1,9 +1,16 @@
 use crate::Bridge;
 use crate::Id;
+use crate::MissingUniformPolicy;
+use crate::UniformContext;
 use crate::UniformCreateUpdateCallback;
+use crate::UniformHandleState;
 use crate::UniformShouldUpdateCallback;
+use crate::UniformValue;
+use crate::Uniforms;
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::rc::Rc;
 
 /// This link is used to associated a one or more `ProgramId`s with a conceptual uniform.
 /// This allows sharing uniforms across programs, even when each uniform's location differs between programs.
@@ -15,6 +22,10 @@ pub struct UniformLink<ProgramId: Id, UniformId: Id> {
     update_callback: Option<UniformCreateUpdateCallback>,
     should_update_callback: Option<UniformShouldUpdateCallback>,
     use_init_callback_for_update: bool,
+    missing_uniform_policy: Option<MissingUniformPolicy>,
+    handle_state: Rc<RefCell<UniformHandleState>>,
+    fields: Vec<String>,
+    field_values: Rc<RefCell<Vec<(String, UniformValue)>>>,
 }
 
 impl<ProgramId: Id, UniformId: Id> UniformLink<ProgramId, UniformId> {
@@ -33,9 +44,89 @@ impl<ProgramId: Id, UniformId: Id> UniformLink<ProgramId, UniformId> {
             use_init_callback_for_update: false,
             should_update_callback: None,
             update_callback: None,
+            missing_uniform_policy: None,
+            handle_state: Default::default(),
+            fields: Vec::new(),
+            field_values: Default::default(),
         }
     }
 
+    /// Creates a link to a GLSL struct uniform, whose members mirror the fields of `V`
+    /// (see [crate::Uniforms]).
+    ///
+    /// Values are uploaded with [crate::RendererData::update_uniform_struct].
+    pub fn struct_of<V: Uniforms>(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+    ) -> Self {
+        let mut fields = Vec::new();
+        V::uniform_names("", &mut fields);
+        Self::from_fields(program_ids, uniform_id, fields)
+    }
+
+    /// Creates a link to a GLSL array of `len` elements, each of which mirrors `V`
+    /// (see [crate::Uniforms]), such as `uniform Sphere u_sphere_list[len];`.
+    ///
+    /// The location of every member of every element is found when the uniform is built. Members that cannot
+    /// be found (for example, because the shader declares a shorter array or never reads them) are skipped
+    /// rather than failing the build. If no member can be found in a program, the [MissingUniformPolicy] applies.
+    ///
+    /// Values are uploaded with [crate::RendererData::update_uniform_array].
+    pub fn array_of<V: Uniforms>(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        len: usize,
+    ) -> Self {
+        let fields = crate::uniform_array_names::<V>(len);
+        Self::from_fields(program_ids, uniform_id, fields)
+    }
+
+    fn from_fields(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        fields: Vec<String>,
+    ) -> Self {
+        // members are uploaded all at once from their cached values, so there is nothing to initialize
+        let mut uniform_link = Self::new(program_ids, uniform_id, |_: &UniformContext| {});
+        uniform_link.fields = fields;
+        uniform_link
+    }
+
+    /// Creates a uniform link that uploads a constant value when the uniform is initialized,
+    /// without the need for an initialize callback.
+    pub fn from_value(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        value: impl Into<UniformValue>,
+    ) -> Self {
+        let value = value.into();
+        Self::new(program_ids, uniform_id, move |ctx: &UniformContext| {
+            value.upload(ctx.gl(), ctx.uniform_location())
+        })
+    }
+
+    /// Creates a uniform link that uploads the value returned by `source`, both when the uniform is
+    /// initialized and every time it is updated, without the need for an initialize or update callback.
+    pub fn from_source(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        source: impl Fn() -> UniformValue + 'static,
+    ) -> Self {
+        let mut uniform_link = Self::new(program_ids, uniform_id, move |ctx: &UniformContext| {
+            source().upload(ctx.gl(), ctx.uniform_location())
+        });
+        uniform_link.set_use_init_callback_for_update(true);
+        uniform_link
+    }
+
+    /// Replaces the initialize callback with one that uploads a constant value (see [UniformLink::from_value])
+    pub fn set_value(&mut self, value: impl Into<UniformValue>) -> &mut Self {
+        let value = value.into();
+        self.set_initialize_callback(move |ctx: &UniformContext| {
+            value.upload(ctx.gl(), ctx.uniform_location())
+        })
+    }
+
     /// Gets all program ids that this link is associated with
     pub fn program_ids(&self) -> &Vec<ProgramId> {
         &self.program_ids
@@ -88,6 +179,39 @@ impl<ProgramId: Id, UniformId: Id> UniformLink<ProgramId, UniformId> {
         self
     }
 
+    /// The value set through any [crate::UniformHandle] to this uniform,
+    /// which is shared by every [crate::Uniform] built from this link
+    pub(crate) fn handle_state(&self) -> Rc<RefCell<UniformHandleState>> {
+        Rc::clone(&self.handle_state)
+    }
+
+    /// The [MissingUniformPolicy] for this uniform, if it overrides the builder's policy
+    pub fn missing_uniform_policy(&self) -> Option<MissingUniformPolicy> {
+        self.missing_uniform_policy
+    }
+
+    /// Overrides the builder's [MissingUniformPolicy] for this uniform only
+    /// (see [crate::RendererDataBuilder::set_missing_uniform_policy])
+    pub fn set_missing_uniform_policy(
+        &mut self,
+        missing_uniform_policy: MissingUniformPolicy,
+    ) -> &mut Self {
+        self.missing_uniform_policy.replace(missing_uniform_policy);
+        self
+    }
+
+    /// The names of every member of a struct or array uniform, relative to the uniform's own name
+    /// (for example, `[0].material.type`). Empty for uniforms that hold a single value.
+    pub fn fields(&self) -> &Vec<String> {
+        &self.fields
+    }
+
+    /// The values most recently uploaded to the members of a struct or array uniform,
+    /// which are re-uploaded whenever the uniform is rebuilt or its programs are relinked
+    pub(crate) fn field_values(&self) -> Rc<RefCell<Vec<(String, UniformValue)>>> {
+        Rc::clone(&self.field_values)
+    }
+
     /// See [Uniform::use_init_callback_for_update]
     pub fn use_init_callback_for_update(&self) -> bool {
         self.use_init_callback_for_update
@@ -115,6 +239,8 @@ impl<ProgramId: Id, UniformId: Id> Debug for UniformLink<ProgramId, UniformId> {
                 "use_init_callback_for_update",
                 &self.use_init_callback_for_update,
             )
+            .field("missing_uniform_policy", &self.missing_uniform_policy)
+            .field("fields", &self.fields)
             .finish()
     }
 }
@@ -133,3 +259,33 @@ impl<ProgramId: Id, UniformId: Id> PartialEq for UniformLink<ProgramId, UniformI
 }
 
 impl<ProgramId: Id, UniformId: Id> Eq for UniformLink<ProgramId, UniformId> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_uniforms_from_a_source_are_reuploaded_on_update() {
+        let from_value =
+            UniformLink::<&str, &str>::from_value("program", "u_color", [1.0, 0.0, 0.0]);
+        let from_source = UniformLink::<&str, &str>::from_source("program", "u_time", || {
+            UniformValue::Float(0.0)
+        });
+
+        assert!(!from_value.use_init_callback_for_update());
+        assert!(from_source.use_init_callback_for_update());
+    }
+
+    #[test]
+    fn array_links_list_every_element() {
+        let uniform_link =
+            UniformLink::<&str, &str>::array_of::<[f32; 3]>("program", "u_points", 3);
+
+        assert_eq!(uniform_link.fields(), &vec!["[0]", "[1]", "[2]"]);
+        assert!(
+            UniformLink::<&str, &str>::from_value("program", "u_time", 0.0)
+                .fields()
+                .is_empty()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    utils, StringArray, UniformCreateUpdateCallbackJs, UniformLink, UniformShouldUpdateCallbackJs,
+    utils, MissingUniformPolicy, StringArray, UniformCreateUpdateCallbackJs, UniformLink,
+    UniformShouldUpdateCallbackJs, UniformValueJs,
 };
 use std::ops::{Deref, DerefMut};
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -25,6 +26,21 @@ impl UniformLinkJs {
         ))
     }
 
+    #[wasm_bindgen(js_name = fromValue)]
+    pub fn from_value(program_ids: StringArray, uniform_id: String, value: UniformValueJs) -> Self {
+        let program_ids = utils::js_array_to_vec_strings(&program_ids);
+        Self(UniformLinkJsInner::from_value(
+            program_ids,
+            uniform_id,
+            value,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = setValue)]
+    pub fn set_value(&mut self, value: UniformValueJs) {
+        self.deref_mut().set_value(value);
+    }
+
     #[wasm_bindgen(js_name = programIds)]
     pub fn program_ids(&self) -> StringArray {
         utils::strings_to_js_array(self.deref().program_ids())
@@ -77,6 +93,17 @@ impl UniformLinkJs {
         self.deref().use_init_callback_for_update()
     }
 
+    #[wasm_bindgen(js_name = missingUniformPolicy)]
+    pub fn missing_uniform_policy(&self) -> Option<MissingUniformPolicy> {
+        self.deref().missing_uniform_policy()
+    }
+
+    #[wasm_bindgen(js_name = setMissingUniformPolicy)]
+    pub fn set_missing_uniform_policy(&mut self, missing_uniform_policy: MissingUniformPolicy) {
+        self.deref_mut()
+            .set_missing_uniform_policy(missing_uniform_policy);
+    }
+
     #[wasm_bindgen(js_name = setUseInitCallbackForUpdate)]
     pub fn set_use_init_callback_for_update(&mut self, use_init_callback_for_update: bool) {
         self.deref_mut()
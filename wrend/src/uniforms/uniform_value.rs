@@ -0,0 +1,120 @@
+use web_sys::{WebGl2RenderingContext, WebGlUniformLocation};
+
+/// A typed uniform value that knows which `gl.uniform*` call to upload itself with.
+///
+/// Used with [crate::UniformLink::from_value] and [crate::UniformLink::from_source]
+/// to create uniforms without writing an update callback by hand.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UniformValue {
+    /// `float`
+    Float(f32),
+    /// `vec2`
+    Vec2([f32; 2]),
+    /// `vec3`
+    Vec3([f32; 3]),
+    /// `vec4`
+    Vec4([f32; 4]),
+    /// `int`
+    Int(i32),
+    /// `ivec2`
+    IVec2([i32; 2]),
+    /// `ivec3`
+    IVec3([i32; 3]),
+    /// `ivec4`
+    IVec4([i32; 4]),
+    /// `uint`
+    UInt(u32),
+    /// `bool`, uploaded as an `int`
+    Bool(bool),
+    /// Column-major 2x2 matrix
+    Mat2([f32; 4]),
+    /// Column-major 3x3 matrix
+    Mat3([f32; 9]),
+    /// Column-major 4x4 matrix
+    Mat4([f32; 16]),
+    /// The texture unit that a `sampler*` uniform should read from
+    Sampler(i32),
+}
+
+impl UniformValue {
+    /// Uploads this value to the given location of the program that is currently in use
+    pub fn upload(&self, gl: &WebGl2RenderingContext, uniform_location: &WebGlUniformLocation) {
+        let location = Some(uniform_location);
+        match self {
+            UniformValue::Float(x) => gl.uniform1f(location, *x),
+            UniformValue::Vec2([x, y]) => gl.uniform2f(location, *x, *y),
+            UniformValue::Vec3([x, y, z]) => gl.uniform3f(location, *x, *y, *z),
+            UniformValue::Vec4([x, y, z, w]) => gl.uniform4f(location, *x, *y, *z, *w),
+            UniformValue::Int(x) => gl.uniform1i(location, *x),
+            UniformValue::IVec2([x, y]) => gl.uniform2i(location, *x, *y),
+            UniformValue::IVec3([x, y, z]) => gl.uniform3i(location, *x, *y, *z),
+            UniformValue::IVec4([x, y, z, w]) => gl.uniform4i(location, *x, *y, *z, *w),
+            UniformValue::UInt(x) => gl.uniform1ui(location, *x),
+            UniformValue::Bool(x) => gl.uniform1i(location, i32::from(*x)),
+            UniformValue::Mat2(m) => gl.uniform_matrix2fv_with_f32_array(location, false, m),
+            UniformValue::Mat3(m) => gl.uniform_matrix3fv_with_f32_array(location, false, m),
+            UniformValue::Mat4(m) => gl.uniform_matrix4fv_with_f32_array(location, false, m),
+            UniformValue::Sampler(texture_unit) => gl.uniform1i(location, *texture_unit),
+        }
+    }
+}
+
+impl From<f32> for UniformValue {
+    fn from(x: f32) -> Self {
+        UniformValue::Float(x)
+    }
+}
+
+impl From<[f32; 2]> for UniformValue {
+    fn from(v: [f32; 2]) -> Self {
+        UniformValue::Vec2(v)
+    }
+}
+
+impl From<[f32; 3]> for UniformValue {
+    fn from(v: [f32; 3]) -> Self {
+        UniformValue::Vec3(v)
+    }
+}
+
+impl From<[f32; 4]> for UniformValue {
+    fn from(v: [f32; 4]) -> Self {
+        UniformValue::Vec4(v)
+    }
+}
+
+impl From<i32> for UniformValue {
+    fn from(x: i32) -> Self {
+        UniformValue::Int(x)
+    }
+}
+
+impl From<[i32; 2]> for UniformValue {
+    fn from(v: [i32; 2]) -> Self {
+        UniformValue::IVec2(v)
+    }
+}
+
+impl From<[i32; 3]> for UniformValue {
+    fn from(v: [i32; 3]) -> Self {
+        UniformValue::IVec3(v)
+    }
+}
+
+impl From<[i32; 4]> for UniformValue {
+    fn from(v: [i32; 4]) -> Self {
+        UniformValue::IVec4(v)
+    }
+}
+
+impl From<u32> for UniformValue {
+    fn from(x: u32) -> Self {
+        UniformValue::UInt(x)
+    }
+}
+
+impl From<bool> for UniformValue {
+    fn from(x: bool) -> Self {
+        UniformValue::Bool(x)
+    }
+}
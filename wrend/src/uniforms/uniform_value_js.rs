@@ -0,0 +1,114 @@
+use crate::UniformValue;
+use std::ops::Deref;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// See [UniformValue](crate::UniformValue)
+#[wasm_bindgen(inspectable, js_name = UniformValue)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformValueJs(UniformValue);
+
+#[wasm_bindgen(js_class = UniformValue)]
+impl UniformValueJs {
+    /// Creates a `float` value
+    pub fn float(x: f32) -> Self {
+        Self(UniformValue::Float(x))
+    }
+
+    /// Creates a `vec2` value
+    pub fn vec2(x: f32, y: f32) -> Self {
+        Self(UniformValue::Vec2([x, y]))
+    }
+
+    /// Creates a `vec3` value
+    pub fn vec3(x: f32, y: f32, z: f32) -> Self {
+        Self(UniformValue::Vec3([x, y, z]))
+    }
+
+    /// Creates a `vec4` value
+    pub fn vec4(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self(UniformValue::Vec4([x, y, z, w]))
+    }
+
+    /// Creates an `int` value
+    pub fn int(x: i32) -> Self {
+        Self(UniformValue::Int(x))
+    }
+
+    /// Creates an `ivec2` value
+    pub fn ivec2(x: i32, y: i32) -> Self {
+        Self(UniformValue::IVec2([x, y]))
+    }
+
+    /// Creates an `ivec3` value
+    pub fn ivec3(x: i32, y: i32, z: i32) -> Self {
+        Self(UniformValue::IVec3([x, y, z]))
+    }
+
+    /// Creates an `ivec4` value
+    pub fn ivec4(x: i32, y: i32, z: i32, w: i32) -> Self {
+        Self(UniformValue::IVec4([x, y, z, w]))
+    }
+
+    /// Creates a `uint` value
+    pub fn uint(x: u32) -> Self {
+        Self(UniformValue::UInt(x))
+    }
+
+    /// Creates a `bool` value
+    pub fn bool(x: bool) -> Self {
+        Self(UniformValue::Bool(x))
+    }
+
+    /// Creates a `mat2` value from 4 column-major elements
+    pub fn mat2(m: Vec<f32>) -> Result<UniformValueJs, String> {
+        Ok(Self(UniformValue::Mat2(Self::matrix(m)?)))
+    }
+
+    /// Creates a `mat3` value from 9 column-major elements
+    pub fn mat3(m: Vec<f32>) -> Result<UniformValueJs, String> {
+        Ok(Self(UniformValue::Mat3(Self::matrix(m)?)))
+    }
+
+    /// Creates a `mat4` value from 16 column-major elements
+    pub fn mat4(m: Vec<f32>) -> Result<UniformValueJs, String> {
+        Ok(Self(UniformValue::Mat4(Self::matrix(m)?)))
+    }
+
+    /// Creates a `sampler*` value that reads from the given texture unit
+    pub fn sampler(texture_unit: i32) -> Self {
+        Self(UniformValue::Sampler(texture_unit))
+    }
+}
+
+impl UniformValueJs {
+    fn matrix<const N: usize>(m: Vec<f32>) -> Result<[f32; N], String> {
+        let len = m.len();
+        m.try_into()
+            .map_err(|_| format!("Expected a matrix with {N} elements, but received {len}"))
+    }
+
+    /// Gets the wrapped [UniformValue]
+    pub fn into_inner(self) -> UniformValue {
+        self.0
+    }
+}
+
+impl From<UniformValue> for UniformValueJs {
+    fn from(uniform_value: UniformValue) -> Self {
+        Self(uniform_value)
+    }
+}
+
+impl From<UniformValueJs> for UniformValue {
+    fn from(uniform_value_js: UniformValueJs) -> Self {
+        uniform_value_js.into_inner()
+    }
+}
+
+impl Deref for UniformValueJs {
+    type Target = UniformValue;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -3,11 +3,73 @@
 #![cfg(target_arch = "wasm32")]
 
 extern crate wasm_bindgen_test;
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
+use web_sys::{window, HtmlCanvasElement, WebGl2RenderingContext};
+use wrend::{IdDefault, ProgramLink, RendererData, RendererDataBuilder, RendererTypes};
 
 wasm_bindgen_test_configure!(run_in_browser);
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct TestTypes;
+
+impl RendererTypes for TestTypes {
+    type VertexShaderId = &'static str;
+    type FragmentShaderId = &'static str;
+    type ProgramId = &'static str;
+    type UniformId = IdDefault;
+    type UniformBlockId = IdDefault;
+    type BufferId = IdDefault;
+    type AttributeId = IdDefault;
+    type TextureId = IdDefault;
+    type FramebufferId = IdDefault;
+    type RenderbufferId = IdDefault;
+    type TransformFeedbackId = IdDefault;
+    type VertexArrayObjectId = IdDefault;
+    type UserCtx = ();
+}
+
+const VERTEX_SHADER: &str = "#version 300 es
+void main() {
+    gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+}";
+
+const FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+out vec4 color;
+void main() {
+    color = vec4(1.0);
+}";
+
 #[wasm_bindgen_test]
 fn pass() {
     // todo
 }
+
+#[wasm_bindgen_test]
+fn dropping_an_animating_renderer_disposes_its_resources() {
+    let canvas: HtmlCanvasElement = window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .create_element("canvas")
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+    let mut builder = RendererDataBuilder::<TestTypes>::default();
+    builder
+        .set_canvas(canvas)
+        .add_vertex_shader_src("vertex", VERTEX_SHADER)
+        .add_fragment_shader_src("fragment", FRAGMENT_SHADER)
+        .add_program_link(ProgramLink::new("program", "vertex", "fragment"))
+        .set_render_callback(|_: &RendererData<TestTypes>| {});
+    let renderer = builder.build_renderer().unwrap();
+    let gl: WebGl2RenderingContext = renderer.gl();
+    let program = renderer.program(&"program").unwrap();
+
+    renderer.start_animating();
+    assert!(gl.is_program(Some(&program)));
+
+    drop(renderer);
+    assert!(!gl.is_program(Some(&program)));
+}